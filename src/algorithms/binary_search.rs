@@ -1,43 +1,96 @@
-use crate::Fragment;
-use std::cmp::Ordering;
+use crate::{Fragment, Growth};
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
 
-pub fn binary_search_by<T, F>(fragments: &[Fragment<T>], mut compare: F) -> Result<usize, usize>
+/// Binary searches the sorted `fragments` of a split vector with the given `growth` and `len` with the `compare` function:
+/// * positions of the vector are binary searched, locating each probed element by the growth strategy;
+///   i.e., in constant time for growth strategies with constant time access and by the cumulative lengths index of [`Recursive`],
+/// * once the remaining positions belong to a single fragment, they are binary searched within this fragment.
+///
+/// Hence, the search requires O(log(n)) comparisons, while empty fragments are skipped by the growth strategy at no additional cost.
+///
+/// The time complexity of the search further depends on the cost of locating a probe by the growth strategy:
+/// * O(log(n)) for growth strategies with constant time access, such as [`Doubling`] and [`Linear`],
+/// * O(log(f) log(n)) for [`Recursive`], where f is the number of fragments,
+/// * O(f log(n)) for any other growth strategy, which locates each probe by the default linear scan over the fragments.
+///
+/// [`Doubling`]: crate::Doubling
+/// [`Linear`]: crate::Linear
+/// [`Recursive`]: crate::Recursive
+pub fn binary_search_by<T, G: Growth, A: Allocator, F>(
+    growth: &G,
+    len: usize,
+    fragments: &[Fragment<T, A>],
+    mut compare: F,
+) -> Result<usize, usize>
 where
     F: FnMut(&T) -> Ordering,
 {
-    let mut f = 0;
-    let mut fragment_begin_idx = 0;
+    let location = |index| {
+        growth
+            .get_fragment_and_inner_indices(len, fragments, index)
+            .expect("index is within the bounds of the vector")
+    };
 
-    while let Some(fragment) = fragments.get(f) {
-        let result = fragment.binary_search_by(&mut compare);
-
-        match result {
-            Ok(idx_in_fragment) => {
-                let idx = fragment_begin_idx + idx_in_fragment;
-                return Ok(idx);
-            }
-            Err(idx_in_fragment) => match idx_in_fragment {
-                x if x == fragment.len() => {}
-                _ => {
-                    let idx = fragment_begin_idx + idx_in_fragment;
-                    return Err(idx);
-                }
-            },
+    let (mut begin, mut end) = (0, len);
+    while begin < end {
+        let ((f, i), (g, j)) = (location(begin), location(end - 1));
+        if f == g {
+            return match fragments[f][i..=j].binary_search_by(compare) {
+                Ok(idx) => Ok(begin + idx),
+                Err(idx) => Err(begin + idx),
+            };
         }
 
-        fragment_begin_idx += fragment.len();
-        f += 1;
+        let mid = begin + (end - begin) / 2;
+        let (f, i) = location(mid);
+        match compare(&fragments[f][i]) {
+            Ordering::Less => begin = mid + 1,
+            Ordering::Greater => end = mid,
+            Ordering::Equal => return Ok(mid),
+        }
     }
+    Err(begin)
+}
 
-    Err(fragment_begin_idx)
+/// Returns the index of the partition point of the sorted `fragments` according to the given predicate;
+/// i.e., the index of the first element for which `pred` returns false.
+///
+/// Uses the search of [`binary_search_by`].
+pub fn partition_point<T, G: Growth, A: Allocator, P>(
+    growth: &G,
+    len: usize,
+    fragments: &[Fragment<T, A>],
+    mut pred: P,
+) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let compare = |x: &T| match pred(x) {
+        true => Ordering::Less,
+        false => Ordering::Greater,
+    };
+    match binary_search_by(growth, len, fragments, compare) {
+        Ok(idx) | Err(idx) => idx,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{test_all_growth_types, Growth, SplitVec};
+    use crate::{test_all_growth_types, Recursive, SplitVec};
     use orx_pinned_vec::PinnedVec;
 
+    fn search<F>(fragments: &[Fragment<usize>], compare: F) -> Result<usize, usize>
+    where
+        F: FnMut(&usize) -> Ordering,
+    {
+        let mut growth = Recursive::default();
+        growth.fragments_mutated_from(fragments, 0);
+        let len = fragments.iter().map(|x| x.len()).sum();
+        binary_search_by(&growth, len, fragments, compare)
+    }
+
     fn get_compare(value: usize) -> impl FnMut(&usize) -> Ordering {
         move |x: &usize| x.cmp(&value)
     }
@@ -46,8 +99,8 @@ mod tests {
     fn bin_search_empty() {
         let cmp = get_compare(42);
 
-        let fragments = vec![];
-        let result = binary_search_by(&fragments, cmp);
+        let fragments: Vec<Fragment<usize>> = vec![];
+        let result = search(&fragments, cmp);
         assert_eq!(result, Err(0));
     }
 
@@ -56,7 +109,7 @@ mod tests {
         let cmp = get_compare(42);
 
         let fragments = vec![vec![].into()];
-        let result = binary_search_by(&fragments, cmp);
+        let result = search(&fragments, cmp);
         assert_eq!(result, Err(0));
     }
 
@@ -64,22 +117,22 @@ mod tests {
     fn bin_search_empty_second_fragment() {
         let fragments = vec![vec![1, 4, 5].into(), vec![].into()];
 
-        let result = binary_search_by(&fragments, get_compare(0));
+        let result = search(&fragments, get_compare(0));
         assert_eq!(result, Err(0));
 
-        let result = binary_search_by(&fragments, get_compare(2));
+        let result = search(&fragments, get_compare(2));
         assert_eq!(result, Err(1));
 
-        let result = binary_search_by(&fragments, get_compare(42));
+        let result = search(&fragments, get_compare(42));
         assert_eq!(result, Err(3));
 
-        let result = binary_search_by(&fragments, get_compare(1));
+        let result = search(&fragments, get_compare(1));
         assert_eq!(result, Ok(0));
 
-        let result = binary_search_by(&fragments, get_compare(4));
+        let result = search(&fragments, get_compare(4));
         assert_eq!(result, Ok(1));
 
-        let result = binary_search_by(&fragments, get_compare(5));
+        let result = search(&fragments, get_compare(5));
         assert_eq!(result, Ok(2));
     }
 
@@ -87,7 +140,7 @@ mod tests {
     fn bin_search_three_fragments() {
         let fragments = vec![vec![1, 4, 5].into(), vec![7].into(), vec![9, 10].into()];
 
-        let search = |x| binary_search_by(&fragments, get_compare(x));
+        let search = |x| search(&fragments, get_compare(x));
 
         assert_eq!(search(0), Err(0));
         assert_eq!(search(1), Ok(0));
@@ -134,4 +187,80 @@ mod tests {
         }
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn bin_search_empty_middle_fragments() {
+        let fragments = vec![
+            vec![].into(),
+            vec![1, 4].into(),
+            vec![].into(),
+            vec![].into(),
+            vec![7, 9].into(),
+            vec![].into(),
+            vec![12].into(),
+            vec![].into(),
+        ];
+        let ref_vec = [1, 4, 7, 9, 12];
+
+        for x in 0..15 {
+            assert_eq!(
+                search(&fragments, get_compare(x)),
+                ref_vec.binary_search(&x)
+            );
+            let mut growth = Recursive::default();
+            growth.fragments_mutated_from(&fragments, 0);
+            assert_eq!(
+                partition_point(&growth, ref_vec.len(), &fragments, |y| *y < x),
+                ref_vec.partition_point(|y| *y < x)
+            );
+        }
+    }
+
+    #[test]
+    fn bin_search_many_fragments_logarithmic_comparisons() {
+        let mut vec = SplitVec::with_recursive_growth();
+        for f in 0..500 {
+            vec.append((0..(1 + f % 7)).map(|i| 10 * f + i).collect::<Vec<_>>());
+        }
+        let ref_vec: Vec<_> = vec.iter().copied().collect();
+
+        for x in (0..5010).step_by(3) {
+            let mut num_comparisons = 0;
+            let result = vec.binary_search_by(|y| {
+                num_comparisons += 1;
+                y.cmp(&x)
+            });
+            assert_eq!(result, ref_vec.binary_search(&x));
+            assert!(num_comparisons <= 9 + 3 + 2);
+        }
+    }
+
+    #[test]
+    fn partition_point_randomized() {
+        use rand::prelude::*;
+        use rand_chacha::ChaCha8Rng;
+
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut rng = ChaCha8Rng::seed_from_u64(3254);
+            let mut ref_vec = vec![];
+            let mut value = 0;
+            while ref_vec.len() < 1033 {
+                value += rng.gen_range(0..3);
+                ref_vec.push(value);
+                vec.push(value);
+            }
+
+            for i in 0..(value + 10) {
+                assert_eq!(
+                    vec.partition_point(|x| *x < i),
+                    ref_vec.partition_point(|x| *x < i)
+                );
+                assert_eq!(
+                    vec.partition_point(|x| *x <= i),
+                    ref_vec.partition_point(|x| *x <= i)
+                );
+            }
+        }
+        test_all_growth_types!(test);
+    }
 }
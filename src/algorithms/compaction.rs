@@ -0,0 +1,193 @@
+use crate::{Growth, SplitVec};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
+
+/// Location of an element as a tuple of (fragment-index, index-within-fragment).
+pub(crate) type Location = (usize, usize);
+
+/// Removes elements of a split vector in a single pass over its fragments with read and write cursors.
+///
+/// Compaction captures the lengths of the fragments on creation and hides the elements starting from the `begin` position.
+/// Then, each element at the read cursor is either kept, in which case it is moved to the write cursor,
+/// or discarded, in which case the caller is responsible for dropping or moving it out.
+///
+/// When the compaction is dropped, which also happens while unwinding due to a panic,
+/// the elements which are not yet read are moved right after the kept elements with bulk moves,
+/// lengths of fragments are set accordingly and trailing empty fragments are dropped.
+pub(crate) struct Compaction<'a, T, G: Growth, A: Allocator> {
+    vec: &'a mut SplitVec<T, G, A>,
+    lengths: Vec<usize>,
+    original_len: usize,
+    begin_fragment: usize,
+    read: usize,
+    read_location: Location,
+    write: usize,
+    write_location: Location,
+    last_kept_location: Option<Location>,
+}
+
+impl<'a, T, G: Growth, A: Allocator> Compaction<'a, T, G, A> {
+    pub(crate) fn new(vec: &'a mut SplitVec<T, G, A>, begin: usize) -> Self {
+        debug_assert!(begin <= vec.len);
+
+        let lengths: Vec<_> = vec.fragments.iter().map(|f| f.len()).collect();
+        let begin_location = locate(&lengths, begin);
+        let last_kept_location = begin.checked_sub(1).map(|x| locate(&lengths, x));
+        let original_len = vec.len;
+
+        // SAFETY: elements from begin on are hidden from the vector; each of them is
+        // either discarded or moved while closing the gap when the compaction is dropped
+        let (f, i) = begin_location;
+        for (f2, fragment) in vec.fragments.iter_mut().enumerate().skip(f) {
+            unsafe { fragment.set_len(if f2 == f { i } else { 0 }) };
+        }
+        vec.len = begin;
+
+        Self {
+            vec,
+            lengths,
+            original_len,
+            begin_fragment: begin_location.0,
+            read: begin,
+            read_location: begin_location,
+            write: begin,
+            write_location: begin_location,
+            last_kept_location,
+        }
+    }
+
+    /// Returns the location of the `position`-th element with respect to the original fragment lengths.
+    #[inline(always)]
+    pub(crate) fn locate(&self, position: usize) -> Location {
+        locate(&self.lengths, position)
+    }
+
+    /// Returns the location which is `count` positions after the `location`.
+    #[inline(always)]
+    pub(crate) fn advance(&self, location: Location, count: usize) -> Location {
+        advance(&self.lengths, location, count)
+    }
+
+    /// Returns the location which is one position before the `location`.
+    pub(crate) fn retreat(&self, location: Location) -> Location {
+        let (mut f, mut i) = location;
+        while i == 0 {
+            f -= 1;
+            i = self.lengths[f];
+        }
+        (f, i - 1)
+    }
+
+    /// Returns the number of elements that can be stored starting at the `location` in the same fragment.
+    #[inline(always)]
+    pub(crate) fn contiguous_len(&self, location: Location) -> usize {
+        self.lengths[location.0] - location.1
+    }
+
+    /// Returns a pointer to the element at the given `location` with respect to the original fragment lengths.
+    #[inline(always)]
+    pub(crate) fn ptr(&mut self, location: Location) -> *mut T {
+        let (f, i) = location;
+        // SAFETY: location is within the original length of the fragment, and hence, within its capacity
+        unsafe { self.vec.fragments[f].as_mut_ptr().add(i) }
+    }
+
+    /// Returns a pointer to the element at the read cursor; None if all elements are read.
+    #[inline(always)]
+    pub(crate) fn read_ptr(&mut self) -> Option<*mut T> {
+        match self.read < self.original_len {
+            true => Some(self.ptr(self.read_location)),
+            false => None,
+        }
+    }
+
+    /// Returns a pointer to the last kept element, which is the element right before the write cursor; None if there is no such element.
+    #[inline(always)]
+    pub(crate) fn last_kept_ptr(&mut self) -> Option<*mut T> {
+        self.last_kept_location.map(|location| self.ptr(location))
+    }
+
+    /// Keeps the element at the read cursor by moving it to the write cursor, and advances both cursors.
+    pub(crate) fn keep(&mut self) {
+        if self.read != self.write {
+            let src = self.ptr(self.read_location);
+            let dst = self.ptr(self.write_location);
+            // SAFETY: read element is initialized while the write position is either moved out or dropped
+            unsafe { core::ptr::copy_nonoverlapping(src, dst, 1) };
+        }
+
+        self.last_kept_location = Some(self.write_location);
+        self.write += 1;
+        self.write_location = self.advance(self.write_location, 1);
+        self.read += 1;
+        self.read_location = self.advance(self.read_location, 1);
+    }
+
+    /// Discards `count` elements starting at the read cursor by advancing the read cursor;
+    /// the caller is responsible for dropping or moving out the discarded elements.
+    pub(crate) fn discard(&mut self, count: usize) {
+        debug_assert!(self.read + count <= self.original_len);
+        self.read += count;
+        self.read_location = self.advance(self.read_location, count);
+    }
+}
+
+impl<T, G: Growth, A: Allocator> Drop for Compaction<'_, T, G, A> {
+    fn drop(&mut self) {
+        let mut src = self.read_location;
+        let mut dst = self.write_location;
+        let mut remaining = self.original_len - self.read;
+
+        if self.read != self.write {
+            while remaining > 0 {
+                let count = remaining
+                    .min(self.contiguous_len(src))
+                    .min(self.contiguous_len(dst));
+
+                // SAFETY: both ranges are within the original lengths of the fragments; src elements are
+                // initialized while dst elements are either moved out or dropped; ranges might overlap when
+                // they are in the same fragment, hence, `copy` is used
+                let (src_ptr, dst_ptr) = (self.ptr(src), self.ptr(dst));
+                unsafe { core::ptr::copy(src_ptr, dst_ptr, count) };
+
+                src = self.advance(src, count);
+                dst = self.advance(dst, count);
+                remaining -= count;
+            }
+        }
+
+        let new_len = self.original_len - (self.read - self.write);
+        let mut remaining = new_len;
+        let mut num_fragments = 1;
+        for (f, fragment) in self.vec.fragments.iter_mut().enumerate() {
+            let len = remaining.min(self.lengths[f]);
+            // SAFETY: first new_len positions with respect to the original lengths are initialized
+            unsafe { fragment.set_len(len) };
+            remaining -= len;
+            if len > 0 {
+                num_fragments = f + 1;
+            }
+        }
+
+        self.vec.fragments.truncate(num_fragments);
+        self.vec.len = new_len;
+        self.vec
+            .fragments_mutated_from(self.begin_fragment.min(num_fragments));
+    }
+}
+
+/// Returns the location of the `position`-th element of the split vector with fragments with the given `lengths`.
+fn locate(lengths: &[usize], position: usize) -> Location {
+    advance(lengths, (0, 0), position)
+}
+
+/// Returns the location which is `count` positions after the given `location`, skipping the ends of fragments.
+fn advance(lengths: &[usize], location: Location, count: usize) -> Location {
+    let (mut f, mut i) = location;
+    i += count;
+    while f < lengths.len() && i >= lengths[f] {
+        i -= lengths[f];
+        f += 1;
+    }
+    (f, i)
+}
@@ -1 +1,2 @@
 pub mod binary_search;
+pub(crate) mod compaction;
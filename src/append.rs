@@ -0,0 +1,315 @@
+use crate::{Fragment, Growth, IntoFragments, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Consumes and appends `other` vector into this vector.
+    ///
+    /// Fragments of `other` are handed over to this vector without memory copies whenever the growth strategy
+    /// can take ownership of them as they are; see [`Growth::can_append_fragment`].
+    /// * This is always the case with the [`Recursive`] growth, which makes the append a constant time operation.
+    /// * With strategies such as [`Linear`], a fragment is adopted when it has the expected capacity and this vector's last fragment is full;
+    ///   for instance, when appending a split vector with the same linear growth to a vector with full fragments.
+    ///
+    /// Otherwise, elements of the fragment are moved into the existing fragment layout with bulk memory copies.
+    ///
+    /// In either case, the elements already in this vector keep their memory locations.
+    /// Empty fragments are skipped and an empty last fragment of this vector is replaced by the first appended fragment;
+    /// hence, appending never leaves empty fragments in between.
+    ///
+    /// [`Recursive`]: crate::Recursive
+    /// [`Linear`]: crate::Linear
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_split_vec::*;
+    ///
+    /// let mut recursive = SplitVec::with_recursive_growth();
+    ///
+    /// recursive.push('a');
+    /// assert_eq!(recursive, &['a']);
+    ///
+    /// recursive.append(vec!['b', 'c']);
+    /// assert_eq!(recursive, &['a', 'b', 'c']);
+    ///
+    /// recursive.append(vec![vec!['d'], vec!['e', 'f']]);
+    /// assert_eq!(recursive, &['a', 'b', 'c', 'd', 'e', 'f']);
+    ///
+    /// let other_split_vec: SplitVec<_> = vec!['g', 'h'].into();
+    /// recursive.append(other_split_vec);
+    /// assert_eq!(recursive, &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h']);
+    ///
+    /// let mut linear = SplitVec::with_linear_growth(2);
+    /// linear.extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// let mut other = SplitVec::with_linear_growth(2);
+    /// other.extend_from_slice(&[4, 5, 6, 7, 8]);
+    ///
+    /// linear.append(other); // fragments of other are adopted
+    /// assert_eq!(linear, &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(linear.fragments().len(), 3);
+    ///
+    /// linear.append(vec![9, 10]); // elements are copied into the last fragment
+    /// assert_eq!(linear, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// assert_eq!(linear.fragments().len(), 3);
+    /// ```
+    pub fn append<I: IntoFragments<T, A>>(&mut self, other: I) {
+        self.append_fragments(other.into_fragments());
+    }
+
+    /// Moves all elements of `other` into this vector, leaving `other` empty.
+    ///
+    /// This method behaves exactly as [`SplitVec::append`], except that `other` is not consumed;
+    /// hence, it can be used to move elements between split vectors with different growth strategies
+    /// while keeping the `other` vector for further use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let mut other = SplitVec::with_linear_growth(4);
+    /// other.extend_from_slice(&[3, 4, 5, 6]);
+    ///
+    /// vec.append_from(&mut other);
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5, 6]);
+    /// assert!(other.is_empty());
+    ///
+    /// other.push(7);
+    /// vec.append_from(&mut other);
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    pub fn append_from<G2: Growth>(&mut self, other: &mut SplitVec<T, G2, A>) {
+        let fragments = core::mem::take(&mut other.fragments);
+        other.len = 0;
+        other.add_fragment();
+        self.append_fragments(fragments);
+    }
+
+    // helpers
+
+    pub(crate) fn append_fragments(&mut self, fragments: impl IntoIterator<Item = Fragment<T, A>>) {
+        let begin_fragment = self.fragments.len().saturating_sub(1);
+
+        for fragment in fragments.into_iter().filter(|x| !x.is_empty()) {
+            // an empty last fragment is replaced first so that it never remains as an interior fragment
+            match self.can_replace_last_empty_fragment(&fragment) {
+                true => {
+                    _ = self.fragments.pop();
+                    self.append_by_adopting(fragment);
+                }
+                false => match self.growth.can_append_fragment(&self.fragments, &fragment) {
+                    true => self.append_by_adopting(fragment),
+                    false => self.append_by_copy(fragment),
+                },
+            }
+        }
+
+        self.fragments_mutated_from(begin_fragment);
+    }
+
+    fn can_replace_last_empty_fragment(&self, fragment: &Fragment<T, A>) -> bool {
+        match self.fragments.split_last() {
+            Some((last, fragments)) if last.is_empty() => {
+                self.growth.can_append_fragment(fragments, fragment)
+            }
+            _ => false,
+        }
+    }
+
+    fn append_by_adopting(&mut self, fragment: Fragment<T, A>) {
+        self.len += fragment.len();
+        self.fragments.push(fragment);
+    }
+
+    /// Moves elements of the `fragment` to the end of this vector with bulk memory copies,
+    /// filling the room of the last fragment and adding new fragments as required.
+    fn append_by_copy(&mut self, fragment: Fragment<T, A>) {
+        let mut source = fragment.data;
+        let mut copied = 0;
+
+        while copied < source.len() {
+            if !self.has_capacity_for_one() {
+                self.add_fragment();
+            }
+
+            let last = self.fragments.len() - 1;
+            let destination = &mut self.fragments[last];
+            let count = destination.room().min(source.len() - copied);
+
+            // SAFETY: destination has room for count elements and source has count elements starting at copied;
+            // source's length is set to zero below so that the moved elements are not dropped twice
+            unsafe {
+                let src = source.as_ptr().add(copied);
+                let len = destination.len();
+                let dst = destination.as_mut_ptr().add(len);
+                core::ptr::copy_nonoverlapping(src, dst, count);
+                destination.set_len(len + count);
+            }
+
+            copied += count;
+            self.len += count;
+        }
+
+        // SAFETY: all elements of source are moved into self
+        unsafe { source.set_len(0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    #[test]
+    fn append() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..10).map(|x| x.to_string()));
+
+            vec.append((10..13).map(|x| x.to_string()).collect::<Vec<_>>());
+            vec.append(Vec::<String>::new());
+            vec.append(vec![
+                (13..20).map(|x| x.to_string()).collect::<Vec<_>>(),
+                vec![],
+                (20..42).map(|x| x.to_string()).collect::<Vec<_>>(),
+            ]);
+
+            let mut other = SplitVec::with_linear_growth(2);
+            other.extend((42..67).map(|x| x.to_string()));
+            vec.append(other);
+
+            let mut other = SplitVec::with_doubling_growth();
+            other.extend((67..100).map(|x| x.to_string()));
+            vec.append(other);
+
+            assert_eq!(vec.len(), 100);
+            for i in 0..100 {
+                assert_eq!(vec.get(i), Some(&i.to_string()));
+            }
+
+            vec.push(100.to_string());
+            assert_eq!(vec.get(100), Some(&100.to_string()));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn append_leaves_no_empty_interior_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.append(vec![1.to_string(), 2.to_string()]);
+            vec.append(Vec::<String>::new());
+            vec.append(vec![vec![], vec![3.to_string()], vec![]]);
+            vec.append(vec![4.to_string()]);
+
+            let num_fragments = vec.fragments().len();
+            for fragment in &vec.fragments()[..num_fragments - 1] {
+                assert!(!fragment.is_empty());
+            }
+
+            assert_eq!(vec.remove(0), 1.to_string());
+            assert_eq!(vec.pop(), Some(4.to_string()));
+            assert_eq!(vec.remove(1), 3.to_string());
+            assert_eq!(vec.pop(), Some(2.to_string()));
+            assert_eq!(vec.pop(), None);
+            assert!(vec.is_empty());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn append_to_empty_recursive() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        assert_eq!(vec.fragments().len(), 1);
+
+        assert_eq!(vec.remove(0), 0);
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(vec.remove(0), 1);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn append_from() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut other = SplitVec::with_recursive_growth();
+            other.extend(0..33);
+
+            vec.append_from(&mut other);
+            assert!(other.is_empty());
+            assert!(vec.iter().copied().eq(0..33));
+
+            other.extend(33..40);
+            vec.append_from(&mut other);
+            assert!(other.is_empty());
+            assert!(vec.iter().copied().eq(0..40));
+
+            vec.append_from(&mut other);
+            assert!(vec.iter().copied().eq(0..40));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn append_keeps_elements_pinned() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..42);
+            let addresses: Vec<_> = vec.iter().map(|x| x as *const usize).collect();
+
+            vec.append((42..100).collect::<Vec<_>>());
+            assert!(vec.iter().copied().eq(0..100));
+            for (i, address) in addresses.iter().enumerate() {
+                assert_eq!(&vec[i] as *const usize, *address);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn append_adopts_matching_fragments() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(0..8);
+
+        let mut other = SplitVec::with_linear_growth(2);
+        other.extend(8..14);
+        let first = other.fragments()[0].as_ptr();
+        let second = other.fragments()[1].as_ptr();
+
+        vec.append(other);
+        assert!(vec.iter().copied().eq(0..14));
+        assert_eq!(vec.fragments().len(), 4);
+        assert_eq!(vec.fragments()[2].as_ptr(), first);
+        assert_eq!(vec.fragments()[3].as_ptr(), second);
+
+        let mut other = SplitVec::with_linear_growth(3);
+        other.extend(14..30);
+
+        vec.append(other);
+        assert!(vec.iter().copied().eq(0..30));
+        assert!(vec.fragments().iter().all(|f| f.capacity() == 4));
+    }
+
+    #[test]
+    fn append_drops_each_element_once() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend((0..5).map(|_| counter.clone()));
+        vec.append((0..20).map(|_| counter.clone()).collect::<Vec<_>>());
+        assert_eq!(Rc::strong_count(&counter), 26);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+}
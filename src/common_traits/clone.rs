@@ -1,21 +1,28 @@
-use crate::{Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
+use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
 
-impl<T, G> Clone for SplitVec<T, G>
+impl<T, G, A> Clone for SplitVec<T, G, A>
 where
     T: Clone,
     G: Growth,
+    A: Allocator + Clone,
 {
     fn clone(&self) -> Self {
         let mut fragments = Vec::with_capacity(self.fragments.capacity());
 
         for fragment in &self.fragments {
-            let mut vec = Vec::with_capacity(fragment.capacity());
-            vec.extend_from_slice(fragment);
-            fragments.push(vec.into());
+            let mut clone = Fragment::new_in(fragment.capacity(), self.allocator.clone());
+            clone.extend_from_slice(fragment);
+            fragments.push(clone);
         }
 
-        Self::from_raw_parts(self.len(), fragments, self.growth().clone())
+        Self::from_raw_parts_in(
+            self.len,
+            fragments,
+            self.growth().clone(),
+            self.allocator.clone(),
+        )
     }
 }
 
@@ -1,19 +1,19 @@
-use orx_pinned_vec::PinnedVec;
-
 use crate::{Growth, SplitVec};
-use std::fmt::Debug;
+use allocator_api2::alloc::Allocator;
+use core::fmt::Debug;
 
-impl<T, G> Debug for SplitVec<T, G>
+impl<T, G, A> Debug for SplitVec<T, G, A>
 where
     T: Debug,
     G: Growth,
+    A: Allocator,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(
             f,
             "SplitVec {{ len: {}, capacity:{}, data: [",
-            self.len(),
-            self.capacity()
+            self.len,
+            self.fragments.iter().map(|f| f.capacity()).sum::<usize>()
         )?;
         for frag in &self.fragments {
             writeln!(f, "    {:?}", frag)?;
@@ -1,6 +1,8 @@
 use crate::*;
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
 
-impl<T, G, U> PartialEq<U> for SplitVec<T, G>
+impl<T, G, U, A: Allocator> PartialEq<U> for SplitVec<T, G, A>
 where
     U: AsRef<[T]>,
     T: PartialEq,
@@ -11,40 +13,40 @@ where
     }
 }
 
-impl<T: PartialEq, G> PartialEq<SplitVec<T, G>> for [T]
+impl<T: PartialEq, G, A: Allocator> PartialEq<SplitVec<T, G, A>> for [T]
 where
     G: Growth,
 {
-    fn eq(&self, other: &SplitVec<T, G>) -> bool {
+    fn eq(&self, other: &SplitVec<T, G, A>) -> bool {
         are_fragments_eq_to_slice(&other.fragments, self)
     }
 }
 
-impl<T: PartialEq, G> PartialEq<SplitVec<T, G>> for Vec<T>
+impl<T: PartialEq, G, A: Allocator> PartialEq<SplitVec<T, G, A>> for Vec<T>
 where
     G: Growth,
 {
-    fn eq(&self, other: &SplitVec<T, G>) -> bool {
+    fn eq(&self, other: &SplitVec<T, G, A>) -> bool {
         are_fragments_eq_to_slice(&other.fragments, self)
     }
 }
 
-impl<T: PartialEq, G, const N: usize> PartialEq<SplitVec<T, G>> for [T; N]
+impl<T: PartialEq, G, A: Allocator, const N: usize> PartialEq<SplitVec<T, G, A>> for [T; N]
 where
     G: Growth,
 {
-    fn eq(&self, other: &SplitVec<T, G>) -> bool {
+    fn eq(&self, other: &SplitVec<T, G, A>) -> bool {
         are_fragments_eq_to_slice(&other.fragments, self)
     }
 }
 
-impl<T: PartialEq, G> PartialEq<SplitVec<T, G>> for SplitVec<T, G>
+impl<T: PartialEq, G, A: Allocator> PartialEq<SplitVec<T, G, A>> for SplitVec<T, G, A>
 where
     G: Growth,
 {
-    fn eq(&self, other: &SplitVec<T, G>) -> bool {
-        let mut iter1 = self.iter();
-        let mut iter2 = other.iter();
+    fn eq(&self, other: &SplitVec<T, G, A>) -> bool {
+        let mut iter1 = Iter::with_growth(&self.fragments, &self.growth, self.len);
+        let mut iter2 = Iter::with_growth(&other.fragments, &other.growth, other.len);
         loop {
             match (iter1.next(), iter2.next()) {
                 (Some(x), Some(y)) => {
@@ -59,10 +61,10 @@ where
     }
 }
 
-impl<T: PartialEq, G: Growth> Eq for SplitVec<T, G> {}
+impl<T: PartialEq, G: Growth, A: Allocator> Eq for SplitVec<T, G, A> {}
 
-pub(crate) fn are_fragments_eq_to_slice<T: PartialEq>(
-    fragments: &[Fragment<T>],
+pub(crate) fn are_fragments_eq_to_slice<T: PartialEq, A: Allocator>(
+    fragments: &[Fragment<T, A>],
     slice: &[T],
 ) -> bool {
     let mut slice_beg = 0;
@@ -1,9 +1,14 @@
-use crate::{Growth, SplitVec};
-use std::ops::{Index, IndexMut};
+use crate::{range_helpers::range_bounds, Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+use core::ops::{
+    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
 
-impl<T, G> Index<usize> for SplitVec<T, G>
+impl<T, G, A> Index<usize> for SplitVec<T, G, A>
 where
     G: Growth,
+    A: Allocator,
 {
     type Output = T;
 
@@ -34,9 +39,10 @@ where
     }
 }
 
-impl<T, G> IndexMut<usize> for SplitVec<T, G>
+impl<T, G, A> IndexMut<usize> for SplitVec<T, G, A>
 where
     G: Growth,
+    A: Allocator,
 {
     /// Returns a mutable reference to the `index`-th item of the vector.
     ///
@@ -67,9 +73,10 @@ where
     }
 }
 
-impl<T, G> Index<(usize, usize)> for SplitVec<T, G>
+impl<T, G, A> Index<(usize, usize)> for SplitVec<T, G, A>
 where
     G: Growth,
+    A: Allocator,
 {
     type Output = T;
 
@@ -126,9 +133,10 @@ where
         &self.fragments[fragment_and_inner_index.0][fragment_and_inner_index.1]
     }
 }
-impl<T, G> IndexMut<(usize, usize)> for SplitVec<T, G>
+impl<T, G, A> IndexMut<(usize, usize)> for SplitVec<T, G, A>
 where
     G: Growth,
+    A: Allocator,
 {
     /// One can treat the split vector as a jagged array
     /// and access an item with (fragment_index, inner_fragment_index)
@@ -187,10 +195,94 @@ where
     }
 }
 
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Returns the index of the fragment and the range within the fragment of the elements in the `range`;
+    /// None if the range is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the same messages as `Vec` if the range is out of bounds,
+    /// or if the elements in the range belong to more than one fragment.
+    fn fragment_range<R: RangeBounds<usize>>(&self, range: R) -> Option<(usize, Range<usize>)> {
+        let (a, b) = range_bounds(&range, self.len);
+        match a < b {
+            true => {
+                let (sf, si) = self.get_fragment_and_inner_indices(a)?;
+                let (ef, ei) = self.get_fragment_and_inner_indices(b - 1)?;
+                assert!(
+                    sf == ef,
+                    "range {}..{} spans fragments {} to {}; use `SplitVec::view` to access elements of multiple fragments",
+                    a,
+                    b,
+                    sf,
+                    ef
+                );
+                Some((sf, si..(ei + 1)))
+            }
+            false => None,
+        }
+    }
+}
+
+macro_rules! impl_index_range {
+    ($($range:ty),*) => {
+        $(
+            impl<T, G: Growth, A: Allocator> Index<$range> for SplitVec<T, G, A> {
+                type Output = [T];
+
+                /// Returns the slice of the elements in the `range`, which must belong to a single fragment.
+                ///
+                /// Ranges spanning multiple fragments cannot be represented as a slice;
+                /// [`SplitVec::view`] can be used to access elements of any range.
+                ///
+                /// # Panics
+                ///
+                /// Panics with the same messages as `Vec` if the range is out of bounds,
+                /// or if the elements in the range belong to more than one fragment.
+                fn index(&self, range: $range) -> &Self::Output {
+                    match self.fragment_range(range) {
+                        Some((f, range)) => &self.fragments[f][range],
+                        None => &[],
+                    }
+                }
+            }
+
+            impl<T, G: Growth, A: Allocator> IndexMut<$range> for SplitVec<T, G, A> {
+                /// Returns the mutable slice of the elements in the `range`, which must belong to a single fragment.
+                ///
+                /// Ranges spanning multiple fragments cannot be represented as a slice;
+                /// [`SplitVec::view_mut`] can be used to access elements of any range.
+                ///
+                /// # Panics
+                ///
+                /// Panics with the same messages as `Vec` if the range is out of bounds,
+                /// or if the elements in the range belong to more than one fragment.
+                fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                    match self.fragment_range(range) {
+                        Some((f, range)) => &mut self.fragments[f][range],
+                        None => &mut [],
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>)
+);
+
 #[cfg(test)]
 mod tests {
     use crate::test_all_growth_types;
     use crate::*;
+    use core::ops::Bound;
 
     #[test]
     fn index() {
@@ -231,4 +323,67 @@ mod tests {
         }
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn range_index() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            let expected: Vec<_> = (0..100).collect();
+
+            for f in 0..vec.fragments().len() {
+                let begin: usize = vec.fragments()[..f].iter().map(|x| x.len()).sum();
+                let end = begin + vec.fragments()[f].len();
+
+                assert_eq!(&vec[begin..end], &expected[begin..end]);
+                assert_eq!(&vec[begin..=(end - 1)], &expected[begin..end]);
+                assert_eq!(&vec[(begin + 1)..end], &expected[(begin + 1)..end]);
+                assert_eq!(&vec[end..end], &[]);
+
+                vec[begin..end].iter_mut().for_each(|x| *x += 1000);
+                vec[begin..=(end - 1)].iter_mut().for_each(|x| *x -= 1000);
+            }
+            assert_eq!(vec, expected.as_slice());
+
+            let first_len = vec.fragments()[0].len();
+            assert_eq!(&vec[..first_len], &expected[..first_len]);
+            assert_eq!(&vec[..=(first_len - 1)], &expected[..first_len]);
+
+            let last_len = vec.fragments()[vec.fragments().len() - 1].len();
+            assert_eq!(&vec[(100 - last_len)..], &expected[(100 - last_len)..]);
+            assert_eq!(&vec[100..], &[]);
+            assert_eq!(
+                &vec[(Bound::Excluded(0), Bound::Included(first_len - 1))],
+                &expected[1..first_len]
+            );
+        }
+        test_all_growth_types!(test);
+
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend_from_slice(&[0, 1, 2]);
+        assert_eq!(&vec[..], &[0, 1, 2]);
+        vec[..].reverse();
+        assert_eq!(vec, &[2, 1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "use `SplitVec::view`")]
+    fn range_index_multiple_fragments() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let _ = &vec[2..6];
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 11 out of range for slice of length 10")]
+    fn range_index_out_of_bounds() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let _ = &vec[8..11];
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 5 but ends at 3")]
+    fn range_index_decreasing() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = &mut vec[5..3];
+    }
 }
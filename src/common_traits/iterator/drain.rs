@@ -0,0 +1,394 @@
+use crate::algorithms::compaction::{Compaction, Location};
+use crate::range_helpers::{range_end, range_start};
+use crate::{Growth, SplitVec};
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
+use core::ops::RangeBounds;
+
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Removes the specified range from the vector in bulk, returning all removed elements as an iterator.
+    ///
+    /// When the iterator is dropped, all elements in the range are removed from the vector,
+    /// even if the iterator was not fully consumed.
+    /// The gap is then closed by a single pass of bulk moves of the elements following the range through the fragments.
+    ///
+    /// If the iterator is leaked (e.g., `std::mem::forget`), the vector is left containing only the elements before the range.
+    ///
+    /// # Pinned elements
+    ///
+    /// Elements before `range.start` keep their memory locations.
+    /// However, similar to `remove`, elements following the drained range are moved towards the front to close the gap;
+    /// therefore, their memory locations are not kept and any pointers to these elements are invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let drained: Vec<_> = vec.drain(2..7).collect();
+    /// assert_eq!(drained, &[2, 3, 4, 5, 6]);
+    /// assert_eq!(vec, &[0, 1, 7, 8, 9]);
+    ///
+    /// let mut drain = vec.drain(1..);
+    /// assert_eq!(drain.next_back(), Some(9));
+    /// assert_eq!(drain.next(), Some(1));
+    /// drop(drain);
+    /// assert_eq!(vec, &[0]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, G, A> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len);
+        assert!(
+            a <= b,
+            "drain range start (is {}) should be <= end (is {})",
+            a,
+            b
+        );
+        assert!(
+            b <= self.len,
+            "drain range end (is {}) should be <= len (is {})",
+            b,
+            self.len
+        );
+
+        Drain::new(self, a, b)
+    }
+
+    /// Removes the elements in the specified range from the vector in bulk.
+    ///
+    /// This is equivalent to dropping the iterator returned by [`SplitVec::drain`] immediately;
+    /// the removed elements are dropped and the gap is closed by a single pass of bulk moves through the fragments.
+    ///
+    /// Please see the pinned elements remark of [`SplitVec::drain`];
+    /// elements following the removed range are moved and do not keep their memory locations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// vec.remove_range(3..=5);
+    /// assert_eq!(vec, &[0, 1, 2, 6, 7, 8, 9]);
+    ///
+    /// vec.remove_range(..2);
+    /// assert_eq!(vec, &[2, 6, 7, 8, 9]);
+    /// ```
+    pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        drop(self.drain(range));
+    }
+}
+
+/// A draining iterator for `SplitVec`.
+///
+/// This struct is created by `SplitVec::drain()` method.
+pub struct Drain<'a, T, G: Growth, A: Allocator = Global> {
+    compaction: Compaction<'a, T, G, A>,
+    front: usize,
+    front_location: Location,
+    back: usize,
+    back_location: Location,
+}
+
+impl<'a, T, G: Growth, A: Allocator> Drain<'a, T, G, A> {
+    fn new(vec: &'a mut SplitVec<T, G, A>, range_start: usize, range_end: usize) -> Self {
+        let mut compaction = Compaction::new(vec, range_start);
+        // elements of the range are discarded from the vector; they are either yielded or dropped by the drain
+        compaction.discard(range_end - range_start);
+
+        let front_location = compaction.locate(range_start);
+        let back_location = compaction.locate(range_end);
+
+        Self {
+            compaction,
+            front: range_start,
+            front_location,
+            back: range_end,
+            back_location,
+        }
+    }
+
+    /// Drops the elements of the range which are not yet yielded, one contiguous chunk at a time.
+    fn drop_remaining(&mut self) {
+        while self.front < self.back {
+            let location = self.front_location;
+            let count = (self.back - self.front).min(self.compaction.contiguous_len(location));
+            self.front += count;
+            self.front_location = self.compaction.advance(location, count);
+
+            // SAFETY: remaining elements are initialized and are never read again
+            let slice = core::ptr::slice_from_raw_parts_mut(self.compaction.ptr(location), count);
+            unsafe { core::ptr::drop_in_place(slice) };
+        }
+    }
+}
+
+impl<T, G: Growth, A: Allocator> Iterator for Drain<'_, T, G, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front < self.back {
+            true => {
+                let location = self.front_location;
+                self.front += 1;
+                self.front_location = self.compaction.advance(location, 1);
+                // SAFETY: the element is initialized and will not be read again
+                Some(unsafe { self.compaction.ptr(location).read() })
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, G: Growth, A: Allocator> DoubleEndedIterator for Drain<'_, T, G, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.front < self.back {
+            true => {
+                let location = self.compaction.retreat(self.back_location);
+                self.back -= 1;
+                self.back_location = location;
+                // SAFETY: the element is initialized and will not be read again
+                Some(unsafe { self.compaction.ptr(location).read() })
+            }
+            false => None,
+        }
+    }
+}
+
+impl<T, G: Growth, A: Allocator> ExactSizeIterator for Drain<'_, T, G, A> {}
+
+impl<T, G: Growth, A: Allocator> FusedIterator for Drain<'_, T, G, A> {}
+
+impl<T, G: Growth, A: Allocator> Drop for Drain<'_, T, G, A> {
+    fn drop(&mut self) {
+        struct DropGuard<'r, 'a, T, G: Growth, A: Allocator>(&'r mut Drain<'a, T, G, A>);
+
+        impl<T, G: Growth, A: Allocator> Drop for DropGuard<'_, '_, T, G, A> {
+            fn drop(&mut self) {
+                self.0.drop_remaining();
+            }
+        }
+
+        // remaining elements are dropped even if dropping one of them panics; then,
+        // the gap is closed when the compaction is dropped
+        let guard = DropGuard(self);
+        guard.0.drop_remaining();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn drain() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let ranges = [
+                (0, 0),
+                (0, 3),
+                (4, 4),
+                (5, 13),
+                (10, 40),
+                (37, 100),
+                (0, 100),
+            ];
+            for (a, b) in ranges {
+                vec.clear();
+                vec.extend((0..100).map(|x| x.to_string()));
+                let mut expected: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+
+                let drained: Vec<_> = vec.drain(a..b).collect();
+                let expected_drained: Vec<_> = expected.drain(a..b).collect();
+
+                assert_eq!(drained, expected_drained);
+                assert_eq!(vec, expected.as_slice());
+                for (i, x) in expected.iter().enumerate() {
+                    assert_eq!(vec.get(i), Some(x));
+                }
+
+                vec.push("x".to_string());
+                assert_eq!(vec.get(expected.len()), Some(&"x".to_string()));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_double_ended() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..50);
+
+            let mut drain = vec.drain(3..47);
+            assert_eq!(drain.len(), 44);
+            let mut drained = vec![];
+            let mut drained_back = vec![];
+            for i in 0..44 {
+                match i % 3 {
+                    0 => drained_back.push(drain.next_back().expect("is-some")),
+                    _ => drained.push(drain.next().expect("is-some")),
+                }
+                assert_eq!(drain.len(), 43 - i);
+            }
+            assert_eq!(drain.next(), None);
+            assert_eq!(drain.next_back(), None);
+            drop(drain);
+
+            drained.extend(drained_back.into_iter().rev());
+            assert_eq!(drained, (3..47).collect::<Vec<_>>());
+            assert_eq!(vec, &[0, 1, 2, 47, 48, 49]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_partially_consumed() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..40).map(|x| x.to_string()));
+
+            let mut drain = vec.drain(5..30);
+            assert_eq!(drain.next(), Some(5.to_string()));
+            assert_eq!(drain.next_back(), Some(29.to_string()));
+            drop(drain);
+
+            let expected: Vec<_> = (0..5).chain(30..40).map(|x| x.to_string()).collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_with_appended_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(Vec::<usize>::new());
+        vec.append(vec![3, 4, 5, 6, 7]);
+        vec.append(vec![8, 9]);
+
+        vec.remove_range(2..5);
+        assert_eq!(vec, &[0, 1, 5, 6, 7, 8, 9]);
+        for i in 0..vec.len() {
+            assert_eq!(vec.get(i), Some(&[0, 1, 5, 6, 7, 8, 9][i]));
+        }
+
+        vec.remove_range(4..);
+        assert_eq!(vec, &[0, 1, 5, 6]);
+
+        vec.append(vec![10, 11]);
+        assert_eq!(vec, &[0, 1, 5, 6, 10, 11]);
+        assert_eq!(vec.get(5), Some(&11));
+    }
+
+    #[test]
+    fn remove_range() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            let mut expected: Vec<_> = (0..100).collect();
+
+            let ranges = [(90, 100), (0, 5), (17, 23), (20, 21), (40, 70), (0, 0)];
+            for (a, b) in ranges {
+                vec.remove_range(a..b);
+                expected.drain(a..b);
+                assert_eq!(vec, expected.as_slice());
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_keeps_prefix_pinned() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            let addresses: Vec<_> = vec.iter().take(20).map(|x| x as *const usize).collect();
+
+            vec.remove_range(20..50);
+            for (i, address) in addresses.iter().enumerate() {
+                assert_eq!(&vec[i] as *const usize, *address);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn drain_drops_each_element_once() {
+        let counter = Rc::new(());
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend((0..30).map(|_| counter.clone()));
+
+        let mut drain = vec.drain(5..25);
+        _ = drain.next();
+        _ = drain.next_back();
+        drop(drain);
+        assert_eq!(Rc::strong_count(&counter), 11);
+        assert_eq!(vec.len(), 10);
+
+        let leaked = vec.drain(2..5);
+        core::mem::forget(leaked);
+        assert_eq!(vec.len(), 2);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1 + 8);
+    }
+
+    #[test]
+    fn drain_panic_while_dropping() {
+        struct PanicOnDrop<'a> {
+            value: usize,
+            num_dropped: &'a Cell<usize>,
+        }
+
+        impl Drop for PanicOnDrop<'_> {
+            #[allow(clippy::panic)]
+            fn drop(&mut self) {
+                self.num_dropped.set(self.num_dropped.get() + 1);
+                if self.value == 10 {
+                    panic!("panic while dropping");
+                }
+            }
+        }
+
+        let num_dropped = Cell::new(0);
+        let mut vec = SplitVec::with_linear_growth(2);
+        for value in 0..20 {
+            vec.push(PanicOnDrop {
+                value,
+                num_dropped: &num_dropped,
+            });
+        }
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.remove_range(5..15);
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(num_dropped.get(), 10);
+        assert_eq!(vec.len(), 10);
+        let values: Vec<_> = vec.iter().map(|x| x.value).collect();
+        assert_eq!(values, &[0, 1, 2, 3, 4, 15, 16, 17, 18, 19]);
+    }
+}
@@ -1,6 +1,8 @@
 use super::iter::Iter;
+use crate::Growth;
+use allocator_api2::alloc::Allocator;
 
-impl<'a, T: PartialEq> PartialEq for Iter<'a, T> {
+impl<'a, T: PartialEq, A: Allocator, G: Growth> PartialEq for Iter<'a, T, A, G> {
     fn eq(&self, other: &Self) -> bool {
         let iter1 = self.clone();
         let mut iter2 = other.clone();
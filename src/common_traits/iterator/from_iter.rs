@@ -1,22 +1,20 @@
 use crate::{Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
+use allocator_api2::alloc::Allocator;
 
-impl<T, G: Growth> FromIterator<T> for SplitVec<T, G>
+impl<T, G: Growth, A: Allocator + Clone> FromIterator<T> for SplitVec<T, G, A>
 where
-    SplitVec<T, G>: Default,
+    SplitVec<T, G, A>: Default,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut vec = Self::default();
-        for i in iter {
-            vec.push(i)
-        }
+        vec.extend(iter);
         vec
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Doubling, Recursive, SplitVec};
+    use crate::{ConstLinear, Doubling, DoublingFrom, Growth, Recursive, SplitVec};
 
     #[test]
     fn collect() {
@@ -44,4 +42,23 @@ mod tests {
         let vec: SplitVec<_, Recursive> = (0..6).filter(|x| x % 2 == 0).collect();
         assert_eq!(&vec, &[0, 2, 4]);
     }
+
+    #[test]
+    fn collect_many() {
+        fn test<G: Growth>()
+        where
+            SplitVec<String, G>: Default,
+        {
+            let vec: SplitVec<_, G> = (0..1000).map(|x| x.to_string()).collect();
+            assert_eq!(1000, vec.len());
+            for (i, x) in vec.iter().enumerate() {
+                assert_eq!(x, &i.to_string());
+            }
+        }
+
+        test::<Doubling>();
+        test::<DoublingFrom<3>>();
+        test::<Recursive>();
+        test::<ConstLinear<3>>();
+    }
 }
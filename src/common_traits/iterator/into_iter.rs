@@ -1,66 +1,176 @@
 use crate::{Fragment, Growth, SplitVec};
-use std::iter::FusedIterator;
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
 
-impl<T, G: Growth> IntoIterator for SplitVec<T, G> {
+impl<T, G: Growth, A: Allocator + Clone> IntoIterator for SplitVec<T, G, A> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter::new(self.fragments)
+        Self::IntoIter::new(self.fragments, self.allocator)
     }
 }
 
 /// An iterator that moves out of a vector.
 ///
 /// This struct is created by the `into_iter` method on `SplitVec` (provided by the `IntoIterator` trait).
-pub struct IntoIter<T> {
-    outer: std::vec::IntoIter<Fragment<T>>,
-    inner: std::vec::IntoIter<T>,
+pub struct IntoIter<T, A: Allocator = Global> {
+    outer: alloc::vec::IntoIter<Fragment<T, A>>,
+    inner: allocator_api2::vec::IntoIter<T, A>,
+    inner_back: allocator_api2::vec::IntoIter<T, A>,
+    len: usize,
 }
 
-impl<T> IntoIter<T> {
-    pub(crate) fn new(fragments: Vec<Fragment<T>>) -> Self {
+impl<T, A: Allocator + Clone> IntoIter<T, A> {
+    pub(crate) fn new(fragments: Vec<Fragment<T, A>>, allocator: A) -> Self {
+        let len = fragments.iter().map(|x| x.len()).sum();
         let mut outer = fragments.into_iter();
         let inner = outer
             .next()
             .map(|f| f.data.into_iter())
-            .unwrap_or(vec![].into_iter());
+            .unwrap_or_else(|| allocator_api2::vec::Vec::new_in(allocator.clone()).into_iter());
 
-        Self { outer, inner }
+        Self {
+            outer,
+            inner,
+            inner_back: allocator_api2::vec::Vec::new_in(allocator).into_iter(),
+            len,
+        }
     }
+}
 
+impl<T, A: Allocator> IntoIter<T, A> {
     fn next_fragment(&mut self) -> Option<T> {
-        match self.outer.next() {
-            Some(f) => {
-                self.inner = f.data.into_iter();
-                self.next()
+        loop {
+            match self.outer.next() {
+                Some(f) => {
+                    self.inner = f.data.into_iter();
+                    if let Some(x) = self.inner.next() {
+                        return Some(x);
+                    }
+                }
+                None => return self.inner_back.next(),
+            }
+        }
+    }
+
+    fn next_back_fragment(&mut self) -> Option<T> {
+        loop {
+            match self.outer.next_back() {
+                Some(f) => {
+                    self.inner_back = f.data.into_iter();
+                    if let Some(x) = self.inner_back.next_back() {
+                        return Some(x);
+                    }
+                }
+                None => return self.inner.next_back(),
             }
-            None => None,
         }
     }
 }
 
-impl<T: Clone> Clone for IntoIter<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for IntoIter<T, A> {
     fn clone(&self) -> Self {
         Self {
             outer: self.outer.clone(),
             inner: self.inner.clone(),
+            inner_back: self.inner_back.clone(),
+            len: self.len,
         }
     }
 }
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         let next_element = self.inner.next();
-        if next_element.is_some() {
-            next_element
-        } else {
-            self.next_fragment()
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        next_element
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.len = self.len.saturating_sub(n.saturating_add(1));
+
+        let mut n = n;
+        if n < self.inner.len() {
+            return self.inner.nth(n);
         }
+        n -= self.inner.len();
+        self.inner.by_ref().for_each(drop);
+
+        for fragment in self.outer.by_ref() {
+            if n < fragment.len() {
+                self.inner = fragment.data.into_iter();
+                return self.inner.nth(n);
+            }
+            n -= fragment.len();
+        }
+
+        self.inner_back.nth(n)
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.inner_back.next_back();
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_back_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        next_element
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len = self.len.saturating_sub(n.saturating_add(1));
+
+        let mut n = n;
+        if n < self.inner_back.len() {
+            return self.inner_back.nth_back(n);
+        }
+        n -= self.inner_back.len();
+        self.inner_back.by_ref().for_each(drop);
+
+        while let Some(fragment) = self.outer.next_back() {
+            if n < fragment.len() {
+                self.inner_back = fragment.data.into_iter();
+                return self.inner_back.nth_back(n);
+            }
+            n -= fragment.len();
+        }
+
+        self.inner.nth_back(n)
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
     }
 }
 
-impl<T> FusedIterator for IntoIter<T> {}
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
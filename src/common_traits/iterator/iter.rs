@@ -1,55 +1,200 @@
 use super::reductions;
-use crate::fragment::fragment_struct::Fragment;
-use std::iter::FusedIterator;
+use crate::{fragment::fragment_struct::Fragment, Doubling, Growth};
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
 
 /// Iterator over the `SplitVec`.
 ///
 /// This struct is created by `SplitVec::iter()` method.
+///
+/// The iterator holds a reference to the growth `G` of the vector, which allows it to jump to the target element
+/// in constant time in `nth` calls whenever the growth allows for constant time access.
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct Iter<'a, T> {
-    outer: std::slice::Iter<'a, Fragment<T>>,
-    inner: std::slice::Iter<'a, T>,
+pub struct Iter<'a, T, A: Allocator = Global, G: Growth = Doubling> {
+    outer: core::slice::Iter<'a, Fragment<T, A>>,
+    inner: core::slice::Iter<'a, T>,
+    inner_back: core::slice::Iter<'a, T>,
+    len: usize,
+    front_index: usize,
+    outer_end: usize,
+    growth: Option<&'a G>,
 }
 
-impl<'a, T> Iter<'a, T> {
-    pub(crate) fn new(fragments: &'a [Fragment<T>]) -> Self {
+impl<'a, T, A: Allocator, G: Growth> Iter<'a, T, A, G> {
+    /// Creates the iterator over the `fragments` with `len` elements in total, which jumps to the target element
+    /// in constant time in `nth` calls whenever the `growth` allows for constant time access.
+    pub(crate) fn with_growth(fragments: &'a [Fragment<T, A>], growth: &'a G, len: usize) -> Self {
+        debug_assert_eq!(len, fragments.iter().map(|x| x.len()).sum::<usize>());
         let mut outer = fragments.iter();
         let inner = outer.next().map(|x| x.iter()).unwrap_or([].iter());
-        Self { outer, inner }
+        Self {
+            outer,
+            inner,
+            inner_back: [].iter(),
+            len,
+            front_index: 0,
+            outer_end: fragments.len(),
+            growth: Some(growth),
+        }
+    }
+
+    /// Creates the iterator over elements of the `front` slice, followed by elements of the `fragments` and elements of the `back` slice.
+    pub(crate) fn from_parts(
+        front: &'a [T],
+        fragments: &'a [Fragment<T, A>],
+        back: &'a [T],
+    ) -> Self {
+        let fragments_len: usize = fragments.iter().map(|x| x.len()).sum();
+        Self {
+            outer: fragments.iter(),
+            inner: front.iter(),
+            inner_back: back.iter(),
+            len: front.len() + fragments_len + back.len(),
+            front_index: 0,
+            outer_end: fragments.len(),
+            growth: None,
+        }
     }
 
     fn next_fragment(&mut self) -> Option<&'a T> {
-        match self.outer.next() {
-            Some(f) => {
-                self.inner = f.iter();
-                self.next()
+        loop {
+            match self.outer.next() {
+                Some(f) => {
+                    self.inner = f.iter();
+                    if let Some(x) = self.inner.next() {
+                        return Some(x);
+                    }
+                }
+                None => return self.inner_back.next(),
+            }
+        }
+    }
+
+    fn remaining_len(&self) -> usize {
+        let outer_len: usize = self.outer.as_slice().iter().map(|x| x.len()).sum();
+        self.inner.len() + outer_len + self.inner_back.len()
+    }
+
+    fn next_back_fragment(&mut self) -> Option<&'a T> {
+        loop {
+            match self.outer.next_back() {
+                Some(f) => {
+                    self.outer_end -= 1;
+                    self.inner_back = f.iter();
+                    if let Some(x) = self.inner_back.next_back() {
+                        return Some(x);
+                    }
+                }
+                None => return self.inner.next_back(),
+            }
+        }
+    }
+
+    /// Returns the `n`-th remaining element by skipping whole fragments until the one containing it.
+    fn scan(&mut self, n: usize) -> Option<&'a T> {
+        let mut n = n;
+        if n < self.inner.len() {
+            return self.inner.nth(n);
+        }
+        n -= self.inner.len();
+        self.inner = [].iter();
+
+        for fragment in self.outer.by_ref() {
+            if n < fragment.len() {
+                self.inner = fragment.iter();
+                return self.inner.nth(n);
+            }
+            n -= fragment.len();
+        }
+
+        self.inner_back.nth(n)
+    }
+
+    /// Returns the `n`-th remaining element, which is known to be located at the `i`-th position of the `f`-th fragment,
+    /// by directly jumping to the fragment.
+    fn jump(&mut self, n: usize, f: usize, i: usize) -> Option<&'a T> {
+        let outer_begin = self.outer_end - self.outer.len();
+        debug_assert!(f >= outer_begin);
+
+        match f < self.outer_end {
+            true => {
+                let fragment = self.outer.nth(f - outer_begin)?;
+                self.inner = fragment.iter();
+                self.inner.nth(i)
+            }
+            false => {
+                let outer_len = self.len - self.inner.len() - self.inner_back.len();
+                let n = n - self.inner.len() - outer_len;
+                self.inner = [].iter();
+                self.outer = [].iter();
+                self.inner_back.nth(n)
             }
-            None => None,
         }
     }
 }
 
-impl<'a, T> Clone for Iter<'a, T> {
+impl<'a, T, A: Allocator, G: Growth> Clone for Iter<'a, T, A, G> {
     fn clone(&self) -> Self {
         Self {
             outer: self.outer.clone(),
             inner: self.inner.clone(),
+            inner_back: self.inner_back.clone(),
+            len: self.len,
+            front_index: self.front_index,
+            outer_end: self.outer_end,
+            growth: self.growth,
         }
     }
 }
 
-impl<'a, T> Iterator for Iter<'a, T> {
+impl<'a, T, A: Allocator, G: Growth> Iterator for Iter<'a, T, A, G> {
     type Item = &'a T;
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         let next_element = self.inner.next();
-        if next_element.is_some() {
-            next_element
-        } else {
-            self.next_fragment()
-        }
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        self.front_index += next_element.is_some() as usize;
+        next_element
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let location = match n < self.inner.len() || n >= self.len {
+            true => None,
+            false => self.growth.and_then(|x| {
+                x.get_fragment_and_inner_indices_in_constant_time(self.front_index + n)
+            }),
+        };
+
+        let element = match location {
+            Some((f, i)) => self.jump(n, f, i),
+            None => self.scan(n),
+        };
+
+        let consumed = n.saturating_add(1).min(self.len);
+        self.len -= consumed;
+        self.front_index += consumed;
+        element
     }
 
     // reductions
@@ -58,7 +203,11 @@ impl<'a, T> Iterator for Iter<'a, T> {
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
     {
-        reductions::all(&mut self.outer, &mut self.inner, f)
+        let result = reductions::all(&mut self.outer, &mut self.inner, &mut self.inner_back, f);
+        let len = self.remaining_len();
+        self.front_index += self.len - len;
+        self.len = len;
+        result
     }
 
     fn any<F>(&mut self, f: F) -> bool
@@ -66,7 +215,11 @@ impl<'a, T> Iterator for Iter<'a, T> {
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
     {
-        reductions::any(&mut self.outer, &mut self.inner, f)
+        let result = reductions::any(&mut self.outer, &mut self.inner, &mut self.inner_back, f);
+        let len = self.remaining_len();
+        self.front_index += self.len - len;
+        self.len = len;
+        result
     }
 
     fn fold<B, F>(mut self, init: B, f: F) -> B
@@ -74,8 +227,56 @@ impl<'a, T> Iterator for Iter<'a, T> {
         Self: Sized,
         F: FnMut(B, Self::Item) -> B,
     {
-        reductions::fold(&mut self.outer, &mut self.inner, init, f)
+        reductions::fold(
+            &mut self.outer,
+            &mut self.inner,
+            &mut self.inner_back,
+            init,
+            f,
+        )
+    }
+}
+
+impl<T, A: Allocator, G: Growth> DoubleEndedIterator for Iter<'_, T, A, G> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.inner_back.next_back();
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_back_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        next_element
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len = self.len.saturating_sub(n.saturating_add(1));
+
+        let mut n = n;
+        if n < self.inner_back.len() {
+            return self.inner_back.nth_back(n);
+        }
+        n -= self.inner_back.len();
+        self.inner_back = [].iter();
+
+        while let Some(fragment) = self.outer.next_back() {
+            self.outer_end -= 1;
+            if n < fragment.len() {
+                self.inner_back = fragment.iter();
+                return self.inner_back.nth_back(n);
+            }
+            n -= fragment.len();
+        }
+
+        self.inner.nth_back(n)
+    }
+}
+
+impl<T, A: Allocator, G: Growth> ExactSizeIterator for Iter<'_, T, A, G> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
     }
 }
 
-impl<T> FusedIterator for Iter<'_, T> {}
+impl<T, A: Allocator, G: Growth> FusedIterator for Iter<'_, T, A, G> {}
@@ -1,22 +1,23 @@
 use super::iter_fragment::FragmentIter;
 use crate::fragment::fragment_struct::Fragment;
-use std::iter::FusedIterator;
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
 
 /// Iterator over the `SplitVec`.
 ///
 /// This struct is created by `SplitVec::iter()` method.
 #[derive(Debug, Clone)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IterCon<'a, T> {
+pub struct IterCon<'a, T, A: Allocator = Global> {
     num_fragments: usize,
     last_fragment_len: usize,
-    fragments: &'a [Fragment<T>],
+    fragments: &'a [Fragment<T, A>],
     inner: FragmentIter<'a, T>,
     f: usize,
 }
 
-impl<'a, T> IterCon<'a, T> {
-    pub(crate) fn new(fragments: &'a [Fragment<T>], last_fragment_len: usize) -> Self {
+impl<'a, T, A: Allocator> IterCon<'a, T, A> {
+    pub(crate) fn new(fragments: &'a [Fragment<T, A>], last_fragment_len: usize) -> Self {
         assert!(!fragments.is_empty());
 
         let num_fragments = fragments.len();
@@ -53,7 +54,7 @@ impl<'a, T> IterCon<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for IterCon<'a, T> {
+impl<'a, T, A: Allocator> Iterator for IterCon<'a, T, A> {
     type Item = &'a T;
 
     #[inline(always)]
@@ -66,4 +67,4 @@ impl<'a, T> Iterator for IterCon<'a, T> {
     }
 }
 
-impl<T> FusedIterator for IterCon<'_, T> {}
+impl<T, A: Allocator> FusedIterator for IterCon<'_, T, A> {}
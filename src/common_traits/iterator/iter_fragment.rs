@@ -1,5 +1,6 @@
 use crate::Fragment;
-use std::marker::PhantomData;
+use allocator_api2::alloc::Allocator;
+use core::marker::PhantomData;
 
 #[derive(Debug, Clone)]
 pub(crate) struct FragmentIter<'a, T> {
@@ -9,8 +10,8 @@ pub(crate) struct FragmentIter<'a, T> {
 }
 
 impl<'a, T> FragmentIter<'a, T> {
-    pub(crate) fn new(fragment: &'a Fragment<T>, len: usize) -> Self {
-        let fragment = unsafe { std::slice::from_raw_parts(fragment.as_ptr(), len) };
+    pub(crate) fn new<A: Allocator>(fragment: &'a Fragment<T, A>, len: usize) -> Self {
+        let fragment = unsafe { core::slice::from_raw_parts(fragment.as_ptr(), len) };
         Self {
             fragment,
             i: 0,
@@ -1,18 +1,35 @@
-use crate::fragment::fragment_struct::Fragment;
-use std::iter::FusedIterator;
+use crate::{fragment::fragment_struct::Fragment, Doubling, Growth};
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
 
 /// Mutable iterator over the `SplitVec`.
 ///
 /// This struct is created by `SplitVec::iter_mut()` method.
+///
+/// The iterator holds a reference to the growth `G` of the vector, which allows it to jump to the target element
+/// in constant time in `nth` calls whenever the growth allows for constant time access.
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IterMut<'a, T> {
-    iter_outer: std::slice::IterMut<'a, Fragment<T>>,
-    iter_inner: std::slice::IterMut<'a, T>,
+pub struct IterMut<'a, T, A: Allocator = Global, G: Growth = Doubling> {
+    iter_outer: core::slice::IterMut<'a, Fragment<T, A>>,
+    iter_inner: core::slice::IterMut<'a, T>,
+    iter_inner_back: core::slice::IterMut<'a, T>,
+    len: usize,
+    front_index: usize,
+    outer_end: usize,
+    growth: Option<&'a G>,
 }
 
-impl<'a, T> IterMut<'a, T> {
-    pub(crate) fn new(fragments: &'a mut [Fragment<T>]) -> Self {
+impl<'a, T, A: Allocator, G: Growth> IterMut<'a, T, A, G> {
+    /// Creates the iterator over the `fragments` with `len` elements in total, which jumps to the target element
+    /// in constant time in `nth` calls whenever the `growth` allows for constant time access.
+    pub(crate) fn with_growth(
+        fragments: &'a mut [Fragment<T, A>],
+        growth: &'a G,
+        len: usize,
+    ) -> Self {
+        debug_assert_eq!(len, fragments.iter().map(|x| x.len()).sum::<usize>());
+        let outer_end = fragments.len();
         let mut iter_outer = fragments.iter_mut();
         let iter_inner = iter_outer
             .next()
@@ -21,32 +38,194 @@ impl<'a, T> IterMut<'a, T> {
         Self {
             iter_outer,
             iter_inner,
+            iter_inner_back: [].iter_mut(),
+            len,
+            front_index: 0,
+            outer_end,
+            growth: Some(growth),
+        }
+    }
+
+    /// Creates the iterator over elements of the `front` slice, followed by elements of the `fragments` and elements of the `back` slice.
+    pub(crate) fn from_parts(
+        front: &'a mut [T],
+        fragments: &'a mut [Fragment<T, A>],
+        back: &'a mut [T],
+    ) -> Self {
+        let fragments_len: usize = fragments.iter().map(|x| x.len()).sum();
+        Self {
+            len: front.len() + fragments_len + back.len(),
+            front_index: 0,
+            outer_end: fragments.len(),
+            iter_outer: fragments.iter_mut(),
+            iter_inner: front.iter_mut(),
+            iter_inner_back: back.iter_mut(),
+            growth: None,
         }
     }
 
     fn next_fragment(&mut self) -> Option<&'a mut T> {
-        match self.iter_outer.next() {
-            Some(f) => {
-                self.iter_inner = f.iter_mut();
-                self.next()
+        loop {
+            match self.iter_outer.next() {
+                Some(f) => {
+                    self.iter_inner = f.iter_mut();
+                    if let Some(x) = self.iter_inner.next() {
+                        return Some(x);
+                    }
+                }
+                None => return self.iter_inner_back.next(),
+            }
+        }
+    }
+
+    fn next_back_fragment(&mut self) -> Option<&'a mut T> {
+        loop {
+            match self.iter_outer.next_back() {
+                Some(f) => {
+                    self.outer_end -= 1;
+                    self.iter_inner_back = f.iter_mut();
+                    if let Some(x) = self.iter_inner_back.next_back() {
+                        return Some(x);
+                    }
+                }
+                None => return self.iter_inner.next_back(),
+            }
+        }
+    }
+
+    /// Returns the `n`-th remaining element by skipping whole fragments until the one containing it.
+    fn scan(&mut self, n: usize) -> Option<&'a mut T> {
+        let mut n = n;
+        if n < self.iter_inner.len() {
+            return self.iter_inner.nth(n);
+        }
+        n -= self.iter_inner.len();
+        self.iter_inner = [].iter_mut();
+
+        for fragment in self.iter_outer.by_ref() {
+            if n < fragment.len() {
+                self.iter_inner = fragment.iter_mut();
+                return self.iter_inner.nth(n);
+            }
+            n -= fragment.len();
+        }
+
+        self.iter_inner_back.nth(n)
+    }
+
+    /// Returns the `n`-th remaining element, which is known to be located at the `i`-th position of the `f`-th fragment,
+    /// by directly jumping to the fragment.
+    fn jump(&mut self, n: usize, f: usize, i: usize) -> Option<&'a mut T> {
+        let outer_begin = self.outer_end - self.iter_outer.len();
+        debug_assert!(f >= outer_begin);
+
+        match f < self.outer_end {
+            true => {
+                let fragment = self.iter_outer.nth(f - outer_begin)?;
+                self.iter_inner = fragment.iter_mut();
+                self.iter_inner.nth(i)
+            }
+            false => {
+                let outer_len = self.len - self.iter_inner.len() - self.iter_inner_back.len();
+                let n = n - self.iter_inner.len() - outer_len;
+                self.iter_inner = [].iter_mut();
+                self.iter_outer = [].iter_mut();
+                self.iter_inner_back.nth(n)
             }
-            None => None,
         }
     }
 }
 
-impl<T> FusedIterator for IterMut<'_, T> {}
+impl<T, A: Allocator, G: Growth> FusedIterator for IterMut<'_, T, A, G> {}
 
-impl<'a, T> Iterator for IterMut<'a, T> {
+impl<'a, T, A: Allocator, G: Growth> Iterator for IterMut<'a, T, A, G> {
     type Item = &'a mut T;
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
         let next_element = self.iter_inner.next();
-        if next_element.is_some() {
-            next_element
-        } else {
-            self.next_fragment()
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        self.front_index += next_element.is_some() as usize;
+        next_element
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let location = match n < self.iter_inner.len() || n >= self.len {
+            true => None,
+            false => self.growth.and_then(|x| {
+                x.get_fragment_and_inner_indices_in_constant_time(self.front_index + n)
+            }),
+        };
+
+        let element = match location {
+            Some((f, i)) => self.jump(n, f, i),
+            None => self.scan(n),
+        };
+
+        let consumed = n.saturating_add(1).min(self.len);
+        self.len -= consumed;
+        self.front_index += consumed;
+        element
+    }
+}
+
+impl<T, A: Allocator, G: Growth> DoubleEndedIterator for IterMut<'_, T, A, G> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next_element = self.iter_inner_back.next_back();
+        let next_element = match next_element.is_some() {
+            true => next_element,
+            false => self.next_back_fragment(),
+        };
+        self.len -= next_element.is_some() as usize;
+        next_element
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.len = self.len.saturating_sub(n.saturating_add(1));
+
+        let mut n = n;
+        if n < self.iter_inner_back.len() {
+            return self.iter_inner_back.nth_back(n);
+        }
+        n -= self.iter_inner_back.len();
+        self.iter_inner_back = [].iter_mut();
+
+        while let Some(fragment) = self.iter_outer.next_back() {
+            self.outer_end -= 1;
+            if n < fragment.len() {
+                self.iter_inner_back = fragment.iter_mut();
+                return self.iter_inner_back.nth_back(n);
+            }
+            n -= fragment.len();
         }
+
+        self.iter_inner.nth_back(n)
+    }
+}
+
+impl<T, A: Allocator, G: Growth> ExactSizeIterator for IterMut<'_, T, A, G> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
     }
 }
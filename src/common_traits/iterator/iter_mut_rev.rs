@@ -1,18 +1,19 @@
 use crate::fragment::fragment_struct::Fragment;
-use std::iter::{FusedIterator, Rev};
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::{FusedIterator, Rev};
 
 /// Mutable iterator over the `SplitVec`.
 ///
 /// This struct is created by `SplitVec::iter_mut_rev()` method.
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IterMutRev<'a, T> {
-    iter_outer: Rev<std::slice::IterMut<'a, Fragment<T>>>,
-    iter_inner: Rev<std::slice::IterMut<'a, T>>,
+pub struct IterMutRev<'a, T, A: Allocator = Global> {
+    iter_outer: Rev<core::slice::IterMut<'a, Fragment<T, A>>>,
+    iter_inner: Rev<core::slice::IterMut<'a, T>>,
 }
 
-impl<'a, T> IterMutRev<'a, T> {
-    pub(crate) fn new(fragments: &'a mut [Fragment<T>]) -> Self {
+impl<'a, T, A: Allocator> IterMutRev<'a, T, A> {
+    pub(crate) fn new(fragments: &'a mut [Fragment<T, A>]) -> Self {
         let mut iter_outer = fragments.iter_mut().rev();
         let iter_inner = iter_outer
             .next()
@@ -36,7 +37,7 @@ impl<'a, T> IterMutRev<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for IterMutRev<'a, T> {
+impl<'a, T, A: Allocator> Iterator for IterMutRev<'a, T, A> {
     type Item = &'a mut T;
 
     #[inline(always)]
@@ -50,4 +51,4 @@ impl<'a, T> Iterator for IterMutRev<'a, T> {
     }
 }
 
-impl<T> FusedIterator for IterMutRev<'_, T> {}
+impl<T, A: Allocator> FusedIterator for IterMutRev<'_, T, A> {}
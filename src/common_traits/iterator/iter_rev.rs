@@ -1,18 +1,19 @@
 use crate::fragment::fragment_struct::Fragment;
-use std::iter::{FusedIterator, Rev};
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::{FusedIterator, Rev};
 
 /// Iterator over the `SplitVec`.
 ///
 /// This struct is created by `SplitVec::iter_rev()` method.
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct IterRev<'a, T> {
-    iter_outer: Rev<std::slice::Iter<'a, Fragment<T>>>,
-    iter_inner: Rev<std::slice::Iter<'a, T>>,
+pub struct IterRev<'a, T, A: Allocator = Global> {
+    iter_outer: Rev<core::slice::Iter<'a, Fragment<T, A>>>,
+    iter_inner: Rev<core::slice::Iter<'a, T>>,
 }
 
-impl<'a, T> IterRev<'a, T> {
-    pub(crate) fn new(fragments: &'a [Fragment<T>]) -> Self {
+impl<'a, T, A: Allocator> IterRev<'a, T, A> {
+    pub(crate) fn new(fragments: &'a [Fragment<T, A>]) -> Self {
         let mut iter_outer = fragments.iter().rev();
         let iter_inner = iter_outer
             .next()
@@ -35,7 +36,7 @@ impl<'a, T> IterRev<'a, T> {
     }
 }
 
-impl<'a, T> Clone for IterRev<'a, T> {
+impl<'a, T, A: Allocator> Clone for IterRev<'a, T, A> {
     fn clone(&self) -> Self {
         Self {
             iter_outer: self.iter_outer.clone(),
@@ -44,7 +45,7 @@ impl<'a, T> Clone for IterRev<'a, T> {
     }
 }
 
-impl<'a, T> Iterator for IterRev<'a, T> {
+impl<'a, T, A: Allocator> Iterator for IterRev<'a, T, A> {
     type Item = &'a T;
 
     #[inline(always)]
@@ -58,4 +59,4 @@ impl<'a, T> Iterator for IterRev<'a, T> {
     }
 }
 
-impl<T> FusedIterator for IterRev<'_, T> {}
+impl<T, A: Allocator> FusedIterator for IterRev<'_, T, A> {}
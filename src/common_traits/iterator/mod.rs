@@ -1,3 +1,4 @@
+pub(crate) mod drain;
 mod eq;
 mod from_iter;
 pub(crate) mod into_iter;
@@ -8,6 +9,8 @@ pub(crate) mod iter_mut;
 pub(crate) mod iter_mut_rev;
 pub(crate) mod iter_rev;
 mod reductions;
+pub(crate) mod slices_iter;
+pub(crate) mod slices_iter_mut;
 
 #[cfg(test)]
 mod tests;
@@ -1,10 +1,16 @@
 use crate::Fragment;
-use std::slice::Iter;
+use allocator_api2::alloc::Allocator;
+use core::slice::Iter;
 
-type Outer<'a, T> = Iter<'a, Fragment<T>>;
+type Outer<'a, T, A> = Iter<'a, Fragment<T, A>>;
 type Inner<'a, T> = Iter<'a, T>;
 
-pub fn all<'a, T, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, mut f: F) -> bool
+pub fn all<'a, T, A: Allocator, F>(
+    outer: &mut Outer<'a, T, A>,
+    inner: &mut Inner<'a, T>,
+    inner_back: &mut Inner<'a, T>,
+    mut f: F,
+) -> bool
 where
     F: FnMut(&'a T) -> bool,
 {
@@ -12,15 +18,21 @@ where
         false
     } else {
         for fragment in outer {
-            if !fragment.iter().all(&mut f) {
+            *inner = fragment.iter();
+            if !inner.all(&mut f) {
                 return false;
             }
         }
-        true
+        inner_back.all(&mut f)
     }
 }
 
-pub fn any<'a, T, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, mut f: F) -> bool
+pub fn any<'a, T, A: Allocator, F>(
+    outer: &mut Outer<'a, T, A>,
+    inner: &mut Inner<'a, T>,
+    inner_back: &mut Inner<'a, T>,
+    mut f: F,
+) -> bool
 where
     F: FnMut(&'a T) -> bool,
 {
@@ -28,15 +40,22 @@ where
         true
     } else {
         for fragment in outer {
-            if fragment.iter().any(&mut f) {
+            *inner = fragment.iter();
+            if inner.any(&mut f) {
                 return true;
             }
         }
-        false
+        inner_back.any(&mut f)
     }
 }
 
-pub fn fold<'a, T, B, F>(outer: &mut Outer<'a, T>, inner: &mut Inner<'a, T>, init: B, mut f: F) -> B
+pub fn fold<'a, T, A: Allocator, B, F>(
+    outer: &mut Outer<'a, T, A>,
+    inner: &mut Inner<'a, T>,
+    inner_back: &mut Inner<'a, T>,
+    init: B,
+    mut f: F,
+) -> B
 where
     F: FnMut(B, &'a T) -> B,
 {
@@ -44,5 +63,5 @@ where
     for fragment in outer {
         res = fragment.iter().fold(res, &mut f);
     }
-    res
+    inner_back.fold(res, &mut f)
 }
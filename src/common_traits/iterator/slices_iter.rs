@@ -0,0 +1,95 @@
+use crate::fragment::fragment_struct::Fragment;
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
+
+/// Iterator over the slices of the `SplitVec` which, when chained, form a range of the vector.
+///
+/// This struct is created by `SplitVec::slices_iter(range)` and `PinnedVec::slices(range)` methods.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SlicesIter<'a, T, A: Allocator = Global> {
+    front: Option<&'a [T]>,
+    fragments: core::slice::Iter<'a, Fragment<T, A>>,
+    back: Option<&'a [T]>,
+}
+
+impl<'a, T, A: Allocator> SlicesIter<'a, T, A> {
+    /// Creates the iterator over the slices starting from the `si`-th element of the `sf`-th fragment
+    /// and ending at the `ei`-th element (inclusive) of the `ef`-th fragment.
+    pub(crate) fn new(
+        fragments: &'a [Fragment<T, A>],
+        (sf, si): (usize, usize),
+        (ef, ei): (usize, usize),
+    ) -> Self {
+        debug_assert!(sf <= ef);
+        match sf == ef {
+            true => Self {
+                front: Some(&fragments[sf][si..=ei]),
+                fragments: [].iter(),
+                back: None,
+            },
+            false => Self {
+                front: Some(&fragments[sf][si..]),
+                fragments: fragments[(sf + 1)..ef].iter(),
+                back: Some(&fragments[ef][..=ei]),
+            },
+        }
+    }
+}
+
+impl<T, A: Allocator> Default for SlicesIter<'_, T, A> {
+    fn default() -> Self {
+        Self {
+            front: None,
+            fragments: [].iter(),
+            back: None,
+        }
+    }
+}
+
+impl<T, A: Allocator> Clone for SlicesIter<'_, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front,
+            fragments: self.fragments.clone(),
+            back: self.back,
+        }
+    }
+}
+
+impl<T, A: Allocator> FusedIterator for SlicesIter<'_, T, A> {}
+
+impl<'a, T, A: Allocator> Iterator for SlicesIter<'a, T, A> {
+    type Item = &'a [T];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front
+            .take()
+            .or_else(|| self.fragments.next().map(|x| x.as_slice()))
+            .or_else(|| self.back.take())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for SlicesIter<'_, T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back
+            .take()
+            .or_else(|| self.fragments.next_back().map(|x| x.as_slice()))
+            .or_else(|| self.front.take())
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for SlicesIter<'_, T, A> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.front.is_some() as usize + self.fragments.len() + self.back.is_some() as usize
+    }
+}
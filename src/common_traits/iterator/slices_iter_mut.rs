@@ -0,0 +1,160 @@
+use crate::fragment::fragment_struct::Fragment;
+use allocator_api2::alloc::{Allocator, Global};
+use core::iter::FusedIterator;
+
+/// Mutable iterator over the slices of the `SplitVec` which, when chained, form a range of the vector.
+///
+/// This struct is created by `SplitVec::slices_iter_mut(range)` and `PinnedVec::slices_mut(range)` methods.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SlicesIterMut<'a, T, A: Allocator = Global> {
+    front: Option<&'a mut [T]>,
+    fragments: Fragments<'a, T, A>,
+    back: Option<&'a mut [T]>,
+}
+
+/// Fragments in between the front and back slices.
+#[derive(Debug)]
+enum Fragments<'a, T, A: Allocator> {
+    /// Exclusively borrowed fragments, each yielding its initialized elements.
+    Mut(core::slice::IterMut<'a, Fragment<T, A>>),
+    /// Shared fragments, each yielding its entire capacity through the raw pointer to its elements;
+    /// the fragments themselves are never borrowed mutably.
+    UpToCapacity(core::slice::Iter<'a, Fragment<T, A>>),
+}
+
+impl<'a, T, A: Allocator> Fragments<'a, T, A> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Mut(x) => x.len(),
+            Self::UpToCapacity(x) => x.len(),
+        }
+    }
+
+    /// Returns the pointer to and the capacity of the `fragment`; the pointer is the raw pointer of its allocation,
+    /// which is the same pointer the concurrent vector writes elements through.
+    fn ptr_up_to_capacity(fragment: &Fragment<T, A>) -> (*mut T, usize) {
+        (fragment.as_ptr() as *mut T, fragment.capacity())
+    }
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        match self {
+            Self::Mut(x) => x.next().map(|f| f.as_mut_slice()),
+            Self::UpToCapacity(x) => x.next().map(Self::ptr_up_to_capacity).map(|(p, len)| {
+                // SAFETY: guaranteed by the caller of `SlicesIterMut::from_parts_up_to_capacity`
+                unsafe { core::slice::from_raw_parts_mut(p, len) }
+            }),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        match self {
+            Self::Mut(x) => x.next_back().map(|f| f.as_mut_slice()),
+            Self::UpToCapacity(x) => x.next_back().map(Self::ptr_up_to_capacity).map(|(p, len)| {
+                // SAFETY: guaranteed by the caller of `SlicesIterMut::from_parts_up_to_capacity`
+                unsafe { core::slice::from_raw_parts_mut(p, len) }
+            }),
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> SlicesIterMut<'a, T, A> {
+    /// Creates the iterator over the slices starting from the `si`-th element of the `sf`-th fragment
+    /// and ending at the `ei`-th element (inclusive) of the `ef`-th fragment.
+    pub(crate) fn new(
+        fragments: &'a mut [Fragment<T, A>],
+        (sf, si): (usize, usize),
+        (ef, ei): (usize, usize),
+    ) -> Self {
+        debug_assert!(sf <= ef);
+        match sf == ef {
+            true => Self {
+                front: Some(&mut fragments[sf][si..=ei]),
+                fragments: Fragments::Mut([].iter_mut()),
+                back: None,
+            },
+            false => {
+                let (fragments, last) = fragments.split_at_mut(ef);
+                let (first, fragments) = fragments[sf..].split_at_mut(1);
+                Self {
+                    front: Some(&mut first[0][si..]),
+                    fragments: Fragments::Mut(fragments.iter_mut()),
+                    back: Some(&mut last[0][..=ei]),
+                }
+            }
+        }
+    }
+
+    /// Creates the iterator yielding the `front` slice, followed by the entire capacities of the `fragments` and the `back` slice.
+    ///
+    /// # Safety
+    ///
+    /// Slices of the `fragments` span positions which might be greater than their lengths.
+    /// The caller is responsible for not reading from uninitialized positions.
+    /// Further, the caller must guarantee that the elements of the `fragments` are not accessed by
+    /// any other reference while the yielded slices are in use; the fragments themselves are only borrowed immutably.
+    pub(crate) unsafe fn from_parts_up_to_capacity(
+        front: Option<&'a mut [T]>,
+        fragments: &'a [Fragment<T, A>],
+        back: Option<&'a mut [T]>,
+    ) -> Self {
+        Self {
+            front,
+            fragments: Fragments::UpToCapacity(fragments.iter()),
+            back,
+        }
+    }
+}
+
+impl<T, A: Allocator> Default for SlicesIterMut<'_, T, A> {
+    fn default() -> Self {
+        Self {
+            front: None,
+            fragments: Fragments::Mut([].iter_mut()),
+            back: None,
+        }
+    }
+}
+
+impl<T, A: Allocator> FusedIterator for SlicesIterMut<'_, T, A> {}
+
+impl<'a, T, A: Allocator> Iterator for SlicesIterMut<'a, T, A> {
+    type Item = &'a mut [T];
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(front) = self.front.take() {
+            return Some(front);
+        }
+        match self.fragments.next() {
+            Some(slice) => Some(slice),
+            None => self.back.take(),
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for SlicesIterMut<'_, T, A> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(back) = self.back.take() {
+            return Some(back);
+        }
+        match self.fragments.next_back() {
+            Some(slice) => Some(slice),
+            None => self.front.take(),
+        }
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for SlicesIterMut<'_, T, A> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.front.is_some() as usize + self.fragments.len() + self.back.is_some() as usize
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{test_all_growth_types, Fragment, Growth, SplitVec};
+use core::fmt::Debug;
+
+fn assert_same_iter<T, I, J>(mut iter: I, mut expected: J)
+where
+    T: PartialEq + Debug,
+    I: DoubleEndedIterator<Item = T> + ExactSizeIterator,
+    J: DoubleEndedIterator<Item = T> + ExactSizeIterator,
+{
+    assert_eq!(iter.len(), expected.len());
+    for step in 0.. {
+        let (x, y) = match step % 6 {
+            0 => (iter.next(), expected.next()),
+            1 => (iter.next_back(), expected.next_back()),
+            2 => (iter.nth(3), expected.nth(3)),
+            3 => (iter.nth_back(2), expected.nth_back(2)),
+            4 => (iter.nth(1), expected.nth(1)),
+            _ => (iter.nth_back(17), expected.nth_back(17)),
+        };
+        assert_eq!(x, y);
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.size_hint(), expected.size_hint());
+
+        if y.is_none() && expected.len() == 0 {
+            break;
+        }
+    }
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+fn vec_with_empty_fragments() -> SplitVec<usize, crate::Recursive> {
+    let mut vec = SplitVec::with_recursive_growth();
+    vec.append(vec![0, 1, 2]);
+    vec.append(vec![3]);
+    vec.append(vec![4, 5, 6, 7, 8, 9]);
+    for f in [0, 2, 4, 6] {
+        unsafe { vec.fragments_mut().insert(f, Fragment::new(0)) };
+    }
+    vec.fragments_mutated_from(0);
+    vec
+}
+
+#[test]
+fn iter_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        for len in [0, 1, 4, 5, 33, 564] {
+            vec.clear();
+            vec.extend(0..len);
+            let stdvec: Vec<_> = (0..len).collect();
+
+            assert_same_iter(vec.iter(), stdvec.iter());
+            assert_same_iter(vec.iter().rev(), stdvec.iter().rev());
+            assert_eq!(vec.iter().count(), len);
+            assert_eq!(vec.iter().last(), stdvec.last());
+        }
+    }
+    test_all_growth_types!(test);
+
+    let vec = vec_with_empty_fragments();
+    let stdvec: Vec<_> = (0..10).collect();
+    assert_same_iter(vec.iter(), stdvec.iter());
+    assert_same_iter(vec.iter().rev(), stdvec.iter().rev());
+}
+
+#[test]
+fn iter_mut_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        for len in [0, 1, 4, 5, 33, 564] {
+            vec.clear();
+            vec.extend(0..len);
+            let mut stdvec: Vec<_> = (0..len).collect();
+
+            assert_same_iter(vec.iter_mut(), stdvec.iter_mut());
+            assert_same_iter(vec.iter_mut().rev(), stdvec.iter_mut().rev());
+            assert_eq!(vec.iter_mut().count(), len);
+
+            for x in vec.iter_mut().rev().step_by(2) {
+                *x += 1000;
+            }
+            for x in stdvec.iter_mut().rev().step_by(2) {
+                *x += 1000;
+            }
+            assert_eq!(vec, stdvec.as_slice());
+        }
+    }
+    test_all_growth_types!(test);
+
+    let mut vec = vec_with_empty_fragments();
+    let mut stdvec: Vec<_> = (0..10).collect();
+    assert_same_iter(vec.iter_mut(), stdvec.iter_mut());
+}
+
+#[test]
+fn into_iter_double_ended() {
+    fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+        for len in [0, 1, 4, 5, 33, 564] {
+            vec.clear();
+            vec.extend((0..len).map(|x| x.to_string()));
+            let stdvec: Vec<_> = (0..len).map(|x| x.to_string()).collect();
+
+            assert_same_iter(vec.clone().into_iter(), stdvec.clone().into_iter());
+            assert_same_iter(
+                vec.clone().into_iter().rev(),
+                stdvec.clone().into_iter().rev(),
+            );
+            assert_eq!(vec.clone().into_iter().count(), len);
+            assert_eq!(vec.clone().into_iter().last(), stdvec.last().cloned());
+        }
+    }
+    test_all_growth_types!(test);
+
+    let vec = vec_with_empty_fragments();
+    let stdvec: Vec<_> = (0..10).collect();
+    assert_same_iter(vec.into_iter(), stdvec.into_iter());
+}
+
+#[test]
+fn exact_size_zip() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..100);
+        let mut iter = vec.iter();
+        assert_eq!(iter.len(), 100);
+
+        _ = iter.next();
+        _ = iter.next_back();
+        assert_eq!(iter.len(), 98);
+        assert!(iter.any(|x| *x == 50));
+        assert_eq!(iter.len(), 48);
+
+        let zipped: Vec<_> = vec
+            .iter()
+            .zip(vec.iter().rev())
+            .map(|(a, b)| a + b)
+            .collect();
+        assert_eq!(zipped, vec![99; 100]);
+    }
+    test_all_growth_types!(test);
+}
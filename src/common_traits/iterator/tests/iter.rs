@@ -1,5 +1,4 @@
 use crate::{test_all_growth_types, Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
 
 #[test]
 fn iter() {
@@ -1,5 +1,9 @@
+mod double_ended;
 mod into_iter;
 mod iter;
 mod iter_mut;
 mod iter_mut_rev;
 mod iter_rev;
+mod nth;
+mod send_sync;
+mod slices_iter;
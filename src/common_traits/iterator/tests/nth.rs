@@ -0,0 +1,122 @@
+use crate::{test_all_growth_types, Growth, GrowthWithConstantTimeAccess, SplitVec};
+
+#[test]
+fn nth_skip_step_by() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        let len = 1000;
+        vec.extend(0..len);
+        let stdvec: Vec<_> = (0..len).collect();
+
+        for n in [0, 1, 3, 4, 11, 12, 100, 999, 1000, 1500] {
+            let mut iter = vec.iter();
+            let mut expected = stdvec.iter();
+            for _ in 0..4 {
+                assert_eq!(iter.nth(n), expected.nth(n));
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.next(), expected.next());
+            }
+
+            assert!(vec.iter().skip(n).eq(stdvec.iter().skip(n)));
+            assert!(vec.iter().step_by(n + 1).eq(stdvec.iter().step_by(n + 1)));
+            assert!(vec
+                .iter()
+                .rev()
+                .step_by(n + 1)
+                .eq(stdvec.iter().rev().step_by(n + 1)));
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn nth_after_next_back() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..100);
+        let stdvec: Vec<_> = (0..100).collect();
+
+        for num_back in [1, 4, 5, 30, 60, 99] {
+            for n in [0, 1, 7, 20, 39, 70] {
+                let mut iter = vec.iter();
+                let mut expected = stdvec.iter();
+                for _ in 0..num_back {
+                    assert_eq!(iter.next_back(), expected.next_back());
+                }
+                assert_eq!(iter.nth(n), expected.nth(n));
+                assert_eq!(iter.len(), expected.len());
+                assert!(iter.eq(expected));
+            }
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn iter_mut_nth_step_by() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..500);
+        let mut stdvec: Vec<_> = (0..500).collect();
+
+        for x in vec.iter_mut().skip(33).step_by(7) {
+            *x += 1000;
+        }
+        for x in stdvec.iter_mut().skip(33).step_by(7) {
+            *x += 1000;
+        }
+        assert_eq!(vec, stdvec.as_slice());
+
+        let mut iter = vec.iter_mut();
+        let mut expected = stdvec.iter_mut();
+        for n in [3, 0, 120, 1, 250, 1000] {
+            assert_eq!(iter.nth(n), expected.nth(n));
+            assert_eq!(iter.len(), expected.len());
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn iter_from() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        for len in [0, 1, 4, 5, 33, 564] {
+            vec.clear();
+            vec.extend(0..len);
+
+            for index in 0..=len {
+                let iter = vec.iter_from(index);
+                assert_eq!(iter.len(), len - index);
+                assert!(iter.copied().eq(index..len));
+            }
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+#[should_panic]
+fn iter_from_out_of_bounds() {
+    let mut vec = SplitVec::with_doubling_growth();
+    vec.extend(0..10);
+    let _ = vec.iter_from(11);
+}
+
+#[test]
+fn constant_time_location() {
+    fn test<G: GrowthWithConstantTimeAccess>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..1000);
+        for index in [0, 1, 4, 100, 999] {
+            let expected = vec.get_fragment_and_inner_indices(index);
+            let location = vec
+                .growth()
+                .get_fragment_and_inner_indices_in_constant_time(index);
+            assert_eq!(location, expected);
+        }
+    }
+    test(SplitVec::with_doubling_growth());
+    test(SplitVec::with_linear_growth(3));
+
+    let vec: SplitVec<usize, _> = SplitVec::with_recursive_growth();
+    let location = vec
+        .growth()
+        .get_fragment_and_inner_indices_in_constant_time(0);
+    assert_eq!(location, None);
+}
@@ -0,0 +1,56 @@
+use crate::*;
+use core::cell::Cell;
+
+fn assert_send_sync<T: Send + Sync>(_: T) {}
+
+#[test]
+fn iterators_are_send_and_sync() {
+    fn test<G: Growth + Sync>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..42);
+
+        assert_send_sync(vec.iter());
+        assert_send_sync(vec.iter_rev());
+        assert_send_sync(vec.iter_from(3));
+        assert_send_sync(vec.slices_iter(..));
+        assert_send_sync(vec.iter_mut());
+        assert_send_sync(vec.iter_mut_rev());
+        assert_send_sync(vec.slices_iter_mut(..));
+        assert_send_sync(vec.into_iter());
+    }
+    crate::test_all_growth_types!(test);
+}
+
+/// Growth which is not `Sync`.
+#[derive(Clone, Default)]
+struct CountingGrowth {
+    num_fragments: Cell<usize>,
+}
+
+impl PseudoDefault for CountingGrowth {
+    fn pseudo_default() -> Self {
+        Default::default()
+    }
+}
+
+impl Growth for CountingGrowth {
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        self.num_fragments.set(self.num_fragments.get() + 1);
+        Doubling.new_fragment_capacity_from(fragment_capacities)
+    }
+}
+
+#[test]
+fn iterators_of_growth_which_is_not_sync() {
+    let mut vec = SplitVec::with_growth(CountingGrowth::default());
+    vec.extend(0..42);
+    assert!(vec.growth().num_fragments.get() > 1);
+
+    assert!(vec.iter().copied().eq(0..42));
+    assert_eq!(vec.iter().nth(33), Some(&33));
+    assert_eq!(vec.iter_from(7).nth(3), Some(&10));
+    vec.iter_mut().for_each(|x| *x += 1);
+    assert!(vec.into_iter().eq(1..43));
+}
@@ -0,0 +1,158 @@
+use crate::{test_all_growth_types, Doubling, Fragment, Growth, SplitVec};
+use core::fmt::Debug;
+use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec};
+
+fn assert_same_iter<T, I, J>(mut iter: I, mut expected: J)
+where
+    T: PartialEq + Debug,
+    I: DoubleEndedIterator<Item = T> + ExactSizeIterator,
+    J: DoubleEndedIterator<Item = T> + ExactSizeIterator,
+{
+    assert_eq!(iter.len(), expected.len());
+    for step in 0.. {
+        let (x, y) = match step % 3 {
+            0 => (iter.next(), expected.next()),
+            1 => (iter.next_back(), expected.next_back()),
+            _ => (iter.nth(1), expected.nth(1)),
+        };
+        assert_eq!(x, y);
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.size_hint(), expected.size_hint());
+
+        if y.is_none() && expected.len() == 0 {
+            break;
+        }
+    }
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+/// Slices of the fragments between the first and last fragments containing the elements in `a..b`.
+fn expected_slices<T, G: Growth>(vec: &SplitVec<T, G>, a: usize, b: usize) -> Vec<&[T]> {
+    let mut slices = vec![];
+    if a < b && b <= vec.len() {
+        let mut offset = 0;
+        for fragment in vec.fragments() {
+            let end = offset + fragment.len();
+            match fragment.is_empty() {
+                true if a < offset && offset < b => slices.push(&fragment[..]),
+                false if offset < b && a < end => {
+                    slices.push(&fragment[(a.max(offset) - offset)..(b.min(end) - offset)])
+                }
+                _ => {}
+            }
+            offset = end;
+        }
+    }
+    slices
+}
+
+fn vec_with_empty_fragments() -> SplitVec<usize, crate::Recursive> {
+    let mut vec = SplitVec::with_recursive_growth();
+    vec.append(vec![0, 1, 2]);
+    vec.append(vec![3]);
+    vec.append(vec![4, 5, 6, 7, 8, 9]);
+    for f in [0, 2, 4, 6] {
+        unsafe { vec.fragments_mut().insert(f, Fragment::new(0)) };
+    }
+    vec.fragments_mutated_from(0);
+    vec
+}
+
+fn assert_slices<G: Growth>(vec: &mut SplitVec<usize, G>) {
+    let len = vec.len();
+    for a in 0..(len + 2) {
+        for b in [a, a + 1, a + 3, a + 17, len / 2, len, len + 1] {
+            let expected: Vec<Vec<_>> = expected_slices(vec, a, b)
+                .into_iter()
+                .map(|x| x.to_vec())
+                .collect();
+
+            assert_same_iter(
+                vec.slices_iter(a..b),
+                expected_slices(vec, a, b).into_iter(),
+            );
+            assert_same_iter(
+                vec.slices_iter_mut(a..b).map(|x| x.to_vec()),
+                expected.iter().cloned(),
+            );
+            assert_same_iter(
+                vec.slices(a..b).map(|x| x.to_vec()),
+                expected.iter().cloned(),
+            );
+            assert_same_iter(
+                vec.slices_mut(a..b).map(|x| x.to_vec()),
+                expected.iter().cloned(),
+            );
+        }
+    }
+}
+
+#[test]
+fn slices_iter() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        for len in [0, 1, 4, 5, 33, 68] {
+            vec.clear();
+            vec.extend(0..len);
+            assert_slices(&mut vec);
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn slices_iter_with_empty_fragments() {
+    let mut vec = vec_with_empty_fragments();
+    assert_slices(&mut vec);
+
+    let slices: Vec<_> = vec.slices_iter(2..5).collect();
+    assert_eq!(slices, [&[2][..], &[], &[3], &[], &[4]]);
+}
+
+#[test]
+fn slices_iter_mut_rev() {
+    fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+        vec.extend(0..184);
+
+        let mut val = 150;
+        for slice in vec.slices_iter_mut(25..150).rev() {
+            for x in slice.iter_mut().rev() {
+                val -= 1;
+                *x = 1000 + val;
+            }
+        }
+
+        for i in 0..184 {
+            let expected = match (25..150).contains(&i) {
+                true => 1000 + i,
+                false => i,
+            };
+            assert_eq!(vec[i], expected);
+        }
+    }
+    test_all_growth_types!(test);
+}
+
+#[test]
+fn concurrent_slices_mut() {
+    let mut vec: SplitVec<usize, Doubling> = SplitVec::with_doubling_growth();
+    vec.extend(0..100);
+    let con_vec = vec.into_concurrent();
+
+    let slices = unsafe { con_vec.slices_mut(3..60) };
+    assert_eq!(slices.len(), 4);
+    for slice in slices.rev() {
+        for x in slice {
+            *x += 1000;
+        }
+    }
+
+    let vec = unsafe { con_vec.into_inner(100) };
+    for i in 0..100 {
+        let expected = match (3..60).contains(&i) {
+            true => 1000 + i,
+            false => i,
+        };
+        assert_eq!(vec[i], expected);
+    }
+}
@@ -1,32 +1,38 @@
 use crate::{
-    common_traits::iterator::iter_con::IterCon,
+    common_traits::iterator::{iter_con::IterCon, slices_iter_mut::SlicesIterMut},
     fragment::fragment_struct::{
         maximum_concurrent_capacity, num_fragments_for_capacity, set_fragments_len,
     },
     range_helpers::{range_end, range_start},
-    Doubling, Fragment, Growth, GrowthWithConstantTimeAccess, SplitVec,
+    Doubling, Fragment, Growth, GrowthWithConstantTimeAccess, SplitVec, SplitVecError,
 };
-use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
-use std::{
+use alloc::{vec, vec::Vec};
+use allocator_api2::alloc::{Allocator, Global};
+use core::{
     fmt::Debug,
     ops::RangeBounds,
     sync::atomic::{AtomicUsize, Ordering},
 };
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
 
 /// Concurrent wrapper ([`orx_pinned_vec::ConcurrentPinnedVec`]) for the `SplitVec`.
-pub struct ConcurrentSplitVec<T, G: GrowthWithConstantTimeAccess = Doubling> {
+pub struct ConcurrentSplitVec<T, G: GrowthWithConstantTimeAccess = Doubling, A: Allocator = Global>
+{
     capacity: AtomicUsize,
     maximum_capacity: usize,
     num_fragments: AtomicUsize,
     growth: G,
-    fragments: Vec<Fragment<T>>,
-    ptr_fragments: *mut Fragment<T>,
+    allocator: A,
+    fragments: Vec<Fragment<T, A>>,
+    ptr_fragments: *mut Fragment<T, A>,
     fragment_pointers: Vec<*const T>,
     ptr_fragments_pointers: *const *const T,
 }
 
-impl<T, G: GrowthWithConstantTimeAccess + Debug> Debug for ConcurrentSplitVec<T, G> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T, G: GrowthWithConstantTimeAccess + Debug, A: Allocator> Debug
+    for ConcurrentSplitVec<T, G, A>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ConcurrentSplitVec")
             .field("capacity", &self.capacity)
             .field("maximum_capacity", &self.maximum_capacity)
@@ -36,27 +42,27 @@ impl<T, G: GrowthWithConstantTimeAccess + Debug> Debug for ConcurrentSplitVec<T,
     }
 }
 
-impl<T, G: GrowthWithConstantTimeAccess> Drop for ConcurrentSplitVec<T, G> {
+impl<T, G: GrowthWithConstantTimeAccess, A: Allocator> Drop for ConcurrentSplitVec<T, G, A> {
     fn drop(&mut self) {
         unsafe { self.fragments.set_len(self.num_fragments()) };
     }
 }
 
-impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
+impl<T, G: GrowthWithConstantTimeAccess, A: Allocator> ConcurrentSplitVec<T, G, A> {
     fn num_fragments(&self) -> usize {
         self.num_fragments.load(Ordering::Relaxed)
     }
 
-    fn fragments(&self) -> &[Fragment<T>] {
+    fn fragments(&self) -> &[Fragment<T, A>] {
         let len = self.num_fragments();
         self.fragments_for(len)
     }
 
-    fn fragments_for(&self, num_fragments: usize) -> &[Fragment<T>] {
-        unsafe { std::slice::from_raw_parts(self.ptr_fragments, num_fragments) }
+    fn fragments_for(&self, num_fragments: usize) -> &[Fragment<T, A>] {
+        unsafe { core::slice::from_raw_parts(self.ptr_fragments, num_fragments) }
     }
 
-    fn push_fragment(&self, fragment: Fragment<T>, fragment_index: usize) {
+    fn push_fragment(&self, fragment: Fragment<T, A>, fragment_index: usize) {
         let p = unsafe { self.fragment_pointers.as_ptr().add(fragment_index) };
         let p = p as *mut *const T;
         unsafe { p.write(fragment.as_ptr()) };
@@ -75,8 +81,8 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentSplitVec<T, G> {
     }
 }
 
-fn get_pointers<T>(
-    fragments: &[Fragment<T>],
+fn get_pointers<T, A: Allocator>(
+    fragments: &[Fragment<T, A>],
     fragments_capacity: usize,
 ) -> (Vec<*const T>, *const *const T) {
     let first = fragments[0].as_ptr();
@@ -89,9 +95,11 @@ fn get_pointers<T>(
     (fragment_pointers, ptr_fragments_pointers)
 }
 
-impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSplitVec<T, G> {
-    fn from(value: SplitVec<T, G>) -> Self {
-        let (mut fragments, growth) = (value.fragments, value.growth);
+impl<T, G: GrowthWithConstantTimeAccess, A: Allocator> From<SplitVec<T, G, A>>
+    for ConcurrentSplitVec<T, G, A>
+{
+    fn from(value: SplitVec<T, G, A>) -> Self {
+        let (mut fragments, growth, allocator) = (value.fragments, value.growth, value.allocator);
 
         let data = data(&mut fragments, &growth);
 
@@ -100,6 +108,7 @@ impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSpli
             maximum_capacity: data.maximum_capacity,
             num_fragments: data.num_fragments.into(),
             growth,
+            allocator,
             fragments,
             ptr_fragments: data.ptr_fragments,
             fragment_pointers: data.fragment_pointers,
@@ -108,14 +117,16 @@ impl<T, G: GrowthWithConstantTimeAccess> From<SplitVec<T, G>> for ConcurrentSpli
     }
 }
 
-impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSplitVec<T, G> {
-    type P = SplitVec<T, G>;
+impl<T, G: GrowthWithConstantTimeAccess, A: Allocator + Clone + Default> ConcurrentPinnedVec<T>
+    for ConcurrentSplitVec<T, G, A>
+{
+    type P = SplitVec<T, G, A>;
 
     unsafe fn into_inner(mut self, len: usize) -> Self::P {
         self.fragments.set_len(self.num_fragments());
 
         let mut fragments = vec![];
-        std::mem::swap(&mut fragments, &mut self.fragments);
+        core::mem::swap(&mut fragments, &mut self.fragments);
         set_fragments_len(&mut fragments, len);
 
         self.num_fragments.store(0, Ordering::Relaxed);
@@ -124,7 +135,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         let growth = self.growth.clone();
 
         // let (mut fragments, growth) = (self.fragments, self.growth.clone());
-        SplitVec::from_raw_parts(len, fragments, growth)
+        SplitVec::from_raw_parts_in(len, fragments, growth, self.allocator.clone())
     }
 
     fn capacity(&self) -> usize {
@@ -139,6 +150,13 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         let capacity = self.capacity();
         match new_capacity <= capacity {
             true => Ok(capacity),
+            false if new_capacity > self.maximum_capacity => {
+                Err(SplitVecError::FragmentsCapacityExceeded {
+                    required: new_capacity,
+                    max: self.maximum_capacity,
+                }
+                .into())
+            }
             false => {
                 let mut num_fragments = self.num_fragments();
 
@@ -148,7 +166,8 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
                     let new_fragment_capacity = self
                         .growth
                         .new_fragment_capacity(self.fragments_for(num_fragments));
-                    let new_fragment = Fragment::new(new_fragment_capacity);
+                    let new_fragment =
+                        Fragment::new_in(new_fragment_capacity, self.allocator.clone());
 
                     self.push_fragment(new_fragment, num_fragments);
 
@@ -168,7 +187,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         &self,
         range: R,
     ) -> <Self::P as PinnedVec<T>>::SliceMutIter<'_> {
-        use std::slice::from_raw_parts_mut;
+        use core::slice::from_raw_parts_mut;
 
         let fragments = self.fragments();
         let fragment_and_inner_indices =
@@ -178,7 +197,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
         let b = range_end(&range, self.capacity());
 
         match b.saturating_sub(a) {
-            0 => vec![],
+            0 => Default::default(),
             _ => {
                 let (sf, si) = fragment_and_inner_indices(a);
                 let (ef, ei) = fragment_and_inner_indices(b - 1);
@@ -187,26 +206,20 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
                     true => {
                         let p = self.fragment_element_ptr_mut(sf, si);
                         let slice = from_raw_parts_mut(p, ei - si + 1);
-                        vec![slice]
+                        SlicesIterMut::from_parts_up_to_capacity(Some(slice), &[], None)
                     }
                     false => {
-                        let mut vec = Vec::with_capacity(ef - sf + 1);
-
                         let slice_len = fragments[sf].capacity() - si;
                         let ptr_s = self.fragment_element_ptr_mut(sf, si);
-                        vec.push(from_raw_parts_mut(ptr_s, slice_len));
+                        let front = from_raw_parts_mut(ptr_s, slice_len);
 
-                        for (f, fragment) in fragments.iter().enumerate().take(ef).skip(sf + 1) {
-                            let slice_len = fragment.capacity();
-                            let ptr_s = self.fragment_element_ptr_mut(f, 0);
-                            vec.push(from_raw_parts_mut(ptr_s, slice_len));
-                        }
+                        let middle = &fragments[(sf + 1)..ef];
 
                         let slice_len = ei + 1;
                         let ptr_s = self.fragment_element_ptr_mut(ef, 0);
-                        vec.push(from_raw_parts_mut(ptr_s, slice_len));
+                        let back = from_raw_parts_mut(ptr_s, slice_len);
 
-                        vec
+                        SlicesIterMut::from_parts_up_to_capacity(Some(front), middle, Some(back))
                     }
                 }
             }
@@ -255,7 +268,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     {
         self.fragments.set_len(self.num_fragments());
         set_fragments_len(&mut self.fragments, len);
-        let iter = crate::IterMut::new(&mut self.fragments);
+        let iter = crate::IterMut::with_growth(&mut self.fragments, &self.growth, len);
         iter.take(len)
     }
 
@@ -342,7 +355,7 @@ impl<T, G: GrowthWithConstantTimeAccess> ConcurrentPinnedVec<T> for ConcurrentSp
     }
 }
 
-fn data<G: Growth, T>(fragments: &mut Vec<Fragment<T>>, growth: &G) -> Data<T> {
+fn data<G: Growth, T, A: Allocator>(fragments: &mut Vec<Fragment<T, A>>, growth: &G) -> Data<T, A> {
     let num_fragments = fragments.len();
 
     let capacity = fragments.iter().map(|x| x.capacity()).sum::<usize>();
@@ -365,11 +378,11 @@ fn data<G: Growth, T>(fragments: &mut Vec<Fragment<T>>, growth: &G) -> Data<T> {
     }
 }
 
-struct Data<T> {
+struct Data<T, A: Allocator> {
     capacity: usize,
     maximum_capacity: usize,
     num_fragments: usize,
-    ptr_fragments: *mut Fragment<T>,
+    ptr_fragments: *mut Fragment<T, A>,
     fragment_pointers: Vec<*const T>,
     ptr_fragments_pointers: *const *const T,
 }
@@ -0,0 +1,205 @@
+use crate::algorithms::compaction::Compaction;
+use crate::{Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Removes consecutive repeated elements in the vector according to the [`PartialEq`] trait implementation.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    ///
+    /// Please see [`SplitVec::dedup_by`] for details and the remark on pinned elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 2, 3, 2, 2, 2, 4, 4]);
+    ///
+    /// vec.dedup();
+    /// assert_eq!(vec, &[1, 2, 3, 2, 4]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that resolve to the same key.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    ///
+    /// Please see [`SplitVec::dedup_by`] for details and the remark on pinned elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[10, 20, 21, 30, 20]);
+    ///
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec, &[10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes all but the first of consecutive elements in the vector satisfying a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from the vector and must determine if the elements compare equal.
+    /// The elements are passed in opposite order from their order in the vector, so if `same_bucket(a, b)` returns true, `a` is removed.
+    ///
+    /// The elements are visited in a single pass with read and write cursors over the fragments;
+    /// trailing fragments which become empty are dropped.
+    ///
+    /// # Pinned elements
+    ///
+    /// Elements preceding the first removed element keep their memory locations.
+    /// However, elements following a removed element are moved towards the front to close the gap;
+    /// therefore, their memory locations are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.append(vec!["foo", "bar"]);
+    /// vec.append(vec!["Bar", "baz", "bar"]);
+    ///
+    /// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec, &["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if self.len < 2 {
+            return;
+        }
+
+        let mut compaction = Compaction::new(self, 1);
+        while let (Some(ptr), Some(last_kept)) = (compaction.read_ptr(), compaction.last_kept_ptr())
+        {
+            // SAFETY: both pointers point to distinct initialized elements, the last kept being before the read cursor
+            match same_bucket(unsafe { &mut *ptr }, unsafe { &mut *last_kept }) {
+                true => {
+                    compaction.discard(1);
+                    // SAFETY: the discarded element will not be accessed again
+                    unsafe { core::ptr::drop_in_place(ptr) };
+                }
+                false => compaction.keep(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    #[test]
+    fn dedup() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let sequences: [fn(usize) -> usize; 4] = [|x| x, |_| 0, |x| x / 3, |x| x / 7 % 2];
+
+            for sequence in sequences {
+                vec.clear();
+                vec.extend((0..100).map(|x| sequence(x).to_string()));
+                let mut expected: Vec<_> = (0..100).map(|x| sequence(x).to_string()).collect();
+
+                vec.dedup();
+                expected.dedup();
+
+                assert_eq!(vec, expected.as_slice());
+                for (i, x) in expected.iter().enumerate() {
+                    assert_eq!(vec.get(i), Some(x));
+                }
+
+                vec.push("x".to_string());
+                assert_eq!(vec.get(expected.len()), Some(&"x".to_string()));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn dedup_small() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.dedup();
+            assert!(vec.is_empty());
+
+            vec.push(1);
+            vec.dedup();
+            assert_eq!(vec, &[1]);
+
+            vec.push(1);
+            vec.dedup();
+            assert_eq!(vec, &[1]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            vec.dedup_by_key(|x| *x / 10);
+            assert_eq!(vec, &[0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn dedup_by() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            // merges runs of 4 consecutive values into the first element
+            vec.dedup_by(|a, b| match *a / 4 == (*b % 1000) / 4 {
+                true => {
+                    *b += 1000;
+                    true
+                }
+                false => false,
+            });
+
+            let expected: Vec<_> = (0..25).map(|x| 4 * x + 3000).collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn dedup_panic_in_same_bucket() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend((0..40).map(|x| x / 2));
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vec.dedup_by(|a, b| match *a {
+                    #[allow(clippy::panic)]
+                    10 => panic!("panic in same bucket"),
+                    _ => a == b,
+                })
+            }));
+            assert!(result.is_err());
+
+            let expected: Vec<_> = (0..10).chain((20..40).map(|x| x / 2)).collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+}
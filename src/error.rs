@@ -0,0 +1,117 @@
+use alloc::collections::TryReserveError;
+use core::fmt::Display;
+use orx_pinned_vec::PinnedVecGrowthError;
+
+/// Errors that might be observed while growing or reserving capacity for a `SplitVec`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitVecError {
+    /// The required capacity is greater than `max`, the maximum cumulative capacity that can be reached by the growth strategy.
+    CapacityOverflow {
+        /// Maximum cumulative capacity that can be reached by the growth strategy.
+        max: usize,
+    },
+    /// The allocator failed to allocate memory for a fragment or for the fragments collection.
+    AllocationFailed,
+    /// The `required` capacity is greater than `max`, the maximum capacity that can be reached concurrently
+    /// without reallocating the fragments collection.
+    FragmentsCapacityExceeded {
+        /// Capacity that is required.
+        required: usize,
+        /// Maximum capacity that can be reached concurrently with the current fragments collection.
+        max: usize,
+    },
+}
+
+impl Display for SplitVecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CapacityOverflow { max } => write!(
+                f,
+                "Maximum cumulative capacity that can be reached by the growth strategy is {}.",
+                max
+            ),
+            Self::AllocationFailed => write!(f, "Failed to allocate memory for the split vector."),
+            Self::FragmentsCapacityExceeded { required, max } => write!(
+                f,
+                "Required capacity {} exceeds the maximum concurrent capacity {}; the fragments collection must first be reserved.",
+                required, max
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SplitVecError {}
+
+impl From<TryReserveError> for SplitVecError {
+    fn from(_: TryReserveError) -> Self {
+        Self::AllocationFailed
+    }
+}
+
+impl From<allocator_api2::collections::TryReserveError> for SplitVecError {
+    fn from(_: allocator_api2::collections::TryReserveError) -> Self {
+        Self::AllocationFailed
+    }
+}
+
+impl From<SplitVecError> for PinnedVecGrowthError {
+    fn from(_: SplitVecError) -> Self {
+        Self::FailedToGrowWhileKeepingElementsPinned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Doubling, SplitVec};
+    use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+
+    #[test]
+    fn display() {
+        let error = SplitVecError::CapacityOverflow { max: 42 };
+        assert_eq!(
+            error.to_string(),
+            "Maximum cumulative capacity that can be reached by the growth strategy is 42."
+        );
+
+        let error = SplitVecError::FragmentsCapacityExceeded {
+            required: 100,
+            max: 60,
+        };
+        assert!(error
+            .to_string()
+            .starts_with("Required capacity 100 exceeds"));
+    }
+
+    #[test]
+    fn from_try_reserve_error() {
+        let error = Vec::<u64>::new()
+            .try_reserve(usize::MAX)
+            .expect_err("capacity overflows");
+        assert_eq!(SplitVecError::from(error), SplitVecError::AllocationFailed);
+    }
+
+    #[test]
+    fn into_pinned_vec_growth_error() {
+        let error: PinnedVecGrowthError = SplitVecError::AllocationFailed.into();
+        assert_eq!(
+            error,
+            PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned
+        );
+    }
+
+    #[test]
+    fn concurrent_grow_beyond_maximum_capacity() {
+        let vec: SplitVec<char, Doubling> =
+            SplitVec::with_doubling_growth_and_fragments_capacity(2);
+        let con_vec = vec.into_concurrent();
+        let max = con_vec.max_capacity();
+
+        assert_eq!(con_vec.grow_to(max), Ok(max));
+        assert_eq!(
+            con_vec.grow_to(max + 1),
+            Err(PinnedVecGrowthError::FailedToGrowWhileKeepingElementsPinned)
+        );
+    }
+}
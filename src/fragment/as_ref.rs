@@ -1,6 +1,7 @@
 use crate::Fragment;
+use allocator_api2::alloc::Allocator;
 
-impl<T> AsRef<[T]> for Fragment<T> {
+impl<T, A: Allocator> AsRef<[T]> for Fragment<T, A> {
     fn as_ref(&self) -> &[T] {
         &self.data
     }
@@ -1,11 +1,12 @@
 use crate::Fragment;
-use std::fmt::Debug;
+use allocator_api2::alloc::Allocator;
+use core::fmt::Debug;
 
-impl<T> Debug for Fragment<T>
+impl<T, A: Allocator> Debug for Fragment<T, A>
 where
     T: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.data.fmt(f)
     }
 }
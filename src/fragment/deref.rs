@@ -1,13 +1,15 @@
 use crate::Fragment;
-use std::ops::{Deref, DerefMut};
+use allocator_api2::alloc::Allocator;
+use allocator_api2::vec::Vec;
+use core::ops::{Deref, DerefMut};
 
-impl<T> Deref for Fragment<T> {
-    type Target = Vec<T>;
+impl<T, A: Allocator> Deref for Fragment<T, A> {
+    type Target = Vec<T, A>;
     fn deref(&self) -> &Self::Target {
         &self.data
     }
 }
-impl<T> DerefMut for Fragment<T> {
+impl<T, A: Allocator> DerefMut for Fragment<T, A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.data
     }
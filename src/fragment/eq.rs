@@ -1,6 +1,8 @@
 use crate::Fragment;
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
 
-impl<T: PartialEq, U> PartialEq<U> for Fragment<T>
+impl<T: PartialEq, U, A: Allocator> PartialEq<U> for Fragment<T, A>
 where
     U: AsRef<[T]>,
 {
@@ -9,18 +11,18 @@ where
     }
 }
 
-impl<T: PartialEq> PartialEq<Fragment<T>> for [T] {
-    fn eq(&self, other: &Fragment<T>) -> bool {
+impl<T: PartialEq, A: Allocator> PartialEq<Fragment<T, A>> for [T] {
+    fn eq(&self, other: &Fragment<T, A>) -> bool {
         self == other.data
     }
 }
-impl<T: PartialEq> PartialEq<Fragment<T>> for Vec<T> {
-    fn eq(&self, other: &Fragment<T>) -> bool {
-        self == &other.data
+impl<T: PartialEq, A: Allocator> PartialEq<Fragment<T, A>> for Vec<T> {
+    fn eq(&self, other: &Fragment<T, A>) -> bool {
+        self.as_slice() == other.data.as_slice()
     }
 }
-impl<T: PartialEq, const N: usize> PartialEq<Fragment<T>> for [T; N] {
-    fn eq(&self, other: &Fragment<T>) -> bool {
+impl<T: PartialEq, A: Allocator, const N: usize> PartialEq<Fragment<T, A>> for [T; N] {
+    fn eq(&self, other: &Fragment<T, A>) -> bool {
         self.as_slice() == other.data
     }
 }
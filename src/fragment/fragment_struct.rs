@@ -1,40 +1,77 @@
 use crate::Growth;
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use allocator_api2::collections::TryReserveError;
 
-#[derive(Default, Clone)]
 /// A contagious fragment of the split vector.
 ///
 /// Suppose a split vector contains 10 integers from 0 to 9.
 /// Depending on the growth strategy of the split vector,
 /// this data might be stored in 3 contagious fragments,
 /// say [0, 1, 2, 3], [4, 5, 6, 7] and [8, 9].
-pub struct Fragment<T> {
-    pub(crate) data: Vec<T>,
+///
+/// Memory of the fragment is allocated by the allocator `A`, which is the [`Global`] allocator by default.
+#[derive(Clone)]
+pub struct Fragment<T, A: Allocator = Global> {
+    pub(crate) data: allocator_api2::vec::Vec<T, A>,
 }
 
 impl<T> Fragment<T> {
     /// Creates a new fragment with the given `capacity` and pushes already the `first_value`.
     pub fn new_with_first_value(capacity: usize, first_value: T) -> Self {
-        let mut data = Vec::with_capacity(capacity);
-        data.push(first_value);
-        Self { data }
+        Self::new_with_first_value_in(capacity, first_value, Global)
     }
 
     /// Creates a new fragment with the given `capacity`.
     pub fn new(capacity: usize) -> Self {
-        Self {
-            data: Vec::with_capacity(capacity),
-        }
+        Self::new_in(capacity, Global)
+    }
+
+    /// Tries to create a new fragment with the given `capacity`; returns the error if the allocation fails.
+    pub fn try_new(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_new_in(capacity, Global)
     }
 
     /// Creates a new fragment with length and capacity equal to the given `capacity`, where each entry is filled with `f()`.
     pub fn new_filled<F: Fn() -> T>(capacity: usize, f: F) -> Self {
-        let mut data = Vec::with_capacity(capacity);
+        let mut fragment = Self::new(capacity);
         for _ in 0..capacity {
-            data.push(f());
+            fragment.data.push(f());
+        }
+        fragment
+    }
+}
+
+impl<T, A: Allocator + Default> Default for Fragment<T, A> {
+    fn default() -> Self {
+        Self {
+            data: allocator_api2::vec::Vec::new_in(A::default()),
         }
+    }
+}
+
+impl<T, A: Allocator> Fragment<T, A> {
+    /// Creates a new fragment with the given `capacity` allocated by `alloc`, and pushes already the `first_value`.
+    pub fn new_with_first_value_in(capacity: usize, first_value: T, alloc: A) -> Self {
+        let mut data = allocator_api2::vec::Vec::with_capacity_in(capacity, alloc);
+        data.push(first_value);
         Self { data }
     }
 
+    /// Creates a new fragment with the given `capacity` allocated by `alloc`.
+    pub fn new_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            data: allocator_api2::vec::Vec::with_capacity_in(capacity, alloc),
+        }
+    }
+
+    /// Tries to create a new fragment with the given `capacity` allocated by `alloc`; returns the error if the allocation fails.
+    pub fn try_new_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        let mut data = allocator_api2::vec::Vec::new_in(alloc);
+        data.try_reserve_exact(capacity)?;
+        Ok(Self { data })
+    }
+
     /// Returns whether the fragment has room to push a new item or not.
     pub fn has_capacity_for_one(&self) -> bool {
         self.data.len() < self.data.capacity()
@@ -46,24 +83,24 @@ impl<T> Fragment<T> {
     }
 
     // helpers
-    pub(crate) fn fragments_with_default_capacity() -> Vec<Fragment<T>> {
+    pub(crate) fn fragments_with_default_capacity() -> Vec<Fragment<T, A>> {
         Vec::new()
     }
 
-    pub(crate) fn into_fragments(self) -> Vec<Fragment<T>> {
+    pub(crate) fn into_fragments(self) -> Vec<Fragment<T, A>> {
         let mut fragments = Self::fragments_with_default_capacity();
         fragments.push(self);
         fragments
     }
 
-    pub(crate) fn fragments_with_capacity(fragments_capacity: usize) -> Vec<Fragment<T>> {
+    pub(crate) fn fragments_with_capacity(fragments_capacity: usize) -> Vec<Fragment<T, A>> {
         Vec::with_capacity(fragments_capacity)
     }
 
     pub(crate) fn into_fragments_with_capacity(
         self,
         fragments_capacity: usize,
-    ) -> Vec<Fragment<T>> {
+    ) -> Vec<Fragment<T, A>> {
         let mut fragments = Self::fragments_with_capacity(fragments_capacity);
         fragments.push(self);
         fragments
@@ -72,12 +109,15 @@ impl<T> Fragment<T> {
     /// Zeroes out all memory; i.e., positions in `0..fragment.capacity()`, of the fragment.
     #[inline(always)]
     pub(crate) unsafe fn zero(&mut self) {
-        let slice = std::slice::from_raw_parts_mut(self.data.as_mut_ptr(), self.capacity());
-        slice.iter_mut().for_each(|m| *m = std::mem::zeroed());
+        let slice = core::slice::from_raw_parts_mut(self.data.as_mut_ptr(), self.capacity());
+        slice.iter_mut().for_each(|m| *m = core::mem::zeroed());
     }
 }
 
-pub(crate) unsafe fn set_fragments_len<T>(fragments: &mut [Fragment<T>], len: usize) {
+pub(crate) unsafe fn set_fragments_len<T, A: Allocator>(
+    fragments: &mut [Fragment<T, A>],
+    len: usize,
+) {
     let mut remaining = len;
 
     for fragment in fragments {
@@ -96,8 +136,8 @@ pub(crate) unsafe fn set_fragments_len<T>(fragments: &mut [Fragment<T>], len: us
     }
 }
 
-pub(crate) fn maximum_concurrent_capacity<G: Growth, T>(
-    fragments: &[Fragment<T>],
+pub(crate) fn maximum_concurrent_capacity<G: Growth, T, A: Allocator>(
+    fragments: &[Fragment<T, A>],
     fragments_capacity: usize,
     growth: &G,
 ) -> usize {
@@ -116,8 +156,8 @@ pub(crate) fn maximum_concurrent_capacity<G: Growth, T>(
     }
 }
 
-pub(crate) fn num_fragments_for_capacity<G: Growth, T>(
-    fragments: &[Fragment<T>],
+pub(crate) fn num_fragments_for_capacity<G: Growth, T, A: Allocator>(
+    fragments: &[Fragment<T, A>],
     growth: &G,
     required_capacity: usize,
 ) -> (usize, usize) {
@@ -149,7 +189,7 @@ mod tests {
         let mut fragment: Fragment<i32> = Fragment::new(4);
         unsafe { fragment.zero() };
         unsafe { fragment.set_len(4) };
-        let zero: i32 = unsafe { std::mem::zeroed() };
+        let zero: i32 = unsafe { core::mem::zeroed() };
         for i in 0..4 {
             assert_eq!(fragment.get(i), Some(&zero));
         }
@@ -1,12 +1,27 @@
 use crate::Fragment;
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
+use core::mem::ManuallyDrop;
 
 impl<T> From<Vec<T>> for Fragment<T> {
     fn from(value: Vec<T>) -> Self {
-        Self { data: value }
+        let mut value = ManuallyDrop::new(value);
+        let (ptr, len, capacity) = (value.as_mut_ptr(), value.len(), value.capacity());
+        // SAFETY: memory of the vector is allocated by the global allocator
+        let data = unsafe { allocator_api2::vec::Vec::from_raw_parts(ptr, len, capacity) };
+        Self { data }
     }
 }
 impl<T> From<Fragment<T>> for Vec<T> {
     fn from(value: Fragment<T>) -> Self {
-        value.data
+        let (ptr, len, capacity) = value.data.into_raw_parts();
+        // SAFETY: memory of the fragment is allocated by the global allocator
+        unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+    }
+}
+
+impl<T, A: Allocator> From<allocator_api2::vec::Vec<T, A>> for Fragment<T, A> {
+    fn from(value: allocator_api2::vec::Vec<T, A>) -> Self {
+        Self { data: value }
     }
 }
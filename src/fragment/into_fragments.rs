@@ -1,9 +1,11 @@
 use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
 
 /// Converts self into a collection of [`Fragment`]s.
-pub trait IntoFragments<T> {
+pub trait IntoFragments<T, A: Allocator = Global> {
     /// Converts self into a collection of [`Fragment`]s.
-    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>>;
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T, A>>;
 }
 
 impl<T> IntoFragments<T> for Vec<T> {
@@ -24,8 +26,8 @@ impl<T> IntoFragments<T> for Vec<Vec<T>> {
     }
 }
 
-impl<T, G: Growth> IntoFragments<T> for SplitVec<T, G> {
-    fn into_fragments(self) -> impl Iterator<Item = Fragment<T>> {
+impl<T, G: Growth, A: Allocator> IntoFragments<T, A> for SplitVec<T, G, A> {
+    fn into_fragments(self) -> impl Iterator<Item = Fragment<T, A>> {
         self.fragments.into_iter()
     }
 }
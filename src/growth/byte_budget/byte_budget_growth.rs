@@ -0,0 +1,423 @@
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Fragment, Linear, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
+use core::marker::PhantomData;
+use orx_pseudo_default::PseudoDefault;
+
+const MAX_FRAGMENT_CAPACITY_EXPONENT: usize = 31;
+const DEFAULT_FRAGMENT_BYTES: usize = 64 * 1024;
+
+/// Strategy which allows the split vector to grow linearly with fragments targeting a given number of bytes,
+/// rather than a given number of elements.
+///
+/// The strategy is tied to the element type `T`; the element capacity of each fragment is computed from the size of `T`
+/// as the largest power of two such that the fragment does not exceed `fragment_bytes`.
+/// When a single element is larger than the budget, each fragment has a capacity of one element.
+///
+/// Therefore, a `SplitVec<[u8; 4096], ByteBudget<[u8; 4096]>>` and a `SplitVec<u8, ByteBudget<u8>>` created with the same budget
+/// allocate fragments of similar sizes in bytes.
+///
+/// Since the element capacities are powers of two, the strategy allows for constant time random access
+/// exactly as the `Linear` strategy; and hence, it implements [`GrowthWithConstantTimeAccess`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // 64 KiB per fragment
+/// let bytes = vec![0u8; 100_000];
+/// let mut vec: SplitVec<u8, ByteBudget<u8>> = SplitVec::with_byte_budget_growth(64 * 1024);
+/// vec.extend_from_slice(&bytes);
+///
+/// assert_eq!(vec.fragments().len(), 2);
+/// assert_eq!(vec.fragments()[0].capacity(), 65_536);
+///
+/// let mut vec: SplitVec<[u8; 4096], ByteBudget<[u8; 4096]>> = SplitVec::with_byte_budget_growth(64 * 1024);
+/// for _ in 0..20 {
+///     vec.push([0; 4096]);
+/// }
+///
+/// assert_eq!(vec.fragments().len(), 2);
+/// assert_eq!(vec.fragments()[0].capacity(), 16);
+///
+/// // the budget is not a power of two: largest power of two element capacity fitting in the budget is used
+/// let vec: SplitVec<u64, ByteBudget<u64>> = SplitVec::with_byte_budget_growth(1000);
+/// assert_eq!(vec.fragments()[0].capacity(), 64);
+/// ```
+pub struct ByteBudget<T> {
+    fragment_bytes: usize,
+    linear: Linear,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> core::fmt::Debug for ByteBudget<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ByteBudget")
+            .field("fragment_bytes", &self.fragment_bytes)
+            .field("linear", &self.linear)
+            .finish()
+    }
+}
+
+impl<T> Clone for ByteBudget<T> {
+    fn clone(&self) -> Self {
+        Self {
+            fragment_bytes: self.fragment_bytes,
+            linear: self.linear.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for ByteBudget<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fragment_bytes == other.fragment_bytes && self.linear == other.linear
+    }
+}
+
+impl<T> ByteBudget<T> {
+    /// Creates a byte budget growth strategy for elements of type `T` where each fragment is sized to be at most `fragment_bytes`;
+    /// unless a single element of `T` is larger than `fragment_bytes`, in which case each fragment holds one element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragment_bytes` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let growth = ByteBudget::<u32>::new(4096);
+    /// assert_eq!(growth.fragment_bytes(), 4096);
+    /// assert_eq!(growth.fragment_capacity(), 1024);
+    ///
+    /// let vec: SplitVec<u32, _> = SplitVec::with_growth(growth);
+    /// assert_eq!(vec.capacity(), 1024);
+    /// ```
+    pub fn new(fragment_bytes: usize) -> Self {
+        assert!(fragment_bytes > 0);
+
+        let exponent = fragment_capacity_exponent(fragment_bytes, core::mem::size_of::<T>());
+        Self {
+            fragment_bytes,
+            linear: Linear::new(exponent),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the targeted number of bytes of each fragment.
+    pub fn fragment_bytes(&self) -> usize {
+        self.fragment_bytes
+    }
+
+    /// Returns the constant element capacity of each fragment computed from the byte budget.
+    pub fn fragment_capacity(&self) -> usize {
+        self.linear.new_fragment_capacity_from([].into_iter())
+    }
+}
+
+/// Returns the exponent of the largest power of two element capacity such that elements of `element_size` bytes
+/// fit in `fragment_bytes`; zero if a single element does not fit.
+fn fragment_capacity_exponent(fragment_bytes: usize, element_size: usize) -> usize {
+    let max_num_elements = fragment_bytes / usize::max(element_size, 1);
+    let exponent = match max_num_elements {
+        0 => 0,
+        x => (usize::BITS - 1 - x.leading_zeros()) as usize,
+    };
+    usize::min(exponent, MAX_FRAGMENT_CAPACITY_EXPONENT)
+}
+
+impl<T> PseudoDefault for ByteBudget<T> {
+    /// Creates a byte budget growth strategy for elements of type `T` with a budget of 64 KiB per fragment.
+    fn pseudo_default() -> Self {
+        Self::new(DEFAULT_FRAGMENT_BYTES)
+    }
+}
+
+impl<E> Growth for ByteBudget<E> {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        self.linear.new_fragment_capacity_from(fragment_capacities)
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        self.linear
+            .get_fragment_and_inner_indices(vec_len, fragments, element_index)
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        self.linear
+            .maximum_concurrent_capacity(fragments, fragments_capacity)
+    }
+
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        self.linear
+            .required_fragments_len(fragments, maximum_capacity)
+    }
+}
+
+impl<E> GrowthWithConstantTimeAccess for ByteBudget<E> {
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        self.linear
+            .get_fragment_and_inner_indices_unchecked(element_index)
+    }
+}
+
+impl<T> SplitVec<T, ByteBudget<T>> {
+    /// Creates a split vector with linear growth where each fragment is sized to be at most `fragment_bytes`;
+    /// the element capacity of each fragment is the largest power of two fitting in the budget given the size of `T`.
+    ///
+    /// When a single element of `T` is larger than `fragment_bytes`, each fragment holds one element.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragment_bytes` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // fragments of 2 MiB huge-pages
+    /// let huge_page = 2 * 1024 * 1024;
+    ///
+    /// let vec: SplitVec<u8, _> = SplitVec::with_byte_budget_growth(huge_page);
+    /// assert_eq!(vec.fragments()[0].capacity(), 2 * 1024 * 1024);
+    ///
+    /// let vec: SplitVec<u64, _> = SplitVec::with_byte_budget_growth(huge_page);
+    /// assert_eq!(vec.fragments()[0].capacity(), 256 * 1024);
+    ///
+    /// let vec: SplitVec<[u8; 4096], _> = SplitVec::with_byte_budget_growth(huge_page);
+    /// assert_eq!(vec.fragments()[0].capacity(), 512);
+    /// ```
+    pub fn with_byte_budget_growth(fragment_bytes: usize) -> Self {
+        let growth = ByteBudget::new(fragment_bytes);
+        let fragments = Fragment::new(growth.fragment_capacity()).into_fragments();
+        Self::from_raw_parts(0, fragments, growth)
+    }
+
+    /// Creates a new split vector with `ByteBudget` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_byte_budget_growth`] only by the pre-allocation of fragments collection.
+    /// The pre-allocation matters only in concurrent programs, keeping the meta information of up to `fragments_capacity` fragments pinned just like the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragment_bytes == 0` or `fragments_capacity == 0`.
+    pub fn with_byte_budget_growth_and_fragments_capacity(
+        fragment_bytes: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        assert!(fragments_capacity > 0);
+
+        let growth = ByteBudget::new(fragment_bytes);
+        let fragments = Fragment::new(growth.fragment_capacity())
+            .into_fragments_with_capacity(fragments_capacity);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Global;
+
+    #[test]
+    fn fragment_capacity() {
+        assert_eq!(ByteBudget::<u8>::new(1).fragment_capacity(), 1);
+        assert_eq!(ByteBudget::<u8>::new(2).fragment_capacity(), 2);
+        assert_eq!(ByteBudget::<u8>::new(3).fragment_capacity(), 2);
+        assert_eq!(ByteBudget::<u8>::new(1024).fragment_capacity(), 1024);
+        assert_eq!(ByteBudget::<u8>::new(1025).fragment_capacity(), 1024);
+        assert_eq!(ByteBudget::<u8>::new(2047).fragment_capacity(), 1024);
+
+        assert_eq!(ByteBudget::<u32>::new(1024).fragment_capacity(), 256);
+        assert_eq!(ByteBudget::<u32>::new(1023).fragment_capacity(), 128);
+        assert_eq!(ByteBudget::<u32>::new(3).fragment_capacity(), 1);
+
+        assert_eq!(
+            ByteBudget::<[u8; 4096]>::new(64 * 1024).fragment_capacity(),
+            16
+        );
+        assert_eq!(ByteBudget::<[u8; 4096]>::new(4095).fragment_capacity(), 1);
+    }
+
+    #[test]
+    fn fragment_capacity_exhaustive() {
+        for element_size in 1..100 {
+            for fragment_bytes in 1..10_000 {
+                let capacity: usize = 1 << fragment_capacity_exponent(fragment_bytes, element_size);
+                assert!(capacity.is_power_of_two());
+                match element_size <= fragment_bytes {
+                    true => {
+                        assert!(capacity * element_size <= fragment_bytes);
+                        assert!(capacity * 2 * element_size > fragment_bytes);
+                    }
+                    false => assert_eq!(capacity, 1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fragment_capacity_zero_sized() {
+        assert_eq!(ByteBudget::<()>::new(1).fragment_capacity(), 1);
+        assert_eq!(ByteBudget::<()>::new(1024).fragment_capacity(), 1024);
+        assert_eq!(
+            ByteBudget::<()>::new(usize::MAX).fragment_capacity(),
+            1 << MAX_FRAGMENT_CAPACITY_EXPONENT
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_fragment_bytes() {
+        let _ = ByteBudget::<u8>::new(0);
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        let growth = ByteBudget::<u64>::new(256);
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<u64, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<u64, Global>(index, &[], index);
+
+        for index in 0..111111 {
+            let (f, i) = (index / 32, index % 32);
+            assert_eq!(
+                (f, i),
+                growth.get_fragment_and_inner_indices_unchecked(index)
+            );
+            assert_eq!(Some((f, i)), get(index));
+            assert_eq!(None, get_none(index));
+        }
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, ByteBudget<T>>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<u32, ByteBudget<u32>> =
+            SplitVec::with_byte_budget_growth_and_fragments_capacity(64, 4);
+        assert_eq!(max_cap(&vec), 4 * 16);
+
+        let until = max_cap(&vec);
+        for i in 0..until {
+            vec.push(i as u32);
+            assert_eq!(max_cap(&vec), 4 * 16);
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push(42);
+        assert_eq!(max_cap(&vec), 8 * 16);
+    }
+
+    #[test]
+    fn with_byte_budget_growth_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, _> =
+            SplitVec::with_byte_budget_growth_and_fragments_capacity(1024, 1);
+
+        assert_eq!(1, vec.fragments.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_byte_budget_growth_and_fragments_capacity_zero() {
+        let _: SplitVec<char, _> =
+            SplitVec::with_byte_budget_growth_and_fragments_capacity(1024, 0);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<u64, ByteBudget<u64>> = SplitVec::with_byte_budget_growth(256);
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(32), Ok(1));
+        assert_eq!(num_fragments(33), Ok(2));
+        assert_eq!(num_fragments(64), Ok(2));
+        assert_eq!(num_fragments(65), Ok(3));
+    }
+}
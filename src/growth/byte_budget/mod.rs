@@ -0,0 +1,6 @@
+mod byte_budget_growth;
+
+#[cfg(test)]
+mod tests;
+
+pub use byte_budget_growth::ByteBudget;
@@ -0,0 +1,88 @@
+use crate::{ByteBudget, Fragment, Global, Growth, PseudoDefault};
+
+#[test]
+fn new_cap() {
+    fn new_fra(cap: usize) -> Fragment<u16> {
+        Vec::<u16>::with_capacity(cap).into()
+    }
+
+    let growth = ByteBudget::<u16>::new(100);
+    assert_eq!(32, growth.new_fragment_capacity::<u16, Global>(&[]));
+    assert_eq!(32, growth.new_fragment_capacity(&[new_fra(32)]));
+    assert_eq!(
+        32,
+        growth.new_fragment_capacity(&[new_fra(32), new_fra(32)])
+    );
+}
+
+#[test]
+fn pseudo_default() {
+    let growth = ByteBudget::<u8>::pseudo_default();
+    assert_eq!(growth.fragment_bytes(), 64 * 1024);
+    assert_eq!(growth.fragment_capacity(), 64 * 1024);
+
+    let growth = ByteBudget::<u64>::pseudo_default();
+    assert_eq!(growth.fragment_bytes(), 64 * 1024);
+    assert_eq!(growth.fragment_capacity(), 8 * 1024);
+
+    let growth = ByteBudget::<[u8; 4096]>::pseudo_default();
+    assert_eq!(growth.fragment_capacity(), 16);
+}
+
+#[test]
+fn indices_panics_when_fragments_is_empty() {
+    let growth = ByteBudget::<usize>::new(64);
+    assert_eq!(
+        None,
+        <ByteBudget<usize> as Growth>::get_fragment_and_inner_indices::<usize, Global>(
+            &growth,
+            0,
+            &[],
+            0
+        )
+    );
+}
+
+#[test]
+fn indices() {
+    fn new_full() -> Fragment<u32> {
+        (0..4).collect::<Vec<_>>().into()
+    }
+    fn new_half() -> Fragment<u32> {
+        let mut vec = Vec::with_capacity(4);
+        for i in 0..2 {
+            vec.push(10 + i);
+        }
+        vec.into()
+    }
+
+    let growth = ByteBudget::<u32>::new(16);
+
+    for i in 0..4 {
+        assert_eq!(
+            Some((0, i)),
+            growth.get_fragment_and_inner_indices(4, &[new_full()], i)
+        );
+    }
+    assert_eq!(
+        None,
+        growth.get_fragment_and_inner_indices(4, &[new_full()], 4)
+    );
+
+    for i in 0..4 {
+        assert_eq!(
+            Some((0, i)),
+            growth.get_fragment_and_inner_indices(6, &[new_full(), new_half()], i)
+        );
+    }
+    for i in 4..6 {
+        assert_eq!(
+            Some((1, i - 4)),
+            growth.get_fragment_and_inner_indices(6, &[new_full(), new_half()], i)
+        );
+    }
+    assert_eq!(
+        None,
+        growth.get_fragment_and_inner_indices(6, &[new_full(), new_half()], 6)
+    );
+}
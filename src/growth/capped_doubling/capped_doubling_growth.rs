@@ -0,0 +1,539 @@
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Doubling, Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
+use orx_pseudo_default::PseudoDefault;
+
+const FIRST_FRAGMENT_CAPACITY_POW: usize = 2;
+const FIRST_FRAGMENT_CAPACITY: usize = usize::pow(2, FIRST_FRAGMENT_CAPACITY_POW as u32);
+const MAX_FRAGMENT_CAPACITY_EXPONENT_UPPER_BOUND: usize = 32;
+
+/// Strategy which allows the split vector to grow exponentially up to a maximum fragment capacity,
+/// and linearly afterwards.
+///
+/// * The first fragment has a capacity of 4, as in the `Doubling` strategy.
+/// * Each following fragment doubles the capacity of the prior fragment until the capacity reaches `2 ^ max_fragment_capacity_exponent`.
+/// * All remaining fragments have a constant capacity of `2 ^ max_fragment_capacity_exponent`, as in the `Linear` strategy.
+///
+/// This strategy is useful for long-lived and large collections, such as logs, where a single allocation must never get too large.
+///
+/// Since all fragment capacities are powers of two, the strategy allows for constant time random access
+/// in both the doubling and the linear phases; and hence, it implements [`GrowthWithConstantTimeAccess`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // SplitVec<usize, CappedDoubling>
+/// let mut vec = SplitVec::with_capped_doubling_growth(4);
+///
+/// assert_eq!(1, vec.fragments().len());
+/// assert_eq!(Some(4), vec.fragments().first().map(|f| f.capacity()));
+/// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+///
+/// // fill the first 5 fragments
+/// let expected_fragment_capacities = vec![4, 8, 16, 16, 16];
+/// let num_items: usize = expected_fragment_capacities.iter().sum();
+/// for i in 0..num_items {
+///     vec.push(i);
+/// }
+///
+/// assert_eq!(
+///     expected_fragment_capacities,
+///     vec.fragments()
+///     .iter()
+///     .map(|f| f.capacity())
+///     .collect::<Vec<_>>()
+/// );
+///
+/// // create the 6-th fragment with the maximum fragment capacity
+/// vec.push(42);
+/// assert_eq!(6, vec.fragments().len());
+/// assert_eq!(vec.fragments().last().map(|f| f.capacity()), Some(16));
+/// assert_eq!(vec.fragments().last().map(|f| f.len()), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CappedDoubling {
+    max_fragment_capacity_exponent: usize,
+    max_fragment_capacity: usize,
+    num_doubling_fragments: usize,
+    doubling_capacity: usize,
+}
+
+impl CappedDoubling {
+    /// Creates a capped doubling growth strategy where the first fragment has a capacity of 4,
+    /// and capacities of the following fragments double until they reach `2 ^ max_fragment_capacity_exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_fragment_capacity_exponent` is not within [2, 32); i.e., if the maximum fragment capacity is less than 4 or greater than 2^31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let growth = CappedDoubling::new(4);
+    /// assert_eq!(growth.max_fragment_capacity(), 16);
+    ///
+    /// let mut vec: SplitVec<char, _> = SplitVec::with_growth(growth);
+    /// for _ in 0..(4 + 8 + 16 + 16 + 1) {
+    ///     vec.push('x');
+    /// }
+    /// assert_eq!(vec.fragments().len(), 5);
+    /// assert_eq!(vec.capacity(), 4 + 8 + 16 + 16 + 16);
+    /// ```
+    pub fn new(max_fragment_capacity_exponent: usize) -> Self {
+        assert!(
+            (FIRST_FRAGMENT_CAPACITY_POW..MAX_FRAGMENT_CAPACITY_EXPONENT_UPPER_BOUND)
+                .contains(&max_fragment_capacity_exponent),
+            "max_fragment_capacity_exponent of the capped doubling growth must be within [2, 32)"
+        );
+
+        let max_fragment_capacity = usize::pow(2, max_fragment_capacity_exponent as u32);
+        let num_doubling_fragments = max_fragment_capacity_exponent - FIRST_FRAGMENT_CAPACITY_POW;
+        let doubling_capacity = max_fragment_capacity - FIRST_FRAGMENT_CAPACITY;
+
+        Self {
+            max_fragment_capacity_exponent,
+            max_fragment_capacity,
+            num_doubling_fragments,
+            doubling_capacity,
+        }
+    }
+
+    /// Returns the maximum capacity of a fragment; i.e., the constant capacity of fragments in the linear phase.
+    pub fn max_fragment_capacity(&self) -> usize {
+        self.max_fragment_capacity
+    }
+
+    /// Returns the cumulative capacity of the first `num_fragments` fragments.
+    #[inline(always)]
+    fn cumulative_capacity(&self, num_fragments: usize) -> usize {
+        match num_fragments <= self.num_doubling_fragments {
+            true => (FIRST_FRAGMENT_CAPACITY << num_fragments) - FIRST_FRAGMENT_CAPACITY,
+            false => (num_fragments - self.num_doubling_fragments)
+                .saturating_mul(self.max_fragment_capacity)
+                .saturating_add(self.doubling_capacity),
+        }
+    }
+}
+
+impl PseudoDefault for CappedDoubling {
+    fn pseudo_default() -> Self {
+        Self::new(20)
+    }
+}
+
+impl Growth for CappedDoubling {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        let f = fragment_capacities.len();
+        match f < self.num_doubling_fragments {
+            true => FIRST_FRAGMENT_CAPACITY << f,
+            false => self.max_fragment_capacity,
+        }
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        _fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => Some(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        self.cumulative_capacity(fragments_capacity)
+    }
+
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        _: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        match maximum_capacity <= self.doubling_capacity {
+            true => {
+                // smallest f such that 4 * (2^f - 1) >= maximum_capacity
+                let num_first_fragments = maximum_capacity.div_ceil(FIRST_FRAGMENT_CAPACITY);
+                Ok((usize::BITS - num_first_fragments.leading_zeros()) as usize)
+            }
+            false => {
+                let linear_capacity = maximum_capacity - self.doubling_capacity;
+                let num_linear_fragments = linear_capacity.div_ceil(self.max_fragment_capacity);
+                Ok(self.num_doubling_fragments + num_linear_fragments)
+            }
+        }
+    }
+}
+
+impl GrowthWithConstantTimeAccess for CappedDoubling {
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        match element_index < self.doubling_capacity {
+            true => Doubling.get_fragment_and_inner_indices_unchecked(element_index),
+            false => {
+                let linear_index = element_index - self.doubling_capacity;
+                let f = linear_index >> self.max_fragment_capacity_exponent;
+                let i = linear_index & (self.max_fragment_capacity - 1);
+                (self.num_doubling_fragments + f, i)
+            }
+        }
+    }
+}
+
+impl<T> SplitVec<T, CappedDoubling> {
+    /// Creates a split vector with capped doubling growth:
+    /// * the first fragment has a capacity of 4,
+    /// * capacity of each following fragment doubles until it reaches `2 ^ max_fragment_capacity_exponent`,
+    /// * all following fragments have a capacity of `2 ^ max_fragment_capacity_exponent`.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_fragment_capacity_exponent` is not within [2, 32); i.e., if the maximum fragment capacity is less than 4 or greater than 2^31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // fragment capacities double up to 2^20 = 1_048_576, and stay constant afterwards
+    /// let mut vec: SplitVec<u32, _> = SplitVec::with_capped_doubling_growth(20);
+    /// assert_eq!(vec.growth().max_fragment_capacity(), 1 << 20);
+    ///
+    /// for i in 0..3_000_000 {
+    ///     vec.push(i);
+    /// }
+    ///
+    /// let largest = vec.fragments().iter().map(|f| f.capacity()).max();
+    /// assert_eq!(largest, Some(1 << 20));
+    ///
+    /// assert_eq!(vec.get(2_999_999), Some(&2_999_999));
+    /// ```
+    pub fn with_capped_doubling_growth(max_fragment_capacity_exponent: usize) -> Self {
+        let fragments = Fragment::new(FIRST_FRAGMENT_CAPACITY).into_fragments();
+        let growth = CappedDoubling::new(max_fragment_capacity_exponent);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+
+    /// Creates a new split vector with `CappedDoubling` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_capped_doubling_growth`] only by the pre-allocation of fragments collection.
+    /// Only concurrent programs benefit from this, since the fragments collection is not reallocated while the vector grows to `fragments_capacity` fragments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `fragments_capacity == 0`, or
+    /// * `max_fragment_capacity_exponent` is not within [2, 32).
+    pub fn with_capped_doubling_growth_and_fragments_capacity(
+        max_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        assert!(fragments_capacity > 0);
+        let fragments =
+            Fragment::new(FIRST_FRAGMENT_CAPACITY).into_fragments_with_capacity(fragments_capacity);
+        let growth = CappedDoubling::new(max_fragment_capacity_exponent);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Global;
+    use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+
+    #[test]
+    fn get_fragment_and_inner_indices() {
+        let growth = CappedDoubling::new(4);
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+        // 4 - 8 - 16 - 16 - 16
+        assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
+        assert_eq!((0, 3), growth.get_fragment_and_inner_indices_unchecked(3));
+        assert_eq!((1, 0), growth.get_fragment_and_inner_indices_unchecked(4));
+        assert_eq!((1, 7), growth.get_fragment_and_inner_indices_unchecked(11));
+        assert_eq!((2, 0), growth.get_fragment_and_inner_indices_unchecked(12));
+        assert_eq!((2, 15), growth.get_fragment_and_inner_indices_unchecked(27));
+        assert_eq!((3, 0), growth.get_fragment_and_inner_indices_unchecked(28));
+        assert_eq!((4, 1), growth.get_fragment_and_inner_indices_unchecked(45));
+
+        assert_eq!(Some((1, 7)), get(11));
+        assert_eq!(Some((4, 1)), get(45));
+
+        assert_eq!(None, get_none(11));
+        assert_eq!(None, get_none(45));
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        for max_exp in [2, 3, 7, 12] {
+            let growth = CappedDoubling::new(max_exp);
+
+            let get = |index| {
+                growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index)
+            };
+            let get_none =
+                |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+            let mut f = 0;
+            let mut prev_cumulative_capacity = 0;
+            let mut curr_capacity = 4;
+            let mut cumulative_capacity = 4;
+
+            for index in 0..333333 {
+                if index == cumulative_capacity {
+                    prev_cumulative_capacity = cumulative_capacity;
+                    curr_capacity = usize::min(curr_capacity * 2, 1 << max_exp);
+                    cumulative_capacity += curr_capacity;
+                    f += 1;
+                }
+
+                let (f, i) = (f, index - prev_cumulative_capacity);
+                assert_eq!(
+                    (f, i),
+                    growth.get_fragment_and_inner_indices_unchecked(index)
+                );
+                assert_eq!(Some((f, i)), get(index));
+                assert_eq!(None, get_none(index));
+            }
+        }
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, CappedDoubling>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<char, CappedDoubling> =
+            SplitVec::with_capped_doubling_growth_and_fragments_capacity(4, 4);
+        assert_eq!(max_cap(&vec), 4 + 8 + 16 + 16);
+
+        let until = max_cap(&vec);
+        for _ in 0..until {
+            vec.push('x');
+            assert_eq!(max_cap(&vec), 4 + 8 + 16 + 16);
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push('x');
+        assert_eq!(max_cap(&vec), 4 + 8 + 16 + 16 * 5);
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity_exhaustive() {
+        for max_exp in [2, 3, 7, 12] {
+            let growth = CappedDoubling::new(max_exp);
+            let mut fragments: Vec<Fragment<char>> = vec![];
+            let mut capacity = 0;
+            for fragments_capacity in 0..100 {
+                assert_eq!(
+                    capacity,
+                    growth
+                        .maximum_concurrent_capacity(&[] as &[Fragment<char>], fragments_capacity)
+                );
+                let fragment = Fragment::new(growth.new_fragment_capacity(&fragments));
+                capacity += fragment.capacity();
+                fragments.push(fragment);
+            }
+        }
+    }
+
+    #[test]
+    fn with_capped_doubling_growth_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, _> =
+            SplitVec::with_capped_doubling_growth_and_fragments_capacity(10, 1);
+
+        assert_eq!(1, vec.fragments.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_capped_doubling_growth_and_fragments_capacity_zero() {
+        let _: SplitVec<char, _> =
+            SplitVec::with_capped_doubling_growth_and_fragments_capacity(10, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_capped_doubling_growth_exponent_too_small() {
+        let _: SplitVec<char, _> = SplitVec::with_capped_doubling_growth(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_capped_doubling_growth_exponent_too_large() {
+        let _: SplitVec<char, _> = SplitVec::with_capped_doubling_growth(32);
+    }
+
+    #[test]
+    fn new_exponent_range() {
+        assert_eq!(CappedDoubling::new(2).max_fragment_capacity(), 4);
+        assert_eq!(CappedDoubling::new(31).max_fragment_capacity(), 1 << 31);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be within [2, 32)")]
+    fn new_exponent_too_small() {
+        let _ = CappedDoubling::new(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be within [2, 32)")]
+    fn new_exponent_too_large() {
+        let _ = CappedDoubling::new(32);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<char, CappedDoubling> = SplitVec::with_capped_doubling_growth(4);
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        // 4 - 12 - 28 - 44 - 60
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(4), Ok(1));
+        assert_eq!(num_fragments(5), Ok(2));
+        assert_eq!(num_fragments(12), Ok(2));
+        assert_eq!(num_fragments(13), Ok(3));
+        assert_eq!(num_fragments(28), Ok(3));
+        assert_eq!(num_fragments(29), Ok(4));
+        assert_eq!(num_fragments(44), Ok(4));
+        assert_eq!(num_fragments(45), Ok(5));
+        assert_eq!(num_fragments(60), Ok(5));
+        assert_eq!(num_fragments(61), Ok(6));
+    }
+
+    #[test]
+    fn required_fragments_len_exhaustive() {
+        for max_exp in [2, 3, 7, 12] {
+            let growth = CappedDoubling::new(max_exp);
+            for max_cap in 0..33333 {
+                let f = growth
+                    .required_fragments_len::<char, Global>(&[], max_cap)
+                    .expect("is-ok");
+                assert!(growth.cumulative_capacity(f) >= max_cap);
+                if f > 0 {
+                    assert!(growth.cumulative_capacity(f - 1) < max_cap);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn concurrent_reserve() {
+        let mut vec: SplitVec<char, CappedDoubling> = SplitVec::with_capped_doubling_growth(4);
+
+        let max_cap = vec.concurrent_reserve(1000).expect("is-ok");
+        assert!(max_cap >= 1000);
+        assert_eq!(max_cap, vec.maximum_concurrent_capacity());
+
+        // fragments collection does not reallocate while growing up to max_cap
+        let fragments_ptr = vec.fragments.as_ptr();
+        for _ in 0..max_cap {
+            vec.push('x');
+        }
+        assert_eq!(fragments_ptr, vec.fragments.as_ptr());
+    }
+
+    #[test]
+    fn into_concurrent() {
+        let vec: SplitVec<usize, CappedDoubling> =
+            SplitVec::with_capped_doubling_growth_and_fragments_capacity(5, 64);
+        let con_vec = vec.into_concurrent();
+
+        let len = 1000;
+        assert!(con_vec.max_capacity() >= len);
+
+        let capacity = con_vec.grow_to(len).expect("is-ok");
+        assert!(capacity >= len);
+
+        for i in 0..len {
+            unsafe { con_vec.get_ptr_mut(i).write(i) };
+        }
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        assert_eq!(vec.len(), len);
+        for i in 0..len {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        assert!(vec.fragments().iter().all(|f| f.capacity() <= 32));
+    }
+}
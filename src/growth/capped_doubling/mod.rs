@@ -0,0 +1,6 @@
+mod capped_doubling_growth;
+
+#[cfg(test)]
+mod tests;
+
+pub use capped_doubling_growth::CappedDoubling;
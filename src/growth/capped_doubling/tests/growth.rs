@@ -0,0 +1,89 @@
+use crate::{CappedDoubling, Fragment, Global, Growth, PseudoDefault};
+
+#[test]
+fn new_cap() {
+    fn new_fra(cap: usize) -> Fragment<usize> {
+        Vec::<usize>::with_capacity(cap).into()
+    }
+
+    let growth = CappedDoubling::new(4);
+    assert_eq!(4, growth.new_fragment_capacity::<usize, Global>(&[]));
+    assert_eq!(8, growth.new_fragment_capacity(&[new_fra(4)]));
+    assert_eq!(16, growth.new_fragment_capacity(&[new_fra(4), new_fra(8)]));
+    assert_eq!(
+        16,
+        growth.new_fragment_capacity(&[new_fra(4), new_fra(8), new_fra(16)])
+    );
+    assert_eq!(
+        16,
+        growth.new_fragment_capacity(&[new_fra(4), new_fra(8), new_fra(16), new_fra(16)])
+    );
+
+    let growth = CappedDoubling::new(2);
+    assert_eq!(4, growth.new_fragment_capacity::<usize, Global>(&[]));
+    assert_eq!(4, growth.new_fragment_capacity(&[new_fra(4)]));
+}
+
+#[test]
+fn max_fragment_capacity() {
+    assert_eq!(4, CappedDoubling::new(2).max_fragment_capacity());
+    assert_eq!(1024, CappedDoubling::new(10).max_fragment_capacity());
+    assert_eq!(
+        1 << 20,
+        CappedDoubling::pseudo_default().max_fragment_capacity()
+    );
+}
+
+#[test]
+fn indices_panics_when_fragments_is_empty() {
+    let growth = CappedDoubling::new(4);
+    assert_eq!(
+        None,
+        <CappedDoubling as Growth>::get_fragment_and_inner_indices::<usize, Global>(
+            &growth,
+            0,
+            &[],
+            0
+        )
+    );
+}
+
+#[test]
+fn indices() {
+    fn new_full(cap: usize) -> Fragment<usize> {
+        (0..cap).collect::<Vec<_>>().into()
+    }
+    fn new_half() -> Fragment<usize> {
+        let mut vec = Vec::with_capacity(8);
+        for i in 0..4 {
+            vec.push(10 + i);
+        }
+        vec.into()
+    }
+
+    let growth = CappedDoubling::new(3);
+    let fragments = [new_full(4), new_full(8), new_half()];
+
+    for i in 0..4 {
+        assert_eq!(
+            Some((0, i)),
+            growth.get_fragment_and_inner_indices(16, &fragments, i)
+        );
+    }
+    for i in 4..12 {
+        assert_eq!(
+            Some((1, i - 4)),
+            growth.get_fragment_and_inner_indices(16, &fragments, i)
+        );
+    }
+    for i in 12..16 {
+        assert_eq!(
+            Some((2, i - 12)),
+            growth.get_fragment_and_inner_indices(16, &fragments, i)
+        );
+    }
+    assert_eq!(
+        None,
+        growth.get_fragment_and_inner_indices(16, &fragments, 16)
+    );
+}
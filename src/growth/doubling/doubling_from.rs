@@ -0,0 +1,384 @@
+use super::constants::*;
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
+use orx_pseudo_default::PseudoDefault;
+
+/// Strategy which creates a fragment with double the capacity
+/// of the prior fragment every time the split vector needs to expand,
+/// starting from a first fragment with a capacity of `2 ^ FIRST_FRAGMENT_CAPACITY_POW`.
+///
+/// This is a generalization of the [`Doubling`] strategy, which is fixed to start with a capacity of 4 (`2 ^ 2`).
+/// Starting with a larger first fragment is useful when the vector is known to grow large,
+/// since it avoids allocating many tiny fragments at the beginning.
+/// Random access still has constant time complexity, as the fragment and inner indices are computed from precomputed cumulative capacities.
+///
+/// `FIRST_FRAGMENT_CAPACITY_POW` must be less than 32.
+///
+/// [`Doubling`]: crate::Doubling
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // first fragment capacity is 2^10 = 1024
+/// let mut vec: SplitVec<usize, DoublingFrom<10>> = SplitVec::with_doubling_growth_from();
+///
+/// assert_eq!(1, vec.fragments().len());
+/// assert_eq!(Some(1024), vec.fragments().first().map(|f| f.capacity()));
+/// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+///
+/// // fill the first 3 fragments
+/// let expected_fragment_capacities = vec![1024, 2048, 4096];
+/// let num_items: usize = expected_fragment_capacities.iter().sum();
+/// for i in 0..num_items {
+///     vec.push(i);
+/// }
+///
+/// assert_eq!(
+///     expected_fragment_capacities,
+///     vec.fragments()
+///     .iter()
+///     .map(|f| f.capacity())
+///     .collect::<Vec<_>>()
+/// );
+///
+/// // create the 4-th fragment doubling the capacity
+/// vec.push(42);
+/// assert_eq!(4, vec.fragments().len());
+/// assert_eq!(vec.fragments().last().map(|f| f.capacity()), Some(4096 * 2));
+/// assert_eq!(vec.fragments().last().map(|f| f.len()), Some(1));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DoublingFrom<const FIRST_FRAGMENT_CAPACITY_POW: usize>;
+
+impl<const FIRST_FRAGMENT_CAPACITY_POW: usize> DoublingFrom<FIRST_FRAGMENT_CAPACITY_POW> {
+    const FIRST_FRAGMENT_CAPACITY: usize = usize::pow(2, FIRST_FRAGMENT_CAPACITY_POW as u32);
+    const OFFSET_FRAGMENT_IDX: usize = SIZE_USIZE - FIRST_FRAGMENT_CAPACITY_POW - 1;
+    const CUMULATIVE_CAPACITIES: [usize; MAX_NUM_FRAGMENTS + 1] =
+        cumulative_capacities(FIRST_FRAGMENT_CAPACITY_POW);
+}
+
+impl<const FIRST_FRAGMENT_CAPACITY_POW: usize> PseudoDefault
+    for DoublingFrom<FIRST_FRAGMENT_CAPACITY_POW>
+{
+    fn pseudo_default() -> Self {
+        Default::default()
+    }
+}
+
+impl<const FIRST_FRAGMENT_CAPACITY_POW: usize> Growth
+    for DoublingFrom<FIRST_FRAGMENT_CAPACITY_POW>
+{
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        fragment_capacities
+            .last()
+            .map(|x| x * 2)
+            .unwrap_or(Self::FIRST_FRAGMENT_CAPACITY)
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        _fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => Some(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        Self::CUMULATIVE_CAPACITIES[fragments_capacity]
+    }
+
+    /// Returns the number of fragments with this growth strategy in order to be able to reach a capacity of `maximum_capacity` of elements.
+    ///
+    /// This method is relevant and useful for concurrent programs, which helps in avoiding the fragments to allocate.
+    ///
+    /// Returns an error if `maximum_capacity` is greater than sum { 2^f | for f in FIRST_FRAGMENT_CAPACITY_POW..(FIRST_FRAGMENT_CAPACITY_POW + 32) }.
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        _: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        for (f, capacity) in Self::CUMULATIVE_CAPACITIES.iter().enumerate() {
+            if maximum_capacity <= *capacity {
+                return Ok(f);
+            }
+        }
+
+        Err(SplitVecError::CapacityOverflow {
+            max: Self::CUMULATIVE_CAPACITIES[MAX_NUM_FRAGMENTS],
+        })
+    }
+}
+
+impl<const FIRST_FRAGMENT_CAPACITY_POW: usize> GrowthWithConstantTimeAccess
+    for DoublingFrom<FIRST_FRAGMENT_CAPACITY_POW>
+{
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        let element_index_offset = element_index + Self::FIRST_FRAGMENT_CAPACITY;
+        let leading_zeros = usize::leading_zeros(element_index_offset) as usize;
+        let f = Self::OFFSET_FRAGMENT_IDX - leading_zeros;
+        (f, element_index - Self::CUMULATIVE_CAPACITIES[f])
+    }
+}
+
+impl<T, const FIRST_FRAGMENT_CAPACITY_POW: usize>
+    SplitVec<T, DoublingFrom<FIRST_FRAGMENT_CAPACITY_POW>>
+{
+    /// Creates a split vector with doubling growth where the first fragment has a capacity of `2 ^ FIRST_FRAGMENT_CAPACITY_POW`;
+    /// and every new fragment has double the capacity of the prior fragment.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<char, DoublingFrom<12>> = SplitVec::with_doubling_growth_from();
+    ///
+    /// assert_eq!(1, vec.fragments().len());
+    /// assert_eq!(4096, vec.capacity());
+    ///
+    /// for _ in 0..4097 {
+    ///     vec.push('x');
+    /// }
+    ///
+    /// assert_eq!(2, vec.fragments().len());
+    /// assert_eq!(4096 + 8192, vec.capacity());
+    /// ```
+    pub fn with_doubling_growth_from() -> Self {
+        let growth = DoublingFrom::<FIRST_FRAGMENT_CAPACITY_POW>;
+        let fragments = Fragment::new(growth.first_fragment_capacity()).into_fragments();
+        Self::from_raw_parts(0, fragments, growth)
+    }
+
+    /// Creates a new split vector with `DoublingFrom` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_doubling_growth_from`] only by the pre-allocation of fragments collection.
+    /// Pre-allocating the fragments collection is only relevant for concurrent programs, where it keeps the meta information of the fragments pinned in addition to the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_doubling_growth_from_and_fragments_capacity(fragments_capacity: usize) -> Self {
+        assert!(fragments_capacity > 0);
+        let growth = DoublingFrom::<FIRST_FRAGMENT_CAPACITY_POW>;
+        let fragments = Fragment::new(growth.first_fragment_capacity())
+            .into_fragments_with_capacity(fragments_capacity);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Doubling;
+    use crate::Global;
+
+    fn validate_indices<const P: usize>(num_indices: usize) {
+        let growth = DoublingFrom::<P>;
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+        let mut f = 0;
+        let mut prev_cumulative_capacity = 0;
+        let mut curr_capacity = usize::pow(2, P as u32);
+        let mut cumulative_capacity = curr_capacity;
+
+        for index in 0..num_indices {
+            if index == cumulative_capacity {
+                prev_cumulative_capacity = cumulative_capacity;
+                curr_capacity *= 2;
+                cumulative_capacity += curr_capacity;
+                f += 1;
+            }
+
+            let (f, i) = (f, index - prev_cumulative_capacity);
+            assert_eq!(
+                (f, i),
+                growth.get_fragment_and_inner_indices_unchecked(index)
+            );
+            assert_eq!(Some((f, i)), get(index));
+            assert_eq!(None, get_none(index));
+        }
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices() {
+        let growth = DoublingFrom::<3>;
+
+        assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
+        assert_eq!((0, 7), growth.get_fragment_and_inner_indices_unchecked(7));
+        assert_eq!((1, 0), growth.get_fragment_and_inner_indices_unchecked(8));
+        assert_eq!((1, 15), growth.get_fragment_and_inner_indices_unchecked(23));
+        assert_eq!((2, 0), growth.get_fragment_and_inner_indices_unchecked(24));
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        validate_indices::<0>(1111111);
+        validate_indices::<1>(1111111);
+        validate_indices::<5>(1111111);
+        validate_indices::<12>(1111111);
+    }
+
+    #[test]
+    fn equivalent_to_doubling() {
+        let from = DoublingFrom::<2>;
+        for index in 0..1111111 {
+            assert_eq!(
+                Doubling.get_fragment_and_inner_indices_unchecked(index),
+                from.get_fragment_and_inner_indices_unchecked(index)
+            );
+        }
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, DoublingFrom<6>>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<char, DoublingFrom<6>> = SplitVec::with_doubling_growth_from();
+        assert_eq!(max_cap(&vec), 64 + 128 + 256 + 512);
+
+        let until = max_cap(&vec);
+        for _ in 0..until {
+            vec.push('x');
+            assert_eq!(max_cap(&vec), 64 + 128 + 256 + 512);
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push('x');
+        assert_eq!(
+            max_cap(&vec),
+            64 + 128 + 256 + 512 + 1024 + 2048 + 4096 + 8192
+        );
+    }
+
+    #[test]
+    fn with_doubling_growth_from_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, DoublingFrom<4>> =
+            SplitVec::with_doubling_growth_from_and_fragments_capacity(1);
+
+        assert_eq!(1, vec.fragments.capacity());
+        assert_eq!(16, vec.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_doubling_growth_from_and_fragments_capacity_zero() {
+        let _: SplitVec<char, DoublingFrom<4>> =
+            SplitVec::with_doubling_growth_from_and_fragments_capacity(0);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<char, DoublingFrom<4>> = SplitVec::with_doubling_growth_from();
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        // 16 - 48 - 112 - 240
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(16), Ok(1));
+        assert_eq!(num_fragments(17), Ok(2));
+        assert_eq!(num_fragments(48), Ok(2));
+        assert_eq!(num_fragments(49), Ok(3));
+        assert_eq!(num_fragments(112), Ok(3));
+        assert_eq!(num_fragments(240), Ok(4));
+        assert_eq!(num_fragments(241), Ok(5));
+    }
+
+    #[test]
+    fn required_fragments_len_more_than_max() {
+        let vec: SplitVec<char, DoublingFrom<4>> = SplitVec::with_doubling_growth_from();
+        let max = DoublingFrom::<4>::CUMULATIVE_CAPACITIES[MAX_NUM_FRAGMENTS];
+
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        assert_eq!(num_fragments(max), Ok(32));
+        assert_eq!(
+            num_fragments(max + 1),
+            Err(SplitVecError::CapacityOverflow { max })
+        );
+    }
+}
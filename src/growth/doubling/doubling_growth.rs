@@ -1,6 +1,7 @@
 use super::constants::*;
 use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
-use crate::{Fragment, SplitVec};
+use crate::{Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
 use orx_pseudo_default::PseudoDefault;
 
 /// Strategy which allows creates a fragment with double the capacity
@@ -69,10 +70,10 @@ impl Growth for Doubling {
     }
 
     #[inline(always)]
-    fn get_fragment_and_inner_indices<T>(
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
         &self,
         vec_len: usize,
-        _fragments: &[Fragment<T>],
+        _fragments: &[Fragment<T, A>],
         element_index: usize,
     ) -> Option<(usize, usize)> {
         match element_index < vec_len {
@@ -81,6 +82,14 @@ impl Growth for Doubling {
         }
     }
 
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
     /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
     ///
     /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
@@ -90,7 +99,11 @@ impl Growth for Doubling {
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
     #[inline(always)]
-    unsafe fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
         <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
     }
 
@@ -105,17 +118,17 @@ impl Growth for Doubling {
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
     #[inline(always)]
-    unsafe fn get_ptr_mut_and_indices<T>(
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
         &self,
-        fragments: &mut [Fragment<T>],
+        fragments: &mut [Fragment<T, A>],
         index: usize,
     ) -> Option<(*mut T, usize, usize)> {
         <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
     }
 
-    fn maximum_concurrent_capacity<T>(
+    fn maximum_concurrent_capacity<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         fragments_capacity: usize,
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
@@ -130,21 +143,20 @@ impl Growth for Doubling {
     /// # Panics
     ///
     /// Panics if `maximum_capacity` is greater than sum { 2^f | for f in 2..34 }.
-    fn required_fragments_len<T>(
+    fn required_fragments_len<T, A: Allocator>(
         &self,
-        _: &[Fragment<T>],
+        _: &[Fragment<T, A>],
         maximum_capacity: usize,
-    ) -> Result<usize, String> {
+    ) -> Result<usize, SplitVecError> {
         for (f, capacity) in CUMULATIVE_CAPACITIES.iter().enumerate() {
             if maximum_capacity <= *capacity {
                 return Ok(f);
             }
         }
 
-        Err(format!(
-            "Maximum cumulative capacity that can be reached by the Doubling strategy is {}.",
-            CUMULATIVE_CAPACITIES[CUMULATIVE_CAPACITIES.len() - 1]
-        ))
+        Err(SplitVecError::CapacityOverflow {
+            max: CUMULATIVE_CAPACITIES[CUMULATIVE_CAPACITIES.len() - 1],
+        })
     }
 }
 
@@ -236,14 +248,16 @@ impl<T> SplitVec<T, Doubling> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use orx_pinned_vec::PinnedVec;
+    use crate::Global;
 
     #[test]
     fn get_fragment_and_inner_indices() {
         let growth = Doubling;
 
-        let get = |index| growth.get_fragment_and_inner_indices::<char>(usize::MAX, &[], index);
-        let get_none = |index| growth.get_fragment_and_inner_indices::<char>(index, &[], index);
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
 
         assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
         assert_eq!((0, 1), growth.get_fragment_and_inner_indices_unchecked(1));
@@ -268,8 +282,10 @@ mod tests {
     fn get_fragment_and_inner_indices_exhaustive() {
         let growth = Doubling;
 
-        let get = |index| growth.get_fragment_and_inner_indices::<char>(usize::MAX, &[], index);
-        let get_none = |index| growth.get_fragment_and_inner_indices::<char>(index, &[], index);
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
 
         let mut f = 0;
         let mut prev_cumulative_capacity = 0;
@@ -374,8 +390,10 @@ mod tests {
                 .required_fragments_len(vec.fragments(), max_cap)
         };
 
-        let more_than_max_possible_capacity =
-            *CUMULATIVE_CAPACITIES.last().expect("is not empty") + 1;
-        assert!(num_fragments(more_than_max_possible_capacity).is_err());
+        let max = *CUMULATIVE_CAPACITIES.last().expect("is not empty");
+        assert_eq!(
+            num_fragments(max + 1),
+            Err(SplitVecError::CapacityOverflow { max })
+        );
     }
 }
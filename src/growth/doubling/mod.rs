@@ -1,8 +1,10 @@
 mod constants;
+mod doubling_from;
 mod doubling_growth;
 mod from;
 
 #[cfg(test)]
 mod tests;
 
+pub use doubling_from::DoublingFrom;
 pub use doubling_growth::Doubling;
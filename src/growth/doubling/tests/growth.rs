@@ -1,4 +1,4 @@
-use crate::{Doubling, Fragment, Growth};
+use crate::{Doubling, Fragment, Global, Growth};
 
 #[test]
 fn new_cap() {
@@ -19,7 +19,7 @@ fn new_cap() {
 fn indices_panics_when_fragments_is_empty() {
     assert_eq!(
         None,
-        <Doubling as Growth>::get_fragment_and_inner_indices::<usize>(&Doubling, 0, &[], 0)
+        <Doubling as Growth>::get_fragment_and_inner_indices::<usize, Global>(&Doubling, 0, &[], 0)
     );
 }
 
@@ -0,0 +1,608 @@
+use crate::growth::growth_trait::Growth;
+use crate::{Fragment, SplitVec, SplitVecError};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
+use orx_pseudo_default::PseudoDefault;
+
+/// Minimum growth factor of the strategy, which bounds the number of fragments that can be created before
+/// the cumulative capacity overflows, and hence, the number of cumulative capacities stored by the strategy.
+const MIN_GROWTH_FACTOR: f64 = 1.01;
+
+/// Strategy which creates a fragment with `growth_factor` times the capacity
+/// of the prior fragment every time the split vector needs to expand.
+///
+/// * The first fragment has the given `first_fragment_capacity`.
+/// * Capacity of every following fragment is the capacity of the prior fragment multiplied by the `growth_factor`, rounded up.
+///   The capacity is guaranteed to increase by at least one element at each new fragment.
+///
+/// Capacities of the fragments are determined only by the position of the fragment.
+/// Therefore, cumulative capacities of all fragments that can be created before the cumulative capacity overflows `usize`
+/// are computed once while creating the strategy and stored.
+/// Their number is roughly the logarithm of `usize::MAX` in base `growth_factor`; for instance, around 100 with a growth factor of 1.5
+/// and around 420 with a growth factor of 1.1.
+/// This allows for:
+/// * ***O(log(f))*** random access by a binary search over the cumulative capacities, where `f` is the number of fragments,
+/// * ***O(1)*** `maximum_concurrent_capacity` and ***O(log(f))*** `required_fragments_len` computations without allocations.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // SplitVec<usize, Geometric>
+/// let mut vec = SplitVec::with_geometric_growth(4, 1.5);
+///
+/// assert_eq!(1, vec.fragments().len());
+/// assert_eq!(Some(4), vec.fragments().first().map(|f| f.capacity()));
+/// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+///
+/// // fill the first 4 fragments
+/// let expected_fragment_capacities = vec![4, 6, 9, 14];
+/// let num_items: usize = expected_fragment_capacities.iter().sum();
+/// for i in 0..num_items {
+///     vec.push(i);
+/// }
+///
+/// assert_eq!(
+///     expected_fragment_capacities,
+///     vec.fragments()
+///     .iter()
+///     .map(|f| f.capacity())
+///     .collect::<Vec<_>>()
+/// );
+///
+/// // create the 5-th fragment growing the capacity by 1.5
+/// vec.push(42);
+/// assert_eq!(5, vec.fragments().len());
+/// assert_eq!(vec.fragments().last().map(|f| f.capacity()), Some(21));
+/// assert_eq!(vec.fragments().last().map(|f| f.len()), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geometric {
+    growth_factor: f64,
+    cumulative_capacities: Vec<usize>,
+}
+
+impl Geometric {
+    /// Creates a geometric growth strategy where the first fragment has a capacity of `first_fragment_capacity`,
+    /// and each following fragment has `growth_factor` times the capacity of the prior fragment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `first_fragment_capacity` is zero, or
+    /// * `growth_factor` is not a finite number greater than or equal to 1.01.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let growth = Geometric::new(10, 4.0);
+    /// assert_eq!(growth.first_fragment_capacity(), 10);
+    /// assert_eq!(growth.growth_factor(), 4.0);
+    ///
+    /// let mut vec: SplitVec<char, _> = SplitVec::with_growth(growth);
+    /// for _ in 0..(10 + 40 + 1) {
+    ///     vec.push('x');
+    /// }
+    /// assert_eq!(vec.fragments().len(), 3);
+    /// assert_eq!(vec.capacity(), 10 + 40 + 160);
+    /// ```
+    pub fn new(first_fragment_capacity: usize, growth_factor: f64) -> Self {
+        assert!(first_fragment_capacity > 0);
+        assert!(growth_factor.is_finite() && growth_factor >= MIN_GROWTH_FACTOR);
+
+        let mut cumulative_capacities = alloc::vec![0, first_fragment_capacity];
+        let mut capacity = first_fragment_capacity;
+        let mut cumulative_capacity = first_fragment_capacity;
+        while let Some(x) = grown_capacity(capacity, growth_factor) {
+            capacity = x;
+            cumulative_capacity = match cumulative_capacity.checked_add(capacity) {
+                Some(x) => x,
+                None => break,
+            };
+            cumulative_capacities.push(cumulative_capacity);
+        }
+
+        Self {
+            growth_factor,
+            cumulative_capacities,
+        }
+    }
+
+    /// Returns the factor by which the capacity of each new fragment grows.
+    pub fn growth_factor(&self) -> f64 {
+        self.growth_factor
+    }
+
+    /// Returns the capacity of the first fragment.
+    pub fn first_fragment_capacity(&self) -> usize {
+        self.cumulative_capacities[1]
+    }
+
+    /// Returns the number of fragments whose cumulative capacities are stored;
+    /// i.e., the number of fragments that can be created before the cumulative capacity overflows.
+    fn num_indexed_fragments(&self) -> usize {
+        self.cumulative_capacities.len() - 1
+    }
+
+    /// ***O(log(f))*** Returns the fragment index and index within fragment of the element with the given `index`,
+    /// assuming all fragments are allocated with the capacities defined by this strategy.
+    ///
+    /// Returns None if the index is not less than the cumulative capacity of all fragments that can be created.
+    #[inline(always)]
+    fn fragment_and_inner_indices(&self, index: usize) -> Option<(usize, usize)> {
+        let f = self.cumulative_capacities.partition_point(|x| *x <= index) - 1;
+        match f < self.num_indexed_fragments() {
+            true => Some((f, index - self.cumulative_capacities[f])),
+            false => None,
+        }
+    }
+}
+
+/// Returns the capacity of the fragment following a fragment with the given `capacity`,
+/// which is at least one greater than `capacity`; None if the capacity overflows.
+fn grown_capacity(capacity: usize, growth_factor: f64) -> Option<usize> {
+    let grown = (capacity as f64 * growth_factor).ceil();
+    match grown < usize::MAX as f64 {
+        true => Some(usize::max(capacity + 1, grown as usize)),
+        false => None,
+    }
+}
+
+impl PseudoDefault for Geometric {
+    fn pseudo_default() -> Self {
+        Self::new(4, 1.5)
+    }
+}
+
+impl Growth for Geometric {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        let f = fragment_capacities.len();
+        match f < self.num_indexed_fragments() {
+            true => self.cumulative_capacities[f + 1] - self.cumulative_capacities[f],
+            false => match fragment_capacities.last() {
+                Some(x) => grown_capacity(x, self.growth_factor).unwrap_or(usize::MAX),
+                None => self.first_fragment_capacity(),
+            },
+        }
+    }
+
+    /// ***O(log(f))*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment).
+    ///
+    /// Returns None if the element index is out of bounds.
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => self.fragment_and_inner_indices(element_index),
+            false => None,
+        }
+    }
+
+    /// ***O(log(f))*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        self.get_ptr_mut_and_indices(fragments, index).map(|x| x.0)
+    }
+
+    /// ***O(log(f))*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        self.fragment_and_inner_indices(index).and_then(|(f, i)| {
+            fragments
+                .get_mut(f)
+                .map(|fragment| (fragment.as_mut_ptr().add(i), f, i))
+        })
+    }
+
+    /// ***O(1)*** Returns the maximum number of elements that can safely be stored in a concurrent program.
+    ///
+    /// Note that pinned vectors already keep the elements pinned to their memory locations.
+    /// Therefore, concurrently safe growth here corresponds to growth without requiring `fragments` collection to allocate.
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        let f = fragments_capacity.min(self.num_indexed_fragments());
+        self.cumulative_capacities[f]
+    }
+
+    /// ***O(log(f))*** Returns the number of fragments with this growth strategy in order to be able to reach a capacity of `maximum_capacity` of elements.
+    /// Returns the error if it the growth strategy does not allow the required number of fragments.
+    ///
+    /// This method is relevant and useful for concurrent programs, which helps in avoiding the fragments to allocate.
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        _: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        let num_indexed = self.num_indexed_fragments();
+        let f = self
+            .cumulative_capacities
+            .partition_point(|x| *x < maximum_capacity);
+
+        match f <= num_indexed {
+            true => Ok(f),
+            false => Err(SplitVecError::CapacityOverflow {
+                max: self.cumulative_capacities[num_indexed],
+            }),
+        }
+    }
+}
+
+impl<T> SplitVec<T, Geometric> {
+    /// Creates a split vector with geometric growth where the first fragment has a capacity of `first_fragment_capacity`,
+    /// and each following fragment has `growth_factor` times the capacity of the prior fragment.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `first_fragment_capacity` is zero, or
+    /// * `growth_factor` is not a finite number greater than or equal to 1.01.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // SplitVec<usize, Geometric>
+    /// let mut vec = SplitVec::with_geometric_growth(4, 1.5);
+    ///
+    /// assert_eq!(1, vec.fragments().len());
+    /// assert_eq!(Some(4), vec.fragments().first().map(|f| f.capacity()));
+    /// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+    ///
+    /// // fill the first 4 fragments
+    /// let expected_fragment_capacities = vec![4, 6, 9, 14];
+    /// let num_items: usize = expected_fragment_capacities.iter().sum();
+    /// for i in 0..num_items {
+    ///     vec.push(i);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     expected_fragment_capacities,
+    ///     vec.fragments()
+    ///     .iter()
+    ///     .map(|f| f.capacity())
+    ///     .collect::<Vec<_>>()
+    /// );
+    ///
+    /// // create the 5-th fragment growing the capacity by 1.5
+    /// vec.push(42);
+    /// assert_eq!(5, vec.fragments().len());
+    /// assert_eq!(vec.fragments().last().map(|f| f.capacity()), Some(21));
+    /// assert_eq!(vec.fragments().last().map(|f| f.len()), Some(1));
+    /// ```
+    pub fn with_geometric_growth(first_fragment_capacity: usize, growth_factor: f64) -> Self {
+        let growth = Geometric::new(first_fragment_capacity, growth_factor);
+        let fragments = Fragment::new(growth.first_fragment_capacity()).into_fragments();
+        Self::from_raw_parts(0, fragments, growth)
+    }
+
+    /// Creates a new split vector with `Geometric` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_geometric_growth`] only by the pre-allocation of fragments collection.
+    /// This is only relevant for concurrent programs, as it allows the vector to grow up to its maximum concurrent capacity without moving the fragments collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// * `fragments_capacity == 0`, or
+    /// * `first_fragment_capacity` is zero, or
+    /// * `growth_factor` is not a finite number greater than or equal to 1.01.
+    pub fn with_geometric_growth_and_fragments_capacity(
+        first_fragment_capacity: usize,
+        growth_factor: f64,
+        fragments_capacity: usize,
+    ) -> Self {
+        assert!(fragments_capacity > 0);
+
+        let growth = Geometric::new(first_fragment_capacity, growth_factor);
+        let fragments = Fragment::new(growth.first_fragment_capacity())
+            .into_fragments_with_capacity(fragments_capacity);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Global;
+
+    #[test]
+    fn fragment_capacities() {
+        let growth = Geometric::new(4, 1.5);
+        let capacities: Vec<_> = growth
+            .cumulative_capacities
+            .windows(2)
+            .take(6)
+            .map(|w| w[1] - w[0])
+            .collect();
+        assert_eq!(capacities, [4, 6, 9, 14, 21, 32]);
+
+        let growth = Geometric::new(3, 4.0);
+        let capacities: Vec<_> = growth
+            .cumulative_capacities
+            .windows(2)
+            .take(4)
+            .map(|w| w[1] - w[0])
+            .collect();
+        assert_eq!(capacities, [3, 12, 48, 192]);
+
+        let growth = Geometric::new(1, 1.01);
+        let capacities: Vec<_> = growth
+            .cumulative_capacities
+            .windows(2)
+            .take(4)
+            .map(|w| w[1] - w[0])
+            .collect();
+        assert_eq!(capacities, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cumulative_capacities_do_not_overflow() {
+        for growth_factor in [1.01, 1.5, 2.0, 4.0, 1000.0] {
+            let growth = Geometric::new(7, growth_factor);
+            let capacities = &growth.cumulative_capacities;
+            assert!(capacities.windows(2).all(|w| w[0] < w[1]));
+
+            let last = capacities[capacities.len() - 1];
+            let next = growth.new_fragment_capacity_from(
+                capacities
+                    .windows(2)
+                    .map(|w| w[1] - w[0])
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            );
+            assert!(last.checked_add(next).is_none());
+        }
+    }
+
+    #[test]
+    fn cumulative_capacities_of_small_growth_factors() {
+        let growth = Geometric::new(1, MIN_GROWTH_FACTOR);
+        let num_fragments = growth.num_indexed_fragments();
+        assert!(num_fragments > 3000);
+        assert!(num_fragments < 4000);
+
+        let max = growth.cumulative_capacities[num_fragments];
+        assert_eq!(
+            growth.fragment_and_inner_indices(max - 1).map(|x| x.0),
+            Some(num_fragments - 1)
+        );
+        assert_eq!(growth.fragment_and_inner_indices(max), None);
+    }
+
+    #[test]
+    fn many_fragments_of_small_growth_factor() {
+        let mut vec = SplitVec::with_geometric_growth(1, 1.01);
+        let len = 10_000;
+        vec.extend(0..len);
+        assert!(vec.fragments().len() > 64);
+
+        let capacities: Vec<_> = vec.fragments().iter().map(|x| x.capacity()).collect();
+        assert!(capacities.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(capacities[64], 65);
+
+        for i in 0..len {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        assert_eq!(vec.get(len), None);
+
+        let fragments_capacity = vec.fragments().len();
+        let expected: usize = capacities.iter().sum();
+        assert_eq!(
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), fragments_capacity),
+            expected
+        );
+        assert_eq!(
+            vec.growth()
+                .required_fragments_len(vec.fragments(), expected),
+            Ok(fragments_capacity)
+        );
+        assert_eq!(
+            vec.growth()
+                .required_fragments_len(vec.fragments(), expected + 1),
+            Ok(fragments_capacity + 1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn growth_factor_one() {
+        let _ = Geometric::new(4, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn growth_factor_below_minimum() {
+        let _ = Geometric::new(1, 1.0 + f64::EPSILON);
+    }
+
+    #[test]
+    #[should_panic]
+    fn growth_factor_nan() {
+        let _ = Geometric::new(4, f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn first_fragment_capacity_zero() {
+        let _ = Geometric::new(0, 2.0);
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        let growth = Geometric::new(5, 1.7);
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+        let mut f = 0;
+        let mut prev_cumulative_capacity = 0;
+        let mut curr_capacity = 5;
+        let mut cumulative_capacity = 5;
+
+        for index in 0..1111111 {
+            if index == cumulative_capacity {
+                prev_cumulative_capacity = cumulative_capacity;
+                curr_capacity = (curr_capacity as f64 * 1.7).ceil() as usize;
+                cumulative_capacity += curr_capacity;
+                f += 1;
+            }
+
+            let (f, i) = (f, index - prev_cumulative_capacity);
+            assert_eq!(Some((f, i)), get(index));
+            assert_eq!(None, get_none(index));
+        }
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, Geometric>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<char, Geometric> = SplitVec::with_geometric_growth(4, 1.5);
+        assert_eq!(max_cap(&vec), 4 + 6 + 9 + 14);
+
+        let until = max_cap(&vec);
+        for _ in 0..until {
+            vec.push('x');
+            assert_eq!(max_cap(&vec), 4 + 6 + 9 + 14);
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push('x');
+        assert_eq!(max_cap(&vec), 4 + 6 + 9 + 14 + 21 + 32 + 48 + 72);
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity_matches_default() {
+        let growth = Geometric::new(3, 2.5);
+        let fragments: Vec<Fragment<char>> = vec![Fragment::new(3)];
+        for fragments_capacity in 1..20 {
+            let cloned: Vec<Fragment<char>> = {
+                let mut x = Vec::with_capacity(fragments_capacity);
+                x.push(Fragment::new(3));
+                for _ in 1..fragments_capacity {
+                    x.push(Fragment::new(growth.new_fragment_capacity(&x)));
+                }
+                x
+            };
+            let expected: usize = cloned.iter().map(|x| x.capacity()).sum();
+            assert_eq!(
+                expected,
+                growth.maximum_concurrent_capacity(&fragments, fragments_capacity)
+            );
+        }
+    }
+
+    #[test]
+    fn with_geometric_growth_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, _> =
+            SplitVec::with_geometric_growth_and_fragments_capacity(4, 1.5, 1);
+
+        assert_eq!(1, vec.fragments.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_geometric_growth_and_fragments_capacity_zero() {
+        let _: SplitVec<char, _> =
+            SplitVec::with_geometric_growth_and_fragments_capacity(4, 1.5, 0);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<char, Geometric> = SplitVec::with_geometric_growth(4, 1.5);
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        // 4 - 10 - 19 - 33 - 54
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(4), Ok(1));
+        assert_eq!(num_fragments(5), Ok(2));
+        assert_eq!(num_fragments(10), Ok(2));
+        assert_eq!(num_fragments(11), Ok(3));
+        assert_eq!(num_fragments(33), Ok(4));
+        assert_eq!(num_fragments(34), Ok(5));
+        assert_eq!(num_fragments(54), Ok(5));
+    }
+
+    #[test]
+    fn required_fragments_len_more_than_max() {
+        let vec: SplitVec<char, Geometric> = SplitVec::with_geometric_growth(4, 1000.0);
+        let max = *vec
+            .growth()
+            .cumulative_capacities
+            .last()
+            .expect("is not empty");
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        assert_eq!(
+            num_fragments(max),
+            Ok(vec.growth().cumulative_capacities.len() - 1)
+        );
+        assert_eq!(
+            num_fragments(max + 1),
+            Err(SplitVecError::CapacityOverflow { max })
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod geometric_growth;
+
+#[cfg(test)]
+mod tests;
+
+pub use geometric_growth::Geometric;
@@ -0,0 +1,88 @@
+use crate::{Fragment, Geometric, Global, Growth};
+
+#[test]
+fn new_cap() {
+    fn new_fra(cap: usize) -> Fragment<usize> {
+        Vec::<usize>::with_capacity(cap).into()
+    }
+
+    let growth = Geometric::new(4, 1.5);
+    assert_eq!(4, growth.new_fragment_capacity::<usize, Global>(&[]));
+    assert_eq!(6, growth.new_fragment_capacity(&[new_fra(4)]));
+    assert_eq!(9, growth.new_fragment_capacity(&[new_fra(4), new_fra(6)]));
+    assert_eq!(
+        14,
+        growth.new_fragment_capacity(&[new_fra(4), new_fra(6), new_fra(9)])
+    );
+}
+
+#[test]
+fn indices_panics_when_fragments_is_empty() {
+    let growth = Geometric::new(4, 1.5);
+    assert_eq!(
+        None,
+        <Geometric as Growth>::get_fragment_and_inner_indices::<usize, Global>(&growth, 0, &[], 0)
+    );
+}
+
+#[test]
+fn indices() {
+    fn new_full() -> Fragment<usize> {
+        (0..4).collect::<Vec<_>>().into()
+    }
+    fn new_half() -> Fragment<usize> {
+        let mut vec = Vec::with_capacity(6);
+        for i in 0..3 {
+            vec.push(10 + i);
+        }
+        vec.into()
+    }
+
+    let growth = Geometric::new(4, 1.5);
+
+    for i in 0..4 {
+        assert_eq!(
+            Some((0, i)),
+            growth.get_fragment_and_inner_indices(4, &[new_full()], i)
+        );
+    }
+    assert_eq!(
+        None,
+        growth.get_fragment_and_inner_indices(4, &[new_full()], 4)
+    );
+
+    for i in 0..4 {
+        assert_eq!(
+            Some((0, i)),
+            growth.get_fragment_and_inner_indices(7, &[new_full(), new_half()], i)
+        );
+    }
+    for i in 4..7 {
+        assert_eq!(
+            Some((1, i - 4)),
+            growth.get_fragment_and_inner_indices(7, &[new_full(), new_half()], i)
+        );
+    }
+    assert_eq!(
+        None,
+        growth.get_fragment_and_inner_indices(7, &[new_full(), new_half()], 7)
+    );
+}
+
+#[test]
+fn get_ptr_mut() {
+    let growth = Geometric::new(4, 1.5);
+    let mut fragments: Vec<Fragment<usize>> = vec![(0..4).collect::<Vec<_>>().into()];
+    fragments.push(Fragment::new(6));
+
+    for i in 0..4 {
+        let ptr = unsafe { growth.get_ptr_mut(&mut fragments, i) }.expect("is-some");
+        assert_eq!(unsafe { *ptr }, i);
+    }
+    for i in 4..10 {
+        let (_, f, j) =
+            unsafe { growth.get_ptr_mut_and_indices(&mut fragments, i) }.expect("is-some");
+        assert_eq!((f, j), (1, i - 4));
+    }
+    assert!(unsafe { growth.get_ptr_mut(&mut fragments, 10) }.is_none());
+}
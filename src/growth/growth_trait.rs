@@ -1,4 +1,6 @@
-use crate::Fragment;
+use crate::{Fragment, SplitVecError};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
 use orx_pseudo_default::PseudoDefault;
 
 /// Growth strategy of a split vector.
@@ -12,7 +14,7 @@ pub trait Growth: Clone + PseudoDefault {
     /// Given that the split vector contains the given `fragments`,
     /// returns the capacity of the next fragment.
     #[inline(always)]
-    fn new_fragment_capacity<T>(&self, fragments: &[Fragment<T>]) -> usize {
+    fn new_fragment_capacity<T, A: Allocator>(&self, fragments: &[Fragment<T, A>]) -> usize {
         self.new_fragment_capacity_from(fragments.iter().map(|x| x.capacity()))
     }
 
@@ -26,21 +28,24 @@ pub trait Growth: Clone + PseudoDefault {
     /// ***O(fragments.len())*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment).
     ///
     /// Returns None if the element index is out of bounds.
-    fn get_fragment_and_inner_indices<T>(
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
         &self,
         _vec_len: usize,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         element_index: usize,
     ) -> Option<(usize, usize)> {
-        let mut prev_end = 0;
-        let mut end = 0;
-        for (f, fragment) in fragments.iter().enumerate() {
-            end += fragment.len();
-            if element_index < end {
-                return Some((f, element_index - prev_end));
-            }
-            prev_end = end;
-        }
+        get_fragment_and_inner_indices_by_scan(fragments, element_index)
+    }
+
+    /// ***O(1)*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment)
+    /// provided that the growth strategy allows for constant time access; returns None otherwise.
+    ///
+    /// Similar to [`GrowthWithConstantTimeAccess::get_fragment_and_inner_indices_unchecked`], this method does not perform bounds check.
+    /// It allows iterators, which are not aware of the growth strategy, to jump to an element without visiting the fragments in between.
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        _element_index: usize,
+    ) -> Option<(usize, usize)> {
         None
     }
 
@@ -52,7 +57,11 @@ pub trait Growth: Clone + PseudoDefault {
     ///
     /// This method allows to write to a memory which is greater than the  vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
-    unsafe fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
         self.get_ptr_mut_and_indices(fragments, index).map(|x| x.0)
     }
 
@@ -66,9 +75,9 @@ pub trait Growth: Clone + PseudoDefault {
     ///
     /// This method allows to write to a memory which is greater than the  vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
-    unsafe fn get_ptr_mut_and_indices<T>(
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
         &self,
-        fragments: &mut [Fragment<T>],
+        fragments: &mut [Fragment<T, A>],
         index: usize,
     ) -> Option<(*mut T, usize, usize)> {
         let mut prev_cumulative_capacity = 0;
@@ -88,6 +97,49 @@ pub trait Growth: Clone + PseudoDefault {
         None
     }
 
+    /// Informs the growth strategy that the `fragments` of the split vector have been mutated,
+    /// such that the fragments before `begin_fragment` are not affected, while lengths of the remaining fragments
+    /// as well as the number of fragments might have changed.
+    ///
+    /// Growth strategies which maintain a state derived from the fragments, such as the cumulative lengths index of [`Recursive`],
+    /// update their state; the default implementation does nothing.
+    ///
+    /// [`Recursive`]: crate::Recursive
+    #[inline(always)]
+    fn fragments_mutated_from<T, A: Allocator>(
+        &mut self,
+        _fragments: &[Fragment<T, A>],
+        _begin_fragment: usize,
+    ) {
+    }
+
+    /// Returns whether or not a split vector with this growth strategy and containing the given `fragments`
+    /// can take ownership of the `fragment` as its next fragment as it is, without violating the assumptions of the growth strategy.
+    ///
+    /// The default implementation requires that:
+    /// * the last of the `fragments`, if any, is at its capacity, and
+    /// * capacity of the `fragment` is equal to the capacity this growth would have chosen for the next fragment.
+    ///
+    /// Growth strategies which do not make any assumptions on the capacities of fragments, such as [`Recursive`], override this method.
+    ///
+    /// [`Recursive`]: crate::Recursive
+    fn can_append_fragment<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragment: &Fragment<T, A>,
+    ) -> bool {
+        let last_is_full = fragments.last().map(|x| x.room() == 0).unwrap_or(true);
+        last_is_full && fragment.capacity() == self.new_fragment_capacity(fragments)
+    }
+
+    /// Returns whether or not a split vector with this growth strategy can take ownership of the given `fragments` as they are,
+    /// without violating the assumptions of the growth strategy.
+    ///
+    /// This is the case when each fragment can be appended to the fragments preceding it; see [`Growth::can_append_fragment`].
+    fn can_adopt_fragments<T, A: Allocator>(&self, fragments: &[Fragment<T, A>]) -> bool {
+        (0..fragments.len()).all(|f| self.can_append_fragment(&fragments[..f], &fragments[f]))
+    }
+
     /// Returns the maximum number of elements that can safely be stored in a concurrent program.
     ///
     /// Note that pinned vectors already keep the elements pinned to their memory locations.
@@ -101,9 +153,9 @@ pub trait Growth: Clone + PseudoDefault {
     /// # Panics
     ///
     /// Panics if `fragments.len() < fragments_capacity`, which must not hold.
-    fn maximum_concurrent_capacity<T>(
+    fn maximum_concurrent_capacity<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         fragments_capacity: usize,
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
@@ -125,21 +177,14 @@ pub trait Growth: Clone + PseudoDefault {
     }
 
     /// Returns the number of fragments with this growth strategy in order to be able to reach a capacity of `maximum_capacity` of elements.
-    /// Returns the error if it the growth strategy does not allow the required number of fragments.
+    /// Returns [`SplitVecError::CapacityOverflow`] if the growth strategy does not allow the required number of fragments.
     ///
     /// This method is relevant and useful for concurrent programs, which helps in avoiding the fragments to allocate.
-    fn required_fragments_len<T>(
+    fn required_fragments_len<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         maximum_capacity: usize,
-    ) -> Result<usize, String> {
-        fn overflown_err() -> String {
-            format!(
-                "Maximum cumulative capacity that can be reached is {}.",
-                usize::MAX
-            )
-        }
-
+    ) -> Result<usize, SplitVecError> {
         let mut cloned: Vec<Fragment<T>> = Vec::new();
         for fragment in fragments {
             cloned.push(Vec::with_capacity(fragment.capacity()).into());
@@ -152,7 +197,7 @@ pub trait Growth: Clone + PseudoDefault {
             let new_capacity = self.new_fragment_capacity(&cloned);
             let (new_current_capacity, overflown) = current_capacity.overflowing_add(new_capacity);
             if overflown {
-                return Err(overflown_err());
+                return Err(SplitVecError::CapacityOverflow { max: usize::MAX });
             }
 
             let fragment = Vec::with_capacity(new_capacity).into();
@@ -166,6 +211,26 @@ pub trait Growth: Clone + PseudoDefault {
     }
 }
 
+/// ***O(fragments.len())*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment),
+/// by scanning the lengths of the `fragments`.
+///
+/// Returns None if the element index is out of bounds.
+pub(crate) fn get_fragment_and_inner_indices_by_scan<T, A: Allocator>(
+    fragments: &[Fragment<T, A>],
+    element_index: usize,
+) -> Option<(usize, usize)> {
+    let mut prev_end = 0;
+    let mut end = 0;
+    for (f, fragment) in fragments.iter().enumerate() {
+        end += fragment.len();
+        if element_index < end {
+            return Some((f, element_index - prev_end));
+        }
+        prev_end = end;
+    }
+    None
+}
+
 /// Growth strategy of a split vector which allows for constant time access to the elements.
 pub trait GrowthWithConstantTimeAccess: Growth {
     /// ***O(1)*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment).
@@ -184,7 +249,11 @@ pub trait GrowthWithConstantTimeAccess: Growth {
     ///
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
-    unsafe fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
         let (f, i) = self.get_fragment_and_inner_indices_unchecked(index);
         fragments
             .get_mut(f)
@@ -201,9 +270,9 @@ pub trait GrowthWithConstantTimeAccess: Growth {
     ///
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
-    unsafe fn get_ptr_mut_and_indices<T>(
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
         &self,
-        fragments: &mut [Fragment<T>],
+        fragments: &mut [Fragment<T, A>],
         index: usize,
     ) -> Option<(*mut T, usize, usize)> {
         let (f, i) = self.get_fragment_and_inner_indices_unchecked(index);
@@ -0,0 +1,341 @@
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
+use orx_pseudo_default::PseudoDefault;
+
+/// Strategy which allows the split vector to grow linearly where each fragment has a capacity of `2 ^ EXP`
+/// which is known at compile time.
+///
+/// This is the compile-time counterpart of the [`Linear`] strategy:
+/// * the shift and mask used to compute fragment and inner indices are constants,
+/// * the strategy is a zero-sized type, and
+/// * `Default` and `PseudoDefault` implementations create exactly the same strategy without requiring to guess an exponent.
+///
+/// `EXP` must be less than 32.
+///
+/// [`Linear`]: crate::Linear
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // SplitVec<usize, ConstLinear<4>>
+/// let mut vec: SplitVec<usize, ConstLinear<4>> = SplitVec::default();
+///
+/// assert_eq!(1, vec.fragments().len());
+/// assert_eq!(Some(16), vec.fragments().first().map(|f| f.capacity()));
+/// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+///
+/// // push 160 elements
+/// for i in 0..10 * 16 {
+///     vec.push(i);
+/// }
+///
+/// assert_eq!(10, vec.fragments().len());
+/// for fragment in vec.fragments() {
+///     assert_eq!(16, fragment.len());
+///     assert_eq!(16, fragment.capacity());
+/// }
+///
+/// // push the 161-st element
+/// vec.push(42);
+/// assert_eq!(11, vec.fragments().len());
+/// assert_eq!(Some(16), vec.fragments().last().map(|f| f.capacity()));
+/// assert_eq!(Some(1), vec.fragments().last().map(|f| f.len()));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConstLinear<const EXP: usize>;
+
+impl<const EXP: usize> ConstLinear<EXP> {
+    const FRAGMENT_CAPACITY: usize = {
+        assert!(
+            EXP < 32,
+            "EXP of the const linear growth must be less than 32"
+        );
+        usize::pow(2, EXP as u32)
+    };
+    const MASK: usize = Self::FRAGMENT_CAPACITY - 1;
+}
+
+impl<const EXP: usize> PseudoDefault for ConstLinear<EXP> {
+    fn pseudo_default() -> Self {
+        Default::default()
+    }
+}
+
+impl<const EXP: usize> Growth for ConstLinear<EXP> {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        _fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        Self::FRAGMENT_CAPACITY
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        _fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => Some(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        fragments_capacity * Self::FRAGMENT_CAPACITY
+    }
+
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        _: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        let num_full_fragments = maximum_capacity >> EXP;
+        let remainder = maximum_capacity & Self::MASK;
+        let additional_fragment = if remainder > 0 { 1 } else { 0 };
+
+        Ok(num_full_fragments + additional_fragment)
+    }
+}
+
+impl<const EXP: usize> GrowthWithConstantTimeAccess for ConstLinear<EXP> {
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        let f = element_index >> EXP;
+        let i = element_index & Self::MASK;
+        (f, i)
+    }
+}
+
+impl<T, const EXP: usize> SplitVec<T, ConstLinear<EXP>> {
+    /// Creates a split vector with linear growth where each fragment will have a capacity of `2 ^ EXP`.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// Note that `SplitVec::<T, ConstLinear<10>>::new()`, provided by [`NewWithDefaultGrowth`](crate::NewWithDefaultGrowth),
+    /// and `SplitVec::<T, ConstLinear<10>>::default()` are equivalent to this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<usize, ConstLinear<10>> = SplitVec::with_const_linear_growth();
+    /// assert_eq!(vec.fragments()[0].capacity(), 1024);
+    ///
+    /// vec.extend_from_slice(&(0..3000).collect::<Vec<_>>());
+    /// assert_eq!(vec.fragments().len(), 3);
+    /// assert!(vec.fragments().iter().all(|f| f.capacity() == 1024));
+    ///
+    /// let other = SplitVec::<usize, ConstLinear<10>>::default();
+    /// assert_eq!(other.capacity(), 1024);
+    ///
+    /// let other = SplitVec::<usize, ConstLinear<10>>::new();
+    /// assert_eq!(other.capacity(), 1024);
+    /// ```
+    pub fn with_const_linear_growth() -> Self {
+        let fragments = Fragment::new(ConstLinear::<EXP>::FRAGMENT_CAPACITY).into_fragments();
+        Self::from_raw_parts(0, fragments, ConstLinear)
+    }
+
+    /// Creates a new split vector with `ConstLinear` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_const_linear_growth`] only by the pre-allocation of fragments collection.
+    /// This is useful only for concurrent programs; see [`SplitVec::maximum_concurrent_capacity`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_const_linear_growth_and_fragments_capacity(fragments_capacity: usize) -> Self {
+        assert!(fragments_capacity > 0);
+
+        let fragments = Fragment::new(ConstLinear::<EXP>::FRAGMENT_CAPACITY)
+            .into_fragments_with_capacity(fragments_capacity);
+        Self::from_raw_parts(0, fragments, ConstLinear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Global;
+    use crate::Linear;
+
+    #[test]
+    fn get_fragment_and_inner_indices() {
+        let growth = ConstLinear::<2>;
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+        assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
+        assert_eq!((0, 1), growth.get_fragment_and_inner_indices_unchecked(1));
+        assert_eq!((1, 0), growth.get_fragment_and_inner_indices_unchecked(4));
+        assert_eq!((2, 1), growth.get_fragment_and_inner_indices_unchecked(9));
+        assert_eq!((4, 0), growth.get_fragment_and_inner_indices_unchecked(16));
+
+        assert_eq!(Some((0, 0)), get(0));
+        assert_eq!(Some((2, 1)), get(9));
+
+        assert_eq!(None, get_none(0));
+        assert_eq!(None, get_none(9));
+    }
+
+    #[test]
+    fn equivalent_to_linear() {
+        fn test<const EXP: usize>() {
+            let growth = ConstLinear::<EXP>;
+            let linear = Linear::new(EXP);
+
+            for index in 0..33333 {
+                assert_eq!(
+                    linear.get_fragment_and_inner_indices_unchecked(index),
+                    growth.get_fragment_and_inner_indices_unchecked(index)
+                );
+                assert_eq!(
+                    linear.required_fragments_len::<char, Global>(&[], index),
+                    growth.required_fragments_len::<char, Global>(&[], index)
+                );
+            }
+            for fragments_capacity in 0..100 {
+                assert_eq!(
+                    linear.maximum_concurrent_capacity::<char, Global>(&[], fragments_capacity),
+                    growth.maximum_concurrent_capacity::<char, Global>(&[], fragments_capacity)
+                );
+            }
+        }
+
+        test::<0>();
+        test::<1>();
+        test::<5>();
+        test::<10>();
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, ConstLinear<5>>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<char, ConstLinear<5>> = SplitVec::with_const_linear_growth();
+        assert_eq!(max_cap(&vec), 4 * 2usize.pow(5));
+
+        let until = max_cap(&vec);
+        for _ in 0..until {
+            vec.push('x');
+            assert_eq!(max_cap(&vec), 4 * 2usize.pow(5));
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push('x');
+        assert_eq!(max_cap(&vec), 8 * 2usize.pow(5));
+    }
+
+    #[test]
+    fn default_and_pseudo_default() {
+        let vec: SplitVec<char, ConstLinear<7>> = SplitVec::default();
+        assert_eq!(1, vec.fragments().len());
+        assert_eq!(128, vec.fragments()[0].capacity());
+
+        let vec: SplitVec<char, ConstLinear<7>> = SplitVec::pseudo_default();
+        assert_eq!(1, vec.fragments().len());
+        assert_eq!(128, vec.fragments()[0].capacity());
+    }
+
+    #[test]
+    fn with_const_linear_growth_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, ConstLinear<10>> =
+            SplitVec::with_const_linear_growth_and_fragments_capacity(1);
+
+        assert_eq!(1, vec.fragments.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_const_linear_growth_and_fragments_capacity_zero() {
+        let _: SplitVec<char, ConstLinear<10>> =
+            SplitVec::with_const_linear_growth_and_fragments_capacity(0);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<char, ConstLinear<5>> = SplitVec::with_const_linear_growth();
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(2), Ok(1));
+        assert_eq!(num_fragments(32), Ok(1));
+        assert_eq!(num_fragments(33), Ok(2));
+        assert_eq!(num_fragments(32 * 7), Ok(7));
+        assert_eq!(num_fragments(32 * 7 + 1), Ok(8));
+    }
+}
@@ -1,5 +1,6 @@
 use super::constants::FIXED_CAPACITIES;
 use crate::{Linear, SplitVec};
+use alloc::{vec, vec::Vec};
 
 // into SplitVec
 impl<T> From<Vec<T>> for SplitVec<T, Linear> {
@@ -1,6 +1,7 @@
 use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
 use crate::growth::linear::constants::FIXED_CAPACITIES;
-use crate::{Fragment, SplitVec};
+use crate::{Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
 use orx_pseudo_default::PseudoDefault;
 
 /// Strategy which allows the split vector to grow linearly.
@@ -69,10 +70,10 @@ impl Growth for Linear {
     }
 
     #[inline(always)]
-    fn get_fragment_and_inner_indices<T>(
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
         &self,
         vec_len: usize,
-        _fragments: &[Fragment<T>],
+        _fragments: &[Fragment<T, A>],
         element_index: usize,
     ) -> Option<(usize, usize)> {
         match element_index < vec_len {
@@ -81,6 +82,14 @@ impl Growth for Linear {
         }
     }
 
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
     /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
     ///
     /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
@@ -90,7 +99,11 @@ impl Growth for Linear {
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
     #[inline(always)]
-    unsafe fn get_ptr_mut<T>(&self, fragments: &mut [Fragment<T>], index: usize) -> Option<*mut T> {
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
         <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
     }
 
@@ -104,17 +117,17 @@ impl Growth for Linear {
     ///
     /// This method allows to write to a memory which is greater than the split vector's length.
     /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
-    unsafe fn get_ptr_mut_and_indices<T>(
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
         &self,
-        fragments: &mut [Fragment<T>],
+        fragments: &mut [Fragment<T, A>],
         index: usize,
     ) -> Option<(*mut T, usize, usize)> {
         <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
     }
 
-    fn maximum_concurrent_capacity<T>(
+    fn maximum_concurrent_capacity<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         fragments_capacity: usize,
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
@@ -122,11 +135,11 @@ impl Growth for Linear {
         fragments_capacity * self.constant_fragment_capacity
     }
 
-    fn required_fragments_len<T>(
+    fn required_fragments_len<T, A: Allocator>(
         &self,
-        _: &[Fragment<T>],
+        _: &[Fragment<T, A>],
         maximum_capacity: usize,
-    ) -> Result<usize, String> {
+    ) -> Result<usize, SplitVecError> {
         let num_full_fragments = maximum_capacity / self.constant_fragment_capacity;
         let remainder = maximum_capacity % self.constant_fragment_capacity;
         let additional_fragment = if remainder > 0 { 1 } else { 0 };
@@ -221,14 +234,16 @@ impl<T> SplitVec<T, Linear> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use orx_pinned_vec::PinnedVec;
+    use crate::Global;
 
     #[test]
     fn get_fragment_and_inner_indices() {
         let growth = Linear::new(2);
 
-        let get = |index| growth.get_fragment_and_inner_indices::<char>(usize::MAX, &[], index);
-        let get_none = |index| growth.get_fragment_and_inner_indices::<char>(index, &[], index);
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
 
         assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
         assert_eq!((0, 1), growth.get_fragment_and_inner_indices_unchecked(1));
@@ -253,8 +268,10 @@ mod tests {
     fn get_fragment_and_inner_indices_exhaustive() {
         let growth = Linear::new(5);
 
-        let get = |index| growth.get_fragment_and_inner_indices::<char>(usize::MAX, &[], index);
-        let get_none = |index| growth.get_fragment_and_inner_indices::<char>(index, &[], index);
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
 
         let curr_capacity = 32;
 
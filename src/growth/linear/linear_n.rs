@@ -0,0 +1,389 @@
+use crate::growth::growth_trait::{Growth, GrowthWithConstantTimeAccess};
+use crate::{Fragment, SplitVec, SplitVecError};
+use allocator_api2::alloc::Allocator;
+use orx_pseudo_default::PseudoDefault;
+
+/// Strategy which allows the split vector to grow linearly where each fragment has the same capacity,
+/// which can be any positive number.
+///
+/// Unlike the [`Linear`] strategy, the fragment capacity is not required to be a power of two;
+/// for instance, fragments of 1000 or 3 * 1024 elements are possible.
+/// Fragment and inner indices are computed by a division and a modulo operation,
+/// and hence, random access is still in constant time.
+///
+/// [`Linear`]: crate::Linear
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// // SplitVec<usize, LinearN>
+/// let mut vec = SplitVec::with_linear_n_growth(1000);
+///
+/// assert_eq!(1, vec.fragments().len());
+/// assert_eq!(Some(1000), vec.fragments().first().map(|f| f.capacity()));
+/// assert_eq!(Some(0), vec.fragments().first().map(|f| f.len()));
+///
+/// // push 3000 elements
+/// for i in 0..3 * 1000 {
+///     vec.push(i);
+/// }
+///
+/// assert_eq!(3, vec.fragments().len());
+/// for fragment in vec.fragments() {
+///     assert_eq!(1000, fragment.len());
+///     assert_eq!(1000, fragment.capacity());
+/// }
+///
+/// // push the 3001-st element
+/// vec.push(42);
+/// assert_eq!(4, vec.fragments().len());
+/// assert_eq!(Some(1000), vec.fragments().last().map(|f| f.capacity()));
+/// assert_eq!(Some(1), vec.fragments().last().map(|f| f.len()));
+///
+/// assert_eq!(vec.get_fragment_and_inner_indices(2999), Some((2, 999)));
+/// assert_eq!(vec.get_fragment_and_inner_indices(3000), Some((3, 0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearN {
+    constant_fragment_capacity: usize,
+}
+
+impl LinearN {
+    /// Creates a linear growth strategy where each fragment has a capacity of `constant_fragment_capacity`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `constant_fragment_capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let growth = LinearN::new(3 * 1024);
+    /// assert_eq!(growth.constant_fragment_capacity(), 3072);
+    ///
+    /// let vec: SplitVec<u8, _> = SplitVec::with_growth(growth);
+    /// assert_eq!(vec.capacity(), 3072);
+    /// ```
+    pub fn new(constant_fragment_capacity: usize) -> Self {
+        assert!(constant_fragment_capacity > 0);
+        Self {
+            constant_fragment_capacity,
+        }
+    }
+
+    /// Returns the constant capacity of each fragment.
+    pub fn constant_fragment_capacity(&self) -> usize {
+        self.constant_fragment_capacity
+    }
+}
+
+impl PseudoDefault for LinearN {
+    fn pseudo_default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Growth for LinearN {
+    #[inline(always)]
+    fn new_fragment_capacity_from(
+        &self,
+        _fragment_capacities: impl ExactSizeIterator<Item = usize>,
+    ) -> usize {
+        self.constant_fragment_capacity
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        vec_len: usize,
+        _fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => Some(self.get_fragment_and_inner_indices_unchecked(element_index)),
+            false => None,
+        }
+    }
+
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_in_constant_time(
+        &self,
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        Some(self.get_fragment_and_inner_indices_unchecked(element_index))
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<*mut T> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut(self, fragments, index)
+    }
+
+    /// ***O(1)*** Returns a mutable reference to the `index`-th element of the split vector of the `fragments`
+    /// together with the index of the fragment that the element belongs to
+    /// and index of the element withing the respective fragment.
+    ///
+    /// Returns `None` if `index`-th position does not belong to the split vector; i.e., if `index` is out of cumulative capacity of fragments.
+    ///
+    /// # Safety
+    ///
+    /// This method allows to write to a memory which is greater than the split vector's length.
+    /// On the other hand, it will never return a pointer to a memory location that the vector does not own.
+    #[inline(always)]
+    unsafe fn get_ptr_mut_and_indices<T, A: Allocator>(
+        &self,
+        fragments: &mut [Fragment<T, A>],
+        index: usize,
+    ) -> Option<(*mut T, usize, usize)> {
+        <Self as GrowthWithConstantTimeAccess>::get_ptr_mut_and_indices(self, fragments, index)
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        fragments_capacity: usize,
+    ) -> usize {
+        assert!(fragments_capacity >= fragments.len());
+
+        fragments_capacity * self.constant_fragment_capacity
+    }
+
+    fn required_fragments_len<T, A: Allocator>(
+        &self,
+        _: &[Fragment<T, A>],
+        maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
+        Ok(maximum_capacity.div_ceil(self.constant_fragment_capacity))
+    }
+}
+
+impl GrowthWithConstantTimeAccess for LinearN {
+    #[inline(always)]
+    fn get_fragment_and_inner_indices_unchecked(&self, element_index: usize) -> (usize, usize) {
+        let f = element_index / self.constant_fragment_capacity;
+        let i = element_index % self.constant_fragment_capacity;
+        (f, i)
+    }
+}
+
+impl<T> SplitVec<T, LinearN> {
+    /// Creates a split vector with linear growth where each fragment will have a capacity of `constant_fragment_capacity`,
+    /// which is not required to be a power of two.
+    ///
+    /// Assuming it is the common case compared to empty vector scenarios,
+    /// it immediately allocates the first fragment to keep the `SplitVec` struct smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `constant_fragment_capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // SplitVec<usize, LinearN>
+    /// let mut vec = SplitVec::with_linear_n_growth(3);
+    ///
+    /// for i in 0..10 {
+    ///     vec.push(i);
+    /// }
+    ///
+    /// assert_eq!(4, vec.fragments().len());
+    /// assert_eq!(vec.fragments()[0], &[0, 1, 2]);
+    /// assert_eq!(vec.fragments()[1], &[3, 4, 5]);
+    /// assert_eq!(vec.fragments()[2], &[6, 7, 8]);
+    /// assert_eq!(vec.fragments()[3], &[9]);
+    /// ```
+    pub fn with_linear_n_growth(constant_fragment_capacity: usize) -> Self {
+        let fragments = Fragment::new(constant_fragment_capacity).into_fragments();
+        let growth = LinearN::new(constant_fragment_capacity);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+
+    /// Creates a new split vector with `LinearN` growth and initial `fragments_capacity`.
+    ///
+    /// This method differs from [`SplitVec::with_linear_n_growth`] only by the pre-allocation of fragments collection.
+    /// It matters only in concurrent programs, which require the fragments collection to stay in place while the vector grows to `fragments_capacity` fragments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `constant_fragment_capacity == 0` or `fragments_capacity == 0`.
+    pub fn with_linear_n_growth_and_fragments_capacity(
+        constant_fragment_capacity: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        assert!(fragments_capacity > 0);
+
+        let growth = LinearN::new(constant_fragment_capacity);
+        let fragments = Fragment::new(constant_fragment_capacity)
+            .into_fragments_with_capacity(fragments_capacity);
+        Self::from_raw_parts(0, fragments, growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Global;
+    use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+
+    #[test]
+    fn get_fragment_and_inner_indices() {
+        let growth = LinearN::new(3);
+
+        let get =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index);
+        let get_none =
+            |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+        assert_eq!((0, 0), growth.get_fragment_and_inner_indices_unchecked(0));
+        assert_eq!((0, 2), growth.get_fragment_and_inner_indices_unchecked(2));
+        assert_eq!((1, 0), growth.get_fragment_and_inner_indices_unchecked(3));
+        assert_eq!((3, 0), growth.get_fragment_and_inner_indices_unchecked(9));
+        assert_eq!((5, 1), growth.get_fragment_and_inner_indices_unchecked(16));
+
+        assert_eq!(Some((0, 0)), get(0));
+        assert_eq!(Some((3, 0)), get(9));
+        assert_eq!(Some((5, 1)), get(16));
+
+        assert_eq!(None, get_none(0));
+        assert_eq!(None, get_none(9));
+        assert_eq!(None, get_none(16));
+    }
+
+    #[test]
+    fn get_fragment_and_inner_indices_exhaustive() {
+        for capacity in [1, 3, 7, 1000, 3 * 1024] {
+            let growth = LinearN::new(capacity);
+
+            let get = |index| {
+                growth.get_fragment_and_inner_indices::<char, Global>(usize::MAX, &[], index)
+            };
+            let get_none =
+                |index| growth.get_fragment_and_inner_indices::<char, Global>(index, &[], index);
+
+            let mut f = 0;
+            let mut prev_cumulative_capacity = 0;
+            let mut cumulative_capacity = capacity;
+
+            for index in 0..111111 {
+                if index == cumulative_capacity {
+                    prev_cumulative_capacity = cumulative_capacity;
+                    cumulative_capacity += capacity;
+                    f += 1;
+                }
+
+                let (f, i) = (f, index - prev_cumulative_capacity);
+                assert_eq!(
+                    (f, i),
+                    growth.get_fragment_and_inner_indices_unchecked(index)
+                );
+                assert_eq!(Some((f, i)), get(index));
+                assert_eq!(None, get_none(index));
+            }
+        }
+    }
+
+    #[test]
+    fn maximum_concurrent_capacity() {
+        fn max_cap<T>(vec: &SplitVec<T, LinearN>) -> usize {
+            vec.growth()
+                .maximum_concurrent_capacity(vec.fragments(), vec.fragments.capacity())
+        }
+
+        let mut vec: SplitVec<char, LinearN> = SplitVec::with_linear_n_growth(100);
+        assert_eq!(max_cap(&vec), 4 * 100);
+
+        let until = max_cap(&vec);
+        for _ in 0..until {
+            vec.push('x');
+            assert_eq!(max_cap(&vec), 4 * 100);
+        }
+
+        // fragments allocate beyond max_cap
+        vec.push('x');
+        assert_eq!(max_cap(&vec), 8 * 100);
+    }
+
+    #[test]
+    fn with_linear_n_growth_and_fragments_capacity_normal_growth() {
+        let mut vec: SplitVec<char, _> =
+            SplitVec::with_linear_n_growth_and_fragments_capacity(1000, 1);
+
+        assert_eq!(1, vec.fragments.capacity());
+
+        for _ in 0..100_000 {
+            vec.push('x');
+        }
+
+        assert!(vec.fragments.capacity() > 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_linear_n_growth_and_fragments_capacity_zero() {
+        let _: SplitVec<char, _> = SplitVec::with_linear_n_growth_and_fragments_capacity(1000, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_linear_n_growth_zero_capacity() {
+        let _: SplitVec<char, _> = SplitVec::with_linear_n_growth(0);
+    }
+
+    #[test]
+    fn required_fragments_len() {
+        let vec: SplitVec<char, LinearN> = SplitVec::with_linear_n_growth(1000);
+        let num_fragments = |max_cap| {
+            vec.growth()
+                .required_fragments_len(vec.fragments(), max_cap)
+        };
+
+        assert_eq!(num_fragments(0), Ok(0));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(1000), Ok(1));
+        assert_eq!(num_fragments(1001), Ok(2));
+        assert_eq!(num_fragments(1000 * 7), Ok(7));
+        assert_eq!(num_fragments(1000 * 7 + 1), Ok(8));
+    }
+
+    #[test]
+    fn into_concurrent() {
+        let vec: SplitVec<usize, LinearN> =
+            SplitVec::with_linear_n_growth_and_fragments_capacity(1000, 8);
+        let con_vec = vec.into_concurrent();
+
+        let len = 3500;
+        assert!(con_vec.max_capacity() >= len);
+
+        let capacity = con_vec.grow_to(len).expect("is-ok");
+        assert_eq!(capacity, 4000);
+
+        for i in 0..len {
+            unsafe { con_vec.get_ptr_mut(i).write(i) };
+        }
+
+        let vec = unsafe { con_vec.into_inner(len) };
+        assert_eq!(vec.len(), len);
+        for i in 0..len {
+            assert_eq!(vec.get(i), Some(&i));
+        }
+        assert_eq!(vec.fragments()[3].len(), 500);
+    }
+}
@@ -1,8 +1,12 @@
+mod const_linear;
 mod constants;
 mod from;
 mod linear_growth;
+mod linear_n;
 
 #[cfg(test)]
 mod tests;
 
+pub use const_linear::ConstLinear;
 pub use linear_growth::Linear;
+pub use linear_n::LinearN;
@@ -1,4 +1,7 @@
+pub(crate) mod byte_budget;
+pub(crate) mod capped_doubling;
 pub(crate) mod doubling;
+pub(crate) mod geometric;
 pub(crate) mod growth_trait;
 pub(crate) mod linear;
 pub(crate) mod recursive;
@@ -1,4 +1,5 @@
 use crate::{Doubling, Linear, Recursive, SplitVec};
+use alloc::{vec, vec::Vec};
 
 impl<T> From<SplitVec<T, Doubling>> for SplitVec<T, Recursive> {
     /// Converts a `SplitVec<T, Doubling>` into a `SplitVec<T, Recursive>` with no cost.
@@ -23,7 +24,7 @@ impl<T> From<SplitVec<T, Doubling>> for SplitVec<T, Recursive> {
     /// assert_eq!(split_vec_recursive, &['a', 'b', 'c']);
     /// ```
     fn from(value: SplitVec<T, Doubling>) -> Self {
-        Self::from_raw_parts(value.len, value.fragments, Recursive)
+        Self::from_raw_parts(value.len, value.fragments, Recursive::default())
     }
 }
 
@@ -50,7 +51,7 @@ impl<T> From<SplitVec<T, Linear>> for SplitVec<T, Recursive> {
     /// assert_eq!(split_vec_recursive, &['a', 'b', 'c']);
     /// ```
     fn from(value: SplitVec<T, Linear>) -> Self {
-        Self::from_raw_parts(value.len, value.fragments, Recursive)
+        Self::from_raw_parts(value.len, value.fragments, Recursive::default())
     }
 }
 
@@ -72,7 +73,7 @@ impl<T: Clone> From<Vec<T>> for SplitVec<T, Recursive> {
     /// assert!(vec_capacity <= split_vec.capacity());
     /// ```
     fn from(value: Vec<T>) -> Self {
-        SplitVec::from_raw_parts(value.len(), vec![value.into()], Recursive)
+        SplitVec::from_raw_parts(value.len(), vec![value.into()], Recursive::default())
     }
 }
 
@@ -1,4 +1,3 @@
-mod append;
 mod from;
 mod recursive_growth;
 
@@ -1,4 +1,7 @@
-use crate::{Doubling, Fragment, Growth, SplitVec};
+use crate::growth::growth_trait::get_fragment_and_inner_indices_by_scan;
+use crate::{Doubling, Fragment, Growth, SplitVec, SplitVecError};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
 use orx_pseudo_default::PseudoDefault;
 
 /// Equivalent to [`Doubling`] strategy except for the following:
@@ -11,13 +14,15 @@ use orx_pseudo_default::PseudoDefault;
 ///     * since its time complexity is independent of size of the data to be appended.
 /// * at the expense of providing slower random-access performance:
 ///   * random access time complexity of `Doubling` strategy is constant time;
-///   * that of `Recursive` strategy is linear in the number of fragments;
-///   * according to benchmarks documented in the crate definition:
-///     * `SplitVec<_, Doubling>` or standard vector are around 4 to 7 times faster than `SplitVec<_, Recursive>`,
-///     * and 1.5 times faster when the elements get very large (16 x `u64`).
+///   * that of `Recursive` strategy is logarithmic in the number of fragments;
+///   * the strategy keeps the cumulative lengths of its fragments, which is updated by mutating methods such as
+///     `append`, `push`, `insert`, `remove` and `truncate`, and locates an element by a binary search over this index.
 ///
 /// Note that other operations such as serial access are equivalent to `Doubling` strategy.
 ///
+/// Since it holds the cumulative lengths index, `Recursive` is not a unit struct and is not `Copy`;
+/// it is created by `Recursive::default()` or by the `SplitVec::with_recursive_growth` constructors.
+///
 /// # Examples
 ///
 /// ```
@@ -40,7 +45,31 @@ use orx_pseudo_default::PseudoDefault;
 /// assert_eq!(vec, &['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h']);
 /// ```
 #[derive(Debug, Default, Clone, PartialEq)]
-pub struct Recursive;
+pub struct Recursive {
+    /// Index of the first element of each fragment; i.e., cumulative lengths of the prior fragments.
+    fragment_begins: Vec<usize>,
+}
+
+impl Recursive {
+    /// Locates the element by a binary search over the cumulative lengths index;
+    /// returns None if the index is not built for the given `fragments`.
+    #[inline(always)]
+    fn indexed_fragment_and_inner_indices<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match self.fragment_begins.len() == fragments.len() {
+            true => self
+                .fragment_begins
+                .partition_point(|x| *x <= element_index)
+                .checked_sub(1)
+                .map(|f| (f, element_index - self.fragment_begins[f]))
+                .filter(|(f, i)| *i < fragments[*f].len()),
+            false => None,
+        }
+    }
+}
 
 impl PseudoDefault for Recursive {
     fn pseudo_default() -> Self {
@@ -57,9 +86,69 @@ impl Growth for Recursive {
         Doubling.new_fragment_capacity_from(fragment_capacities)
     }
 
-    fn maximum_concurrent_capacity<T>(
+    /// ***O(log(f))*** Returns the location of the element with the given `element_index` on the split vector as a tuple of (fragment-index, index-within-fragment),
+    /// where `f` is the number of fragments.
+    ///
+    /// The location is found by a binary search over the cumulative lengths of the fragments.
+    /// When the cumulative lengths index is not built for the given `fragments`, the fragments are scanned instead.
+    ///
+    /// Returns None if the element index is out of bounds.
+    fn get_fragment_and_inner_indices<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        vec_len: usize,
+        fragments: &[Fragment<T, A>],
+        element_index: usize,
+    ) -> Option<(usize, usize)> {
+        match element_index < vec_len {
+            true => self
+                .indexed_fragment_and_inner_indices(fragments, element_index)
+                .or_else(|| get_fragment_and_inner_indices_by_scan(fragments, element_index)),
+            false => None,
+        }
+    }
+
+    fn fragments_mutated_from<T, A: Allocator>(
+        &mut self,
+        fragments: &[Fragment<T, A>],
+        begin_fragment: usize,
+    ) {
+        let begin = begin_fragment
+            .min(self.fragment_begins.len())
+            .min(fragments.len());
+        self.fragment_begins.truncate(begin);
+
+        debug_assert!(
+            self.fragment_begins
+                .iter()
+                .zip(fragments)
+                .try_fold(0, |begin, (x, f)| (*x == begin).then_some(begin + f.len()))
+                .is_some(),
+            "cumulative lengths index of the recursive growth is out of sync with the fragments; `fragments_mutated_from` must be called after the fragments are mutated"
+        );
+
+        let mut next_begin = match begin {
+            0 => 0,
+            f => self.fragment_begins[f - 1] + fragments[f - 1].len(),
+        };
+        for fragment in &fragments[begin..] {
+            self.fragment_begins.push(next_begin);
+            next_begin += fragment.len();
+        }
+    }
+
+    /// `Recursive` growth does not make any assumptions on the capacities of the fragments; hence, it can take ownership of any fragment.
+    #[inline(always)]
+    fn can_append_fragment<T, A: Allocator>(
+        &self,
+        _fragments: &[Fragment<T, A>],
+        _fragment: &Fragment<T, A>,
+    ) -> bool {
+        true
+    }
+
+    fn maximum_concurrent_capacity<T, A: Allocator>(
+        &self,
+        fragments: &[Fragment<T, A>],
         fragments_capacity: usize,
     ) -> usize {
         assert!(fragments_capacity >= fragments.len());
@@ -77,18 +166,11 @@ impl Growth for Recursive {
         total_capacity
     }
 
-    fn required_fragments_len<T>(
+    fn required_fragments_len<T, A: Allocator>(
         &self,
-        fragments: &[Fragment<T>],
+        fragments: &[Fragment<T, A>],
         maximum_capacity: usize,
-    ) -> Result<usize, String> {
-        fn overflown_err() -> String {
-            format!(
-                "Maximum cumulative capacity that can be reached by the Recursive strategy is {}.",
-                usize::MAX
-            )
-        }
-
+    ) -> Result<usize, SplitVecError> {
         let current_capacity: usize = fragments.iter().map(|x| x.capacity()).sum();
         let mut last_capacity = fragments.last().map(|x| x.capacity()).unwrap_or(2);
 
@@ -98,13 +180,13 @@ impl Growth for Recursive {
         while total_capacity < maximum_capacity {
             let (new_last_capacity, overflown) = last_capacity.overflowing_mul(2);
             if overflown {
-                return Err(overflown_err());
+                return Err(SplitVecError::CapacityOverflow { max: usize::MAX });
             }
             last_capacity = new_last_capacity;
 
             let (new_total_capacity, overflown) = total_capacity.overflowing_add(last_capacity);
             if overflown {
-                return Err(overflown_err());
+                return Err(SplitVecError::CapacityOverflow { max: usize::MAX });
             }
 
             total_capacity = new_total_capacity;
@@ -122,7 +204,7 @@ impl<T> SplitVec<T, Recursive> {
     /// Notice that this is similar to the `Doubling` growth strategy.
     /// However, `Recursive` and `Doubling` strategies have the two following important differences in terms of performance:
     ///
-    /// * Random access by indices is much faster with `Doubling`.
+    /// * Random access by indices is faster with `Doubling`.
     /// * Recursive strategy enables copy-free `append` method which merges another vector to this vector in constant time.
     ///
     /// All other operations are expected to have similar complexity.
@@ -130,9 +212,10 @@ impl<T> SplitVec<T, Recursive> {
     /// ## Random Access
     ///
     /// * `Doubling` strategy provides a constant time access by random indices.
-    /// * `Recursive` strategy provides a random access time complexity that is linear in the number of fragments.
-    /// Note that this is significantly faster than the linear-in-number-of-elements complexity of linked lists;
-    /// however, significantly slower than the `Doubling` strategy's constant time.
+    /// * `Recursive` strategy provides a random access time complexity that is logarithmic in the number of fragments,
+    ///   by a binary search over the cumulative lengths of the fragments.
+    ///   Note that this is significantly faster than the linear-in-number-of-elements complexity of linked lists;
+    ///   however, slower than the `Doubling` strategy's constant time.
     ///
     /// ## Append
     ///
@@ -210,11 +293,10 @@ impl<T> SplitVec<T, Recursive> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use orx_pinned_vec::PinnedVec;
 
     #[test]
     fn get_fragment_and_inner_indices() {
-        let growth = Recursive;
+        let growth = Recursive::default();
 
         let vecs = vec![
             vec![0, 1, 2, 3],
@@ -245,7 +327,7 @@ mod tests {
 
     #[test]
     fn get_fragment_and_inner_indices_exhaustive() {
-        let growth = Recursive;
+        let growth = Recursive::default();
 
         let mut fragments: Vec<Fragment<_>> = vec![];
 
@@ -288,6 +370,38 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "out of sync")]
+    fn index_out_of_sync_with_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(vec![3, 4]);
+        vec.append(vec![5, 6]);
+
+        _ = unsafe { vec.fragments_mut()[0].pop() };
+        vec.len -= 1;
+
+        // fragments_mutated_from is not called before the next mutation
+        vec.append(vec![7, 8]);
+    }
+
+    #[test]
+    fn index_in_sync_with_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(vec![3, 4]);
+
+        let f = vec.fragments().len() - 2;
+        _ = unsafe { vec.fragments_mut()[f].pop() };
+        vec.len -= 1;
+        vec.fragments_mutated_from(f);
+
+        assert_eq!(vec, &[0, 1, 3, 4]);
+        assert_eq!(vec.get(2), Some(&3));
+        assert_eq!(vec.get(3), Some(&4));
+    }
+
     #[test]
     fn maximum_concurrent_capacity() {
         fn max_cap<T>(vec: &SplitVec<T, Recursive>) -> usize {
@@ -321,7 +435,7 @@ mod tests {
 
         vec.append(vec!['x'; 10]);
 
-        assert_eq!(max_cap(&vec), 4 + 10 + 20 + 40);
+        assert_eq!(max_cap(&vec), 10 + 20 + 40 + 80);
     }
 
     #[test]
@@ -373,18 +487,136 @@ mod tests {
                 .required_fragments_len(vec.fragments(), max_cap)
         };
 
-        // 4 - 10 - 20 - 40 - 80
-        // 4 - 14 - 34 - 74 - 154
-        assert_eq!(num_fragments(0), Ok(2));
-        assert_eq!(num_fragments(1), Ok(2));
-        assert_eq!(num_fragments(14), Ok(2));
-        assert_eq!(num_fragments(15), Ok(3));
-        assert_eq!(num_fragments(21), Ok(3));
-        assert_eq!(num_fragments(34), Ok(3));
-        assert_eq!(num_fragments(35), Ok(4));
-        assert_eq!(num_fragments(74), Ok(4));
-        assert_eq!(num_fragments(75), Ok(5));
-        assert_eq!(num_fragments(154), Ok(5));
-        assert_eq!(num_fragments(155), Ok(6));
+        // 10 - 20 - 40 - 80
+        // 10 - 30 - 70 - 150
+        assert_eq!(num_fragments(0), Ok(1));
+        assert_eq!(num_fragments(1), Ok(1));
+        assert_eq!(num_fragments(10), Ok(1));
+        assert_eq!(num_fragments(11), Ok(2));
+        assert_eq!(num_fragments(21), Ok(2));
+        assert_eq!(num_fragments(30), Ok(2));
+        assert_eq!(num_fragments(31), Ok(3));
+        assert_eq!(num_fragments(70), Ok(3));
+        assert_eq!(num_fragments(71), Ok(4));
+        assert_eq!(num_fragments(150), Ok(4));
+        assert_eq!(num_fragments(151), Ok(5));
+    }
+
+    fn assert_index_is_synced<T: PartialEq + core::fmt::Debug>(
+        vec: &SplitVec<T, Recursive>,
+        expected: &[T],
+    ) {
+        let mut begins = vec![];
+        let mut begin = 0;
+        for fragment in vec.fragments() {
+            begins.push(begin);
+            begin += fragment.len();
+        }
+        assert_eq!(vec.growth().fragment_begins, begins);
+
+        assert_eq!(vec.len(), expected.len());
+        for (i, x) in expected.iter().enumerate() {
+            assert_eq!(vec.get(i), Some(x));
+            assert_eq!(
+                vec.get_fragment_and_inner_indices(i),
+                get_fragment_and_inner_indices_by_scan(vec.fragments(), i)
+            );
+        }
+        assert_eq!(vec.get(expected.len()), None);
+    }
+
+    #[test]
+    fn index_synced_with_append_and_push() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        let mut expected = vec![];
+        assert_index_is_synced(&vec, &expected);
+
+        for i in 0..100 {
+            match i % 4 {
+                0 => {
+                    let other: Vec<_> = (0..i).collect();
+                    expected.extend_from_slice(&other);
+                    vec.append(other);
+                }
+                1 => {
+                    let other: Vec<_> = (0..i).map(|x| vec![x; i % 3]).collect();
+                    expected.extend(other.iter().flatten().copied());
+                    vec.append(other);
+                }
+                _ => {
+                    for j in 0..i {
+                        vec.push(j);
+                        expected.push(j);
+                    }
+                }
+            }
+            assert_index_is_synced(&vec, &expected);
+        }
+    }
+
+    #[test]
+    fn index_synced_with_mutations() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        let mut expected = vec![];
+
+        for i in 0..50 {
+            let other: Vec<_> = (0..(1 + i % 7)).map(|x| 100 * i + x).collect();
+            expected.extend_from_slice(&other);
+            vec.append(other);
+            vec.push(i);
+            expected.push(i);
+        }
+        assert_index_is_synced(&vec, &expected);
+
+        for i in 0..40 {
+            let index = (i * 17) % expected.len();
+            vec.insert(index, 1000 + i);
+            expected.insert(index, 1000 + i);
+            assert_index_is_synced(&vec, &expected);
+        }
+
+        for i in 0..40 {
+            let index = (i * 13) % expected.len();
+            assert_eq!(vec.remove(index), expected.remove(index));
+            assert_index_is_synced(&vec, &expected);
+        }
+
+        for _ in 0..10 {
+            assert_eq!(vec.pop(), expected.pop());
+            assert_index_is_synced(&vec, &expected);
+        }
+
+        vec.truncate(expected.len() / 2);
+        expected.truncate(expected.len() / 2);
+        assert_index_is_synced(&vec, &expected);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        expected.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_index_is_synced(&vec, &expected);
+
+        vec.clear();
+        expected.clear();
+        assert_index_is_synced(&vec, &expected);
+
+        vec.append(vec![1, 2, 3]);
+        expected.extend_from_slice(&[1, 2, 3]);
+        assert_index_is_synced(&vec, &expected);
+    }
+
+    #[test]
+    fn index_synced_when_cloned_and_converted() {
+        let mut vec: SplitVec<usize, Recursive> = SplitVec::with_recursive_growth();
+        for i in 0..20 {
+            vec.append((0..i).collect::<Vec<_>>());
+        }
+        let expected: Vec<_> = vec.iter().copied().collect();
+
+        let clone = vec.clone();
+        assert_index_is_synced(&clone, &expected);
+
+        let mut doubling = SplitVec::with_doubling_growth();
+        doubling.extend_from_slice(&expected);
+        let converted: SplitVec<_, Recursive> = doubling.into();
+        assert_index_is_synced(&converted, &expected);
     }
 }
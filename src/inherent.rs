@@ -0,0 +1,493 @@
+use crate::{Growth, Iter, IterMut, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Returns the total number of elements the split vector can hold without
+    /// reallocating.
+    ///
+    /// See `FragmentGrowth` for details of capacity growth policies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// // default growth starting with 4, and doubling at each new fragment.
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// assert_eq!(4, vec.capacity());
+    ///
+    /// for i in 0..4 {
+    ///     vec.push(i);
+    /// }
+    /// assert_eq!(4, vec.capacity());
+    ///
+    /// vec.push(4);
+    /// assert_eq!(4 + 8, vec.capacity());
+    ///
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.fragments.iter().map(|f| f.capacity()).sum()
+    }
+
+    /// Clears the vector, removing all values.
+    ///
+    /// This method:
+    /// * drops all fragments except for the first one, and
+    /// * clears the first fragment.
+    ///
+    /// See [`SplitVec::clear_keep_capacity`] to keep the dropped fragments for reuse instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(5);
+    /// for _ in 0..10 {
+    ///     vec.push(4.2);
+    /// }
+    ///
+    /// vec.clear();
+    ///
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        if !self.fragments.is_empty() {
+            self.fragments.truncate(1);
+            self.fragments[0].clear();
+        }
+        self.len = 0;
+        self.fragments_mutated_from(0);
+    }
+
+    /// Clones and appends all elements in a slice to the vec.
+    ///
+    /// Iterates over the slice `other`, clones each element, and then appends
+    /// it to this vector. The `other` slice is traversed in-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(4);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(vec, [1, 2, 3]);
+    ///
+    /// vec.extend_from_slice(&[4, 5, 6, 7]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.len += other.len();
+        let mut slice = other;
+        while !slice.is_empty() {
+            if !self.has_capacity_for_one() {
+                self.add_fragment();
+            }
+            let f = self.fragments.len() - 1;
+
+            let last = &mut self.fragments[f];
+            let available = last.room();
+
+            if available < slice.len() {
+                last.extend_from_slice(&slice[0..available]);
+                slice = &slice[available..];
+                self.add_fragment();
+            } else {
+                last.extend_from_slice(slice);
+                break;
+            }
+        }
+    }
+
+    /// Returns a reference to the element with the given `index`;
+    /// None if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(5);
+    /// vec.extend_from_slice(&[10, 40, 30]);
+    /// assert_eq!(Some(&40), vec.get(1));
+    /// assert_eq!(None, vec.get(3));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.get_fragment_and_inner_indices(index)
+            .map(|(f, i)| unsafe { self.fragments.get_unchecked(f).get_unchecked(i) })
+    }
+
+    /// Returns a mutable reference to the element with the given `index`;
+    /// None if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(5);
+    /// vec.extend_from_slice(&[0, 1, 2]);
+    ///
+    /// if let Some(elem) = vec.get_mut(1) {
+    ///     *elem = 42;
+    /// }
+    ///
+    /// assert_eq!(vec, &[0, 42, 2]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.get_fragment_and_inner_indices(index)
+            .map(|(f, i)| unsafe { self.fragments.get_unchecked_mut(f).get_unchecked_mut(i) })
+    }
+
+    /// Returns a reference to the first element of the vector; returns None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// assert!(vec.first().is_none());
+    ///
+    /// vec.push(42);
+    /// assert_eq!(Some(&42), vec.first());
+    ///
+    /// vec.push(864121);
+    /// assert_eq!(Some(&42), vec.first());
+    ///
+    /// vec.insert(0, 7);
+    /// assert_eq!(Some(&7), vec.first());
+    /// ```
+    #[inline(always)]
+    pub fn first(&self) -> Option<&T> {
+        self.fragments.first().and_then(|x| x.first())
+    }
+
+    /// Returns a reference to the last element of the vector; returns None if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::new();
+    /// assert!(vec.last().is_none());
+    ///
+    /// vec.push(42);
+    /// assert_eq!(Some(&42), vec.last());
+    ///
+    /// vec.push(7);
+    /// assert_eq!(Some(&7), vec.last());
+    ///
+    /// vec.insert(0, 684321);
+    /// assert_eq!(Some(&7), vec.last());
+    /// ```
+    #[inline(always)]
+    pub fn last(&self) -> Option<&T> {
+        self.fragments.last().and_then(|x| x.last())
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting all elements after it to the right.
+    ///
+    /// Elements before the `index` keep their memory locations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// vec.insert(1, 4);
+    /// assert_eq!(vec, [1, 4, 2, 3]);
+    ///
+    /// vec.insert(4, 5);
+    /// assert_eq!(vec, [1, 4, 2, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index == self.len {
+            self.push(value);
+        } else {
+            // make room for one
+            if !self.has_capacity_for_one() {
+                self.add_fragment();
+            }
+
+            let (f, i) = self
+                .get_fragment_and_inner_indices(index)
+                .expect("out-of-bounds");
+
+            if self.fragments[f].has_capacity_for_one() {
+                self.fragments[f].insert(i, value);
+            } else {
+                let mut popped = self.fragments[f].pop().expect("no-way!");
+                self.fragments[f].insert(i, value);
+                let mut f = f;
+                loop {
+                    f += 1;
+
+                    if self.fragments[f].has_capacity_for_one() {
+                        self.fragments[f].insert(0, popped);
+                        break;
+                    } else {
+                        let new_popped = self.fragments[f].pop().expect("no-way");
+                        self.fragments[f].insert(0, popped);
+                        popped = new_popped;
+                    }
+                }
+            }
+            self.fragments_mutated_from(f);
+            self.len += 1;
+        }
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// assert!(vec.is_empty());
+    /// vec.push(1);
+    /// assert!(!vec.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements in the vector, also referred to
+    /// as its 'length'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec =  SplitVec::with_linear_growth(8);
+    /// assert_eq!(0, vec.len());
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(3, vec.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Removes the last element from the vector and returns it, or None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(vec.pop(), Some(3));
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.fragments.is_empty() {
+            None
+        } else {
+            let f = self.fragments.len() - 1;
+            if self.fragments[f].is_empty() {
+                if f == 0 {
+                    None
+                } else {
+                    self.len -= 1;
+                    self.fragments.pop();
+                    self.fragments_mutated_from(f);
+                    self.fragments[f - 1].pop()
+                }
+            } else {
+                self.len -= 1;
+                let popped = self.fragments[f].pop();
+                if self.fragments[f].is_empty() {
+                    self.fragments.pop();
+                    self.fragments_mutated_from(f);
+                }
+                popped
+            }
+        }
+    }
+
+    /// Appends an element to the back of a collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(16);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.push(3);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.len += 1;
+        if self.has_capacity_for_one() {
+            let last_f = self.fragments.len() - 1;
+            self.fragments[last_f].push(value);
+            return;
+        }
+
+        self.add_fragment_with_first_value(value);
+    }
+
+    /// Removes and returns the element at position `index` within the vector, shifting all elements after it to the left.
+    ///
+    /// Elements before the `index` keep their memory locations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(vec.remove(1), 2);
+    /// assert_eq!(vec, [1, 3, 4, 5]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        self.drop_last_empty_fragment();
+
+        let (f, i) = self
+            .get_fragment_and_inner_indices(index)
+            .expect("out-of-bounds");
+
+        let value = self.fragments[f].remove(i);
+
+        for f2 in f + 1..self.fragments.len() {
+            let x = self.fragments[f2].remove(0);
+            self.fragments[f2 - 1].push(x);
+            if self.fragments[f2].is_empty() {
+                self.fragments.remove(f2);
+                break;
+            }
+        }
+        self.fragments_mutated_from(f);
+
+        self.drop_last_empty_fragment();
+
+        self.len -= 1;
+        value
+    }
+
+    /// Swaps two elements in the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// vec.swap(0, 4);
+    /// assert_eq!(vec, [5, 2, 3, 4, 1]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (af, ai) = self
+            .get_fragment_and_inner_indices(a)
+            .expect("out-of-bounds");
+        let (bf, bi) = self
+            .get_fragment_and_inner_indices(b)
+            .expect("out-of-bounds");
+        if af == bf {
+            self.fragments[af].swap(ai, bi);
+        } else {
+            let ptr_a = unsafe { self.fragments[af].as_mut_ptr().add(ai) };
+            let ref_a = unsafe { &mut *ptr_a };
+            let ref_b = &mut self.fragments[bf][bi];
+            core::mem::swap(ref_a, ref_b);
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// If `len` is greater than or equal to the vector's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// vec.truncate(2);
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if let Some((f, i)) = self.get_fragment_and_inner_indices(len) {
+            self.fragments.truncate(f + 1);
+            self.fragments[f].truncate(i);
+            self.len = len;
+            self.fragments_mutated_from(f);
+
+            self.drop_last_empty_fragment();
+        }
+    }
+
+    /// Returns an iterator over references to the elements of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(vec.iter().sum::<i32>(), 15);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, A, G> {
+        Iter::with_growth(&self.fragments, &self.growth, self.len)
+    }
+
+    /// Returns an iterator over mutable references to the elements of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// vec.iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(vec, [10, 20, 30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A, G> {
+        IterMut::with_growth(&mut self.fragments, &self.growth, self.len)
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{ConcurrentSplitVec, GrowthWithConstantTimeAccess, SplitVec};
+use allocator_api2::alloc::Allocator;
 use orx_pinned_vec::IntoConcurrentPinnedVec;
 
-impl<T, G: GrowthWithConstantTimeAccess> IntoConcurrentPinnedVec<T> for SplitVec<T, G> {
-    type ConPinnedVec = ConcurrentSplitVec<T, G>;
+impl<T, G: GrowthWithConstantTimeAccess, A: Allocator + Clone + Default> IntoConcurrentPinnedVec<T>
+    for SplitVec<T, G, A>
+{
+    type ConPinnedVec = ConcurrentSplitVec<T, G, A>;
 
     fn into_concurrent(self) -> Self::ConPinnedVec {
         self.into()
@@ -43,9 +43,21 @@
 //!
 //! `C` is set on initialization as a power of two for `Linear` strategy, and it is fixed to 4 for `Doubling` strategy to allow for access time optimizations.
 //!
-//! In addition there exists the `Recursive` growth strategy, which behaves as the `Doubling` strategy at the beginning. However, it allows for zero-cost `append` operation at the expense of a reduced random access time performance.
+//! `ConstLinear<E>` is the compile-time counterpart of the `Linear` strategy where the fragment capacity `2^E` is a const generic parameter; for instance, `SplitVec<T, ConstLinear<10>>` creates fragments with capacity 1024, and the index computations use compile-time shifts and masks.
 //!
-//! Growth strategies which allow for constant time random access additionally implement the `GrowthWithConstantTimeAccess` trait, which are currently `Doubling` and `Linear` strategies.
+//! `LinearN` is a linear strategy where the constant fragment capacity can be any positive number rather than a power of two; for instance, `SplitVec::with_linear_n_growth(1000)` creates fragments with capacity 1000. The indices are computed by division and modulo operations, and hence, random access is still in constant time.
+//!
+//! `DoublingFrom<P>` is the generalization of the `Doubling` strategy where `C` is set to `2^P` at compile time; for instance, `SplitVec<T, DoublingFrom<12>>` starts with a fragment of capacity 4096 while keeping the same access time optimizations.
+//!
+//! `ByteBudget` strategy is a linear growth where the fragment capacity is determined by a budget in bytes rather than a number of elements. The element capacity of each fragment is the largest power of two such that the fragment fits in the budget given the size of the element type; for instance, `SplitVec::<[u8; 4096], _>::with_byte_budget_growth(64 * 1024)` creates fragments with capacity 16, while `SplitVec::<u8, _>::with_byte_budget_growth(64 * 1024)` creates fragments with capacity 65536.
+//!
+//! `CappedDoubling` strategy doubles the fragment capacities starting from 4, as the `Doubling` strategy, until they reach a maximum fragment capacity of `2^M`; all following fragments have the constant capacity `2^M`, as in the `Linear` strategy. For instance, `SplitVec::with_capped_doubling_growth(20)` never allocates a fragment with more than 1,048,576 elements. It keeps the constant time random access of both strategies.
+//!
+//! `Geometric` strategy generalizes the doubling behavior to an arbitrary growth factor `r >= 1.01`: the first fragment has a given capacity `C` and each following fragment has `r` times the capacity of the prior fragment, rounded up; for instance, `SplitVec::with_geometric_growth(4, 1.5)` creates fragments with capacities 4, 6, 9, 14, 21, etc. Since fragment capacities are not powers of two, random access requires a binary search over the cumulative capacities of the fragments, which are computed once when the strategy is created.
+//!
+//! In addition there exists the `Recursive` growth strategy, which behaves as the `Doubling` strategy at the beginning. However, it allows for zero-cost `append` operation at the expense of a reduced random access time performance. `Recursive` maintains a cumulative length index of its fragments, which allows it to locate an element by a binary search in O(log(f)) time where f is the number of fragments.
+//!
+//! Growth strategies which allow for constant time random access additionally implement the `GrowthWithConstantTimeAccess` trait, which are currently `Doubling`, `DoublingFrom`, `CappedDoubling`, `Linear`, `ConstLinear`, `LinearN` and `ByteBudget` strategies.
 //!
 //! Please see the <a href="#section-benchmarks">E. Benchmarks</a> section for tradeoffs and details. The summary is as follows:
 //!
@@ -147,17 +159,17 @@
 //! let slice = vec.try_get_slice(3..7);
 //! assert_eq!(slice, SplitVecSlice::OutOfBounds);
 //!
-//! // or the slice can be obtained as a vector of slices
-//! let slices = vec.slices(0..3);
+//! // or the range can be obtained as an iterator of slices
+//! let slices: Vec<_> = vec.slices_iter(0..3).collect();
 //! assert_eq!(1, slices.len());
 //! assert_eq!(slices[0], &[0, 1, 2]);
 //!
-//! let slices = vec.slices(3..5);
+//! let slices: Vec<_> = vec.slices_iter(3..5).collect();
 //! assert_eq!(2, slices.len());
 //! assert_eq!(slices[0], &[3]);
 //! assert_eq!(slices[1], &[4]);
 //!
-//! let slices = vec.slices(0..vec.len());
+//! let slices: Vec<_> = vec.slices_iter(0..vec.len()).collect();
 //! assert_eq!(2, slices.len());
 //! assert_eq!(slices[0], &[0, 1, 2, 3]);
 //! assert_eq!(slices[1], &[4]);
@@ -203,6 +215,34 @@
 //! assert_eq!(unsafe { *addr42 }, 42);
 //! ```
 //!
+//! ### D.4. Parallel Iteration
+//!
+//! With the optional `rayon` feature, `SplitVec` implements rayon's `IntoParallelIterator` for owned and borrowed vectors; hence, `par_iter`, `par_iter_mut` and `into_par_iter` are available. Fragments are natural independent chunks: the work is split first on the fragment boundaries and then within the fragments. Further, `SplitVec` can be collected from and extended by parallel iterators; elements are collected into per-thread vectors which are then appended as fragments, without copies when the growth strategy allows as in `Recursive`.
+//!
+//! ### D.5. `no_std` Support
+//!
+//! The crate is `no_std` compatible and requires only the `alloc` crate when the default `std` feature is disabled. `SplitVec` and `ConcurrentSplitVec` are fully available without `std`; the `std` feature only adds the `std::error::Error` implementation of `SplitVecError`. The `rayon` feature requires `std`.
+//!
+//! ```toml
+//! orx-split-vec = { version = "4.0", default-features = false }
+//! ```
+//!
+//! ### D.6. Custom Allocators
+//!
+//! Memory of the fragments can be allocated by a custom allocator, such as an arena, bump or NUMA-aware allocator, which implements the `Allocator` trait of the [allocator-api2](https://crates.io/crates/allocator-api2) crate on stable Rust. The allocator is the third generic parameter of the split vector, `SplitVec<T, G, A>`, which is the global allocator by default. Split vectors using a custom allocator are created by the `new_in` and `with_growth_in` constructors. The allocator is cloned for every new fragment and it is carried over to the `ConcurrentSplitVec` created by `into_concurrent`. The vector operations, such as `push`, `insert`, `pop` or `truncate`, only require the allocator to be `Clone`; hence, borrowed allocators such as `&Bump` can be used. The `PinnedVec` implementation additionally requires the allocator to be `Default` since it creates vectors through `PseudoDefault`.
+//!
+//! ```rust
+//! use orx_split_vec::*;
+//!
+//! let mut vec: SplitVec<i32, Doubling, Global> = SplitVec::new_in(Global);
+//! vec.extend_from_slice(&[0, 1, 2, 3, 4]);
+//! assert_eq!(vec, &[0, 1, 2, 3, 4]);
+//!
+//! let mut vec = SplitVec::with_growth_in(Recursive::pseudo_default(), Global);
+//! vec.push('a');
+//! assert_eq!(vec, &['a']);
+//! ```
+//!
 //! <div id="section-benchmarks"></div>
 //!
 //! ## E. Benchmarks
@@ -229,7 +269,7 @@
 //!
 //! In this benchmark, we access vector elements by indices in a random order. Here the baseline is again the standard vector created by `Vec::with_capacity`, which is compared with `Linear` and `Doubling` growth strategies of the `SplitVec` which are optimized specifically for the random access. Furthermore, `Recursive` growth strategy which does not provide constant time random access operation is included in the benchmarks.
 //!
-//! Note that `Recursive` uses the `Growth` trait's default `get_fragment_and_inner_indices` implementation, and hence, reflects the expected random access performance of custom growth strategies without a specialized access method.
+//! Note that the results below were obtained when `Recursive` used the `Growth` trait's default `get_fragment_and_inner_indices` implementation, and hence, reflect the expected random access performance of custom growth strategies without a specialized access method. `Recursive` now locates elements by a binary search over its cumulative length index, which is logarithmic rather than linear in the number of fragments.
 //!
 //! <img src="https://raw.githubusercontent.com/orxfun/orx-split-vec/main/docs/img/bench_random_access.PNG" alt="https://raw.githubusercontent.com/orxfun/orx-split-vec/main/docs/img/bench_random_access.PNG" />
 //!
@@ -266,6 +306,7 @@
 //!
 //! This library is licensed under MIT license. See LICENSE for details.
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![warn(
     missing_docs,
     clippy::unwrap_in_result,
@@ -278,39 +319,63 @@
     clippy::todo
 )]
 
+extern crate alloc;
+
 mod algorithms;
+mod append;
 mod common_traits;
 mod concurrent_pinned_vec;
+mod dedup;
+mod error;
 mod fragment;
 mod growth;
+mod inherent;
 mod into_concurrent_pinned_vec;
 mod new_split_vec;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod pinned_vec;
 mod range_helpers;
+mod recycle;
 mod resize_multiple;
+mod retain;
 mod slice;
+mod sort;
+mod split_off;
 mod split_vec;
 #[cfg(test)]
 pub(crate) mod test;
+mod try_reserve;
+mod view;
 
 /// Common relevant traits, structs, enums.
 pub mod prelude;
 
+pub use allocator_api2::alloc::{Allocator, Global};
 pub use common_traits::iterator::{
-    into_iter::IntoIter, iter::Iter, iter_mut::IterMut, iter_mut_rev::IterMutRev, iter_rev::IterRev,
+    drain::Drain, into_iter::IntoIter, iter::Iter, iter_mut::IterMut, iter_mut_rev::IterMutRev,
+    iter_rev::IterRev, slices_iter::SlicesIter, slices_iter_mut::SlicesIterMut,
 };
 pub use concurrent_pinned_vec::ConcurrentSplitVec;
+pub use error::SplitVecError;
 pub use fragment::fragment_struct::Fragment;
 pub use fragment::into_fragments::IntoFragments;
 pub use growth::{
-    doubling::Doubling,
+    byte_budget::ByteBudget,
+    capped_doubling::CappedDoubling,
+    doubling::{Doubling, DoublingFrom},
+    geometric::Geometric,
     growth_trait::{Growth, GrowthWithConstantTimeAccess},
-    linear::Linear,
+    linear::{ConstLinear, Linear, LinearN},
     recursive::Recursive,
 };
+pub use new_split_vec::default::NewWithDefaultGrowth;
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
 };
 pub use orx_pseudo_default::PseudoDefault;
+#[cfg(feature = "rayon")]
+pub use parallel::{into_par_iter::IntoParIter, par_iter::ParIter, par_iter_mut::ParIterMut};
 pub use slice::SplitVecSlice;
 pub use split_vec::SplitVec;
+pub use view::{split_vec_view::SplitVecView, split_vec_view_mut::SplitVecViewMut};
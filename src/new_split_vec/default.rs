@@ -1,11 +1,55 @@
 use crate::{Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
 
-impl<T, G> Default for SplitVec<T, G>
+impl<T, G, A> Default for SplitVec<T, G, A>
 where
     G: Growth + Default,
+    A: Allocator + Clone + Default,
 {
     /// Creates an empty split vector with the default `FragmentGrowth` strategy.
     fn default() -> Self {
-        Self::with_growth(G::default())
+        Self::with_growth_in(G::default(), A::default())
+    }
+}
+
+/// Provides the `new` constructor for split vectors with growth strategies implementing `Default`,
+/// such as `SplitVec::<T, ConstLinear<10>>::new()`.
+///
+/// The inherent [`SplitVec::new`] of the default [`Doubling`] growth takes precedence over this trait;
+/// therefore, `SplitVec::new()` creates a vector with doubling growth when the growth is not specified.
+///
+/// [`Doubling`]: crate::Doubling
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::<char, ConstLinear<4>>::new();
+/// assert_eq!(vec.fragments()[0].capacity(), 16);
+///
+/// vec.extend(core::iter::repeat('x').take(40));
+/// assert_eq!(vec.fragments().len(), 3);
+/// assert!(vec.fragments().iter().all(|f| f.capacity() == 16));
+///
+/// let vec = SplitVec::<char, Recursive>::new();
+/// assert!(vec.is_empty());
+///
+/// let vec = SplitVec::new();
+/// assert_eq!(vec.growth(), &Doubling);
+/// # let _: &SplitVec<char> = &vec;
+/// ```
+pub trait NewWithDefaultGrowth {
+    /// Creates an empty split vector with the default value of its growth strategy.
+    fn new() -> Self;
+}
+
+impl<T, G, A> NewWithDefaultGrowth for SplitVec<T, G, A>
+where
+    G: Growth + Default,
+    A: Allocator + Clone + Default,
+{
+    fn new() -> Self {
+        Self::default()
     }
 }
@@ -1,5 +1,5 @@
 use crate::{Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
+use alloc::vec::Vec;
 
 // std::vec::vec
 impl<T, G> From<SplitVec<T, G>> for Vec<T>
@@ -39,14 +39,14 @@ where
             value
                 .fragments
                 .into_iter()
-                .map(|x| x.data)
+                .map(Vec::from)
                 .next()
                 .expect("There exists exactly one fragment")
         } else {
             let mut vec = Vec::with_capacity(value.len());
             vec.reserve(value.len());
-            for f in &mut value.fragments {
-                vec.append(&mut f.data);
+            for f in value.fragments.drain(..) {
+                vec.append(&mut f.into());
             }
             vec
         }
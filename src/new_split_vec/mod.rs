@@ -1,3 +1,3 @@
-mod default;
+pub(crate) mod default;
 mod into;
 mod new;
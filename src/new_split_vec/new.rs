@@ -1,4 +1,6 @@
-use crate::{Fragment, Growth, SplitVec};
+use crate::{Doubling, Fragment, Growth, SplitVec};
+use alloc::vec;
+use allocator_api2::alloc::{Allocator, Global};
 
 impl<T> SplitVec<T> {
     /// Creates an empty split vector with default growth strategy.
@@ -20,6 +22,30 @@ impl<T> SplitVec<T> {
     }
 }
 
+impl<T, A> SplitVec<T, Doubling, A>
+where
+    A: Allocator + Clone,
+{
+    /// Creates an empty split vector with default growth strategy, where the fragments are allocated by `alloc`.
+    ///
+    /// Default growth strategy is `Doubling` with initial capacity of 4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<f32, Doubling, Global> = SplitVec::new_in(Global);
+    /// vec.push(42.0);
+    ///
+    /// assert_eq!(1, vec.fragments().len());
+    /// assert_eq!(4, vec.fragments()[0].capacity());
+    /// ```
+    pub fn new_in(alloc: A) -> Self {
+        Self::with_growth_in(Doubling, alloc)
+    }
+}
+
 impl<T, G> SplitVec<T, G>
 where
     G: Growth,
@@ -75,10 +101,34 @@ where
     /// assert_eq!(1, vec.fragments()[2].len());
     /// ```
     pub fn with_growth(growth: G) -> Self {
-        let capacity = Growth::new_fragment_capacity::<T>(&growth, &[]);
-        let fragment = Fragment::new(capacity);
+        Self::with_growth_in(growth, Global)
+    }
+}
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Creates an empty split vector with the given `growth` strategy, where the fragments are allocated by `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_, Doubling, Global> = SplitVec::with_growth_in(Doubling, Global);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(2, vec.fragments().len());
+    /// assert_eq!(4, vec.fragments()[0].capacity());
+    /// assert_eq!(8, vec.fragments()[1].capacity());
+    /// ```
+    pub fn with_growth_in(growth: G, alloc: A) -> Self {
+        let capacity = Growth::new_fragment_capacity::<T, A>(&growth, &[]);
+        let fragment = Fragment::new_in(capacity, alloc.clone());
         let fragments = vec![fragment];
-        SplitVec::from_raw_parts(0, fragments, growth)
+        SplitVec::from_raw_parts_in(0, fragments, growth, alloc)
     }
 }
 
@@ -0,0 +1,126 @@
+use crate::{Fragment, Growth, SplitVec};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
+use rayon::prelude::*;
+
+impl<T, G, A> ParallelExtend<T> for SplitVec<T, G, A>
+where
+    T: Send,
+    G: Growth,
+    A: Allocator + Clone + Send + Sync,
+{
+    /// Extends the vector with elements of the parallel iterator.
+    ///
+    /// Elements are first collected into per-thread vectors allocated by the allocator of this vector,
+    /// which are then appended to this vector as in [`SplitVec::append`]; hence, they are adopted as fragments
+    /// as they are whenever the growth strategy allows, as is always the case with the [`Recursive`](crate::Recursive) growth.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let allocator = &self.allocator;
+        let vectors: Vec<_> = par_iter
+            .into_par_iter()
+            .fold(
+                || allocator_api2::vec::Vec::new_in(allocator.clone()),
+                |mut vec, x| {
+                    vec.push(x);
+                    vec
+                },
+            )
+            .collect();
+        let fragments = vectors
+            .into_iter()
+            .filter(|x| !x.is_empty())
+            .map(Fragment::from);
+        self.append_fragments(fragments);
+    }
+}
+
+impl<'a, T, G, A> ParallelExtend<&'a T> for SplitVec<T, G, A>
+where
+    T: Clone + Send + Sync + 'a,
+    G: Growth,
+    A: Allocator + Clone + Send + Sync,
+{
+    /// Extends the vector with clones of the elements of the parallel iterator.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = &'a T>,
+    {
+        self.par_extend(par_iter.into_par_iter().cloned())
+    }
+}
+
+impl<T, G, A> FromParallelIterator<T> for SplitVec<T, G, A>
+where
+    T: Send,
+    G: Growth,
+    A: Allocator + Clone + Send + Sync,
+    SplitVec<T, G, A>: Default,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let mut vec = Self::default();
+        vec.par_extend(par_iter);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_extend() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..10);
+            vec.par_extend((10..5000).into_par_iter());
+            vec.par_extend((5000..7000).into_par_iter().filter(|x| x % 2 == 0));
+            vec.par_extend(vec![7000, 7001].par_iter());
+
+            let expected: Vec<_> = (0..5000)
+                .chain((5000..7000).filter(|x| x % 2 == 0))
+                .chain([7000, 7001])
+                .collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn collect() {
+        let vec: SplitVec<_> = (0..10_000).into_par_iter().map(|x| x * 2).collect();
+        assert_eq!(
+            vec,
+            (0..10_000).map(|x| x * 2).collect::<Vec<_>>().as_slice()
+        );
+
+        let vec: SplitVec<_, Recursive> =
+            (0..10_000).into_par_iter().filter(|x| x % 3 == 0).collect();
+        assert_eq!(
+            vec,
+            (0..10_000)
+                .filter(|x| x % 3 == 0)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+
+        let vec: SplitVec<usize, ConstLinear<4>> = (0..0).into_par_iter().collect();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn collect_large() {
+        let vec: SplitVec<_, Recursive> = (0..100_000).into_par_iter().collect();
+        assert_eq!(vec.len(), 100_000);
+
+        let num_elements: usize = vec.fragments().iter().map(|x| x.len()).sum();
+        assert_eq!(num_elements, 100_000);
+        assert!(vec.iter().copied().eq(0..100_000));
+    }
+}
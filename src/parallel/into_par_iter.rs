@@ -0,0 +1,151 @@
+use super::producer::FragmentsDrainProducer;
+use crate::{Fragment, Growth, SplitVec, SplitVecViewMut};
+use allocator_api2::alloc::{Allocator, Global};
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::prelude::*;
+
+/// Parallel iterator consuming the `SplitVec` and yielding its elements.
+///
+/// This struct is created by the `into_par_iter()` method of the [`SplitVec`] which is available when the `rayon` feature is enabled.
+///
+/// The work is split first on the fragment boundaries and then within the fragments.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+/// use rayon::prelude::*;
+///
+/// let mut vec = SplitVec::with_recursive_growth();
+/// vec.append(vec![String::from("a"), String::from("b")]);
+/// vec.append(vec![String::from("c")]);
+///
+/// let upper: Vec<String> = vec.into_par_iter().map(|x| x.to_uppercase()).collect();
+/// assert_eq!(upper, ["A", "B", "C"]);
+/// ```
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoParIter<T, A: Allocator = Global> {
+    fragments: Vec<Fragment<T, A>>,
+    len: usize,
+}
+
+impl<T: Send, A: Allocator + Send> ParallelIterator for IntoParIter<T, A> {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<T: Send, A: Allocator + Send> IndexedParallelIterator for IntoParIter<T, A> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let mut fragments = MovedFragments(self.fragments);
+        callback.callback(FragmentsDrainProducer(SplitVecViewMut::new(
+            &mut [],
+            &mut fragments.0,
+            &mut [],
+        )))
+    }
+}
+
+/// Fragments whose elements are moved out by a [`FragmentsDrainProducer`];
+/// only the memory of the fragments is released when dropped.
+struct MovedFragments<T, A: Allocator>(Vec<Fragment<T, A>>);
+
+impl<T, A: Allocator> Drop for MovedFragments<T, A> {
+    fn drop(&mut self) {
+        for fragment in &mut self.0 {
+            // SAFETY: elements are already dropped or moved out by the producer
+            unsafe { fragment.set_len(0) };
+        }
+    }
+}
+
+impl<T: Send, G: Growth, A: Allocator + Send> IntoParallelIterator for SplitVec<T, G, A> {
+    type Iter = IntoParIter<T, A>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter {
+            fragments: self.fragments,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn into_par_iter() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            for len in [0, 1, 4, 5, 33, 10_000] {
+                vec.clear();
+                vec.extend((0..len).map(|x| x.to_string()));
+
+                let collected: Vec<_> = vec.clone().into_par_iter().collect();
+                assert_eq!(
+                    collected,
+                    (0..len).map(|x| x.to_string()).collect::<Vec<_>>()
+                );
+
+                let numbers: usize = vec
+                    .clone()
+                    .into_par_iter()
+                    .filter_map(|x| x.parse::<usize>().ok())
+                    .sum();
+                assert_eq!(numbers, (0..len).sum());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn into_par_iter_partially_consumed() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            for len in [0, 1, 4, 5, 33, 10_000] {
+                vec.clear();
+                vec.extend((0..len).map(|x| x.to_string()));
+
+                let found = vec.clone().into_par_iter().find_any(|x| x == "3").is_some();
+                assert_eq!(found, len > 3);
+
+                let collected: Vec<_> = vec.clone().into_par_iter().skip(2).take(3).collect();
+                assert_eq!(
+                    collected,
+                    (0..len)
+                        .skip(2)
+                        .take(3)
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                );
+
+                let rev: Vec<_> = vec.clone().into_par_iter().rev().collect();
+                assert_eq!(
+                    rev,
+                    (0..len).rev().map(|x| x.to_string()).collect::<Vec<_>>()
+                );
+            }
+        }
+        test_all_growth_types!(test);
+    }
+}
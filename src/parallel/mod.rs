@@ -0,0 +1,5 @@
+mod from_par_iter;
+pub(crate) mod into_par_iter;
+pub(crate) mod par_iter;
+pub(crate) mod par_iter_mut;
+mod producer;
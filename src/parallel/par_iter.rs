@@ -0,0 +1,155 @@
+use super::producer::FragmentsProducer;
+use crate::{Fragment, Growth, SplitVec, SplitVecView};
+use allocator_api2::alloc::{Allocator, Global};
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::prelude::*;
+
+/// Parallel iterator over references to the elements of the `SplitVec`.
+///
+/// This struct is created by the `par_iter()` method of the [`SplitVec`] which is available when the `rayon` feature is enabled.
+///
+/// The work is split first on the fragment boundaries and then within the fragments.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+/// use rayon::prelude::*;
+///
+/// let mut vec = SplitVec::with_linear_growth(4);
+/// vec.extend(0..1000);
+///
+/// let sum: usize = vec.par_iter().map(|x| x * 2).sum();
+/// assert_eq!(sum, 999_000);
+/// ```
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParIter<'a, T, A: Allocator = Global> {
+    fragments: &'a [Fragment<T, A>],
+    len: usize,
+}
+
+impl<'a, T, A: Allocator> Clone for ParIter<'a, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            fragments: self.fragments,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T: Sync, A: Allocator + Sync> ParallelIterator for ParIter<'a, T, A> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.fragments
+            .par_iter()
+            .flat_map(|fragment| fragment[..].par_iter())
+            .drive_unindexed(consumer)
+    }
+}
+
+impl<'a, T: Sync, A: Allocator + Sync> IndexedParallelIterator for ParIter<'a, T, A> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(FragmentsProducer(SplitVecView::new(
+            &[],
+            self.fragments,
+            &[],
+        )))
+    }
+}
+
+impl<'a, T: Sync, G: Growth, A: Allocator + Sync> IntoParallelIterator for &'a SplitVec<T, G, A> {
+    type Iter = ParIter<'a, T, A>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter {
+            fragments: &self.fragments,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_iter() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for len in [0, 1, 4, 5, 33, 10_000] {
+                vec.clear();
+                vec.extend(0..len);
+
+                let sum: usize = vec.par_iter().sum();
+                assert_eq!(sum, (0..len).sum());
+
+                let evens: Vec<_> = vec.par_iter().filter(|x| *x % 2 == 0).copied().collect();
+                assert_eq!(evens, (0..len).filter(|x| x % 2 == 0).collect::<Vec<_>>());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn par_iter_indexed() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for len in [0, 1, 4, 5, 33, 10_000] {
+                vec.clear();
+                vec.extend(0..len);
+                assert_eq!(vec.par_iter().len(), len);
+
+                let mut collected = vec![];
+                vec.par_iter()
+                    .map(|x| x * 2)
+                    .collect_into_vec(&mut collected);
+                assert_eq!(collected, (0..len).map(|x| x * 2).collect::<Vec<_>>());
+
+                assert!(vec.par_iter().enumerate().all(|(i, x)| i == *x));
+
+                let rev: Vec<_> = vec.par_iter().rev().copied().collect();
+                assert_eq!(rev, (0..len).rev().collect::<Vec<_>>());
+
+                let zipped = vec
+                    .par_iter()
+                    .zip(vec.par_iter().skip(1))
+                    .all(|(a, b)| a + 1 == *b);
+                assert!(zipped);
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn par_iter_with_small_chunks() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(Vec::<usize>::new());
+        vec.append(vec![3]);
+        vec.append((4..1000).collect::<Vec<_>>());
+
+        for min_len in [1, 2, 3, 7] {
+            let collected: Vec<_> = vec
+                .par_iter()
+                .with_min_len(min_len)
+                .with_max_len(min_len)
+                .copied()
+                .collect();
+            assert_eq!(collected, (0..1000).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::producer::FragmentsProducerMut;
+use crate::{Fragment, Growth, SplitVec, SplitVecViewMut};
+use allocator_api2::alloc::{Allocator, Global};
+use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::prelude::*;
+
+/// Parallel iterator over mutable references to the elements of the `SplitVec`.
+///
+/// This struct is created by the `par_iter_mut()` method of the [`SplitVec`] which is available when the `rayon` feature is enabled.
+///
+/// The work is split first on the fragment boundaries and then within the fragments.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+/// use rayon::prelude::*;
+///
+/// let mut vec = SplitVec::with_doubling_growth();
+/// vec.extend(0..100);
+///
+/// vec.par_iter_mut().for_each(|x| *x *= 10);
+/// assert_eq!(vec[42], 420);
+/// ```
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParIterMut<'a, T, A: Allocator = Global> {
+    fragments: &'a mut [Fragment<T, A>],
+    len: usize,
+}
+
+impl<'a, T: Send, A: Allocator + Send> ParallelIterator for ParIterMut<'a, T, A> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.fragments
+            .par_iter_mut()
+            .flat_map(|fragment| fragment[..].par_iter_mut())
+            .drive_unindexed(consumer)
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Send> IndexedParallelIterator for ParIterMut<'a, T, A> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(FragmentsProducerMut(SplitVecViewMut::new(
+            &mut [],
+            self.fragments,
+            &mut [],
+        )))
+    }
+}
+
+impl<'a, T: Send, G: Growth, A: Allocator + Send> IntoParallelIterator
+    for &'a mut SplitVec<T, G, A>
+{
+    type Iter = ParIterMut<'a, T, A>;
+    type Item = &'a mut T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIterMut {
+            fragments: &mut self.fragments,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_iter_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for len in [0, 1, 4, 5, 33, 10_000] {
+                vec.clear();
+                vec.extend(0..len);
+
+                vec.par_iter_mut().for_each(|x| *x *= 2);
+                assert_eq!(vec, (0..len).map(|x| x * 2).collect::<Vec<_>>().as_slice());
+
+                vec.par_iter_mut()
+                    .enumerate()
+                    .filter(|(i, _)| i % 3 == 0)
+                    .for_each(|(_, x)| *x = 0);
+                let expected: Vec<_> = (0..len)
+                    .map(|x| if x % 3 == 0 { 0 } else { x * 2 })
+                    .collect();
+                assert_eq!(vec, expected.as_slice());
+
+                vec.par_iter_mut()
+                    .zip(expected.par_iter())
+                    .for_each(|(x, y)| *x += y);
+                let expected: Vec<_> = expected.iter().map(|x| x * 2).collect();
+                assert_eq!(vec, expected.as_slice());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+}
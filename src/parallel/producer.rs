@@ -0,0 +1,116 @@
+use crate::{Iter, IterMut, SplitVecView, SplitVecViewMut};
+use allocator_api2::alloc::{Allocator, Global};
+use core::ptr;
+use rayon::iter::plumbing::Producer;
+
+/// Producer of references to the elements of a split vector, which is split inside fragments without allocation
+/// as a [`SplitVecView`].
+pub(crate) struct FragmentsProducer<'a, T, A: Allocator = Global>(
+    pub(crate) SplitVecView<'a, T, A>,
+);
+
+impl<'a, T: Sync, A: Allocator + Sync> Producer for FragmentsProducer<'a, T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at(index);
+        (Self(left), Self(right))
+    }
+}
+
+/// Producer of mutable references to the elements of a split vector, which is split inside fragments without allocation
+/// as a [`SplitVecViewMut`].
+pub(crate) struct FragmentsProducerMut<'a, T, A: Allocator = Global>(
+    pub(crate) SplitVecViewMut<'a, T, A>,
+);
+
+impl<'a, T: Send, A: Allocator + Send> Producer for FragmentsProducerMut<'a, T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_into(index);
+        (Self(left), Self(right))
+    }
+}
+
+/// Producer of the elements moved out of the fragments of a split vector, which is split inside fragments without allocation
+/// as a [`SplitVecViewMut`].
+///
+/// The fragments must not drop the elements of the view afterwards; elements which are not yielded are dropped
+/// by the producer or by its iterator.
+pub(crate) struct FragmentsDrainProducer<'a, T, A: Allocator = Global>(
+    pub(crate) SplitVecViewMut<'a, T, A>,
+);
+
+impl<'a, T, A: Allocator> FragmentsDrainProducer<'a, T, A> {
+    fn take(&mut self) -> SplitVecViewMut<'a, T, A> {
+        core::mem::replace(&mut self.0, SplitVecViewMut::new(&mut [], &mut [], &mut []))
+    }
+}
+
+impl<'a, T, A: Allocator> Drop for FragmentsDrainProducer<'a, T, A> {
+    fn drop(&mut self) {
+        for x in self.0.iter_mut() {
+            // SAFETY: elements of the view are owned by the producer and never read again
+            unsafe { ptr::drop_in_place(x) };
+        }
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Send> Producer for FragmentsDrainProducer<'a, T, A> {
+    type Item = T;
+    type IntoIter = Drain<'a, T, A>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        Drain(self.take().into_iter())
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let (left, right) = self.take().split_into(index);
+        (Self(left), Self(right))
+    }
+}
+
+/// Iterator moving the elements out of the fragments of a split vector, dropping the elements which are not yielded.
+pub(crate) struct Drain<'a, T, A: Allocator = Global>(IterMut<'a, T, A>);
+
+impl<'a, T, A: Allocator> Iterator for Drain<'a, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: each element is yielded once and never read again
+        self.0.next().map(|x| unsafe { ptr::read(x) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T, A: Allocator> DoubleEndedIterator for Drain<'a, T, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: each element is yielded once and never read again
+        self.0.next_back().map(|x| unsafe { ptr::read(x) })
+    }
+}
+
+impl<'a, T, A: Allocator> ExactSizeIterator for Drain<'a, T, A> {}
+
+impl<'a, T, A: Allocator> Drop for Drain<'a, T, A> {
+    fn drop(&mut self) {
+        for x in &mut self.0 {
+            // SAFETY: remaining elements are not yielded and never read again
+            unsafe { ptr::drop_in_place(x) };
+        }
+    }
+}
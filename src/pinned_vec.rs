@@ -1,28 +1,30 @@
 use crate::fragment::fragment_struct::set_fragments_len;
-use crate::range_helpers::{range_end, range_start};
 use crate::{algorithms, Fragment, Growth, SplitVec};
+use alloc::vec;
+use allocator_api2::alloc::Allocator;
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
 use orx_pinned_vec::utils::slice;
 use orx_pinned_vec::{CapacityState, PinnedVec};
 use orx_pseudo_default::PseudoDefault;
-use std::cmp::Ordering;
-use std::ops::RangeBounds;
 
-impl<T, G: Growth> PseudoDefault for SplitVec<T, G> {
+impl<T, G: Growth, A: Allocator + Clone + Default> PseudoDefault for SplitVec<T, G, A> {
     fn pseudo_default() -> Self {
         let growth = G::pseudo_default();
         let capacity = growth.first_fragment_capacity();
-        let fragments = vec![Fragment::new(capacity)];
-        Self::from_raw_parts(0, fragments, growth)
+        let allocator = A::default();
+        let fragments = vec![Fragment::new_in(capacity, allocator.clone())];
+        Self::from_raw_parts_in(0, fragments, growth, allocator)
     }
 }
 
-impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
-    type Iter<'a> = crate::common_traits::iterator::iter::Iter<'a, T> where T: 'a, Self: 'a;
-    type IterMut<'a> = crate::common_traits::iterator::iter_mut::IterMut<'a, T> where T: 'a, Self: 'a;
-    type IterRev<'a> = crate::common_traits::iterator::iter_rev::IterRev<'a, T> where T: 'a, Self: 'a;
-    type IterMutRev<'a> = crate::common_traits::iterator::iter_mut_rev::IterMutRev<'a, T> where T: 'a, Self: 'a;
-    type SliceIter<'a> = Vec<&'a [T]> where T: 'a, Self: 'a;
-    type SliceMutIter<'a> = Vec<&'a mut [T]> where T: 'a, Self: 'a;
+impl<T, G: Growth, A: Allocator + Clone + Default> PinnedVec<T> for SplitVec<T, G, A> {
+    type Iter<'a> = crate::common_traits::iterator::iter::Iter<'a, T, A, G> where T: 'a, Self: 'a;
+    type IterMut<'a> = crate::common_traits::iterator::iter_mut::IterMut<'a, T, A, G> where T: 'a, Self: 'a;
+    type IterRev<'a> = crate::common_traits::iterator::iter_rev::IterRev<'a, T, A> where T: 'a, Self: 'a;
+    type IterMutRev<'a> = crate::common_traits::iterator::iter_mut_rev::IterMutRev<'a, T, A> where T: 'a, Self: 'a;
+    type SliceIter<'a> = crate::common_traits::iterator::slices_iter::SlicesIter<'a, T, A> where T: 'a, Self: 'a;
+    type SliceMutIter<'a> = crate::common_traits::iterator::slices_iter_mut::SlicesIterMut<'a, T, A> where T: 'a, Self: 'a;
 
     /// Returns the index of the `element` with the given reference.
     /// This method has *O(f)* time complexity where `f << vec.len()` is the number of fragments.
@@ -129,31 +131,8 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
             .any(|fragment| slice::contains_reference(&fragment.data, element))
     }
 
-    /// Returns the total number of elements the split vector can hold without
-    /// reallocating.
-    ///
-    /// See `FragmentGrowth` for details of capacity growth policies.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// // default growth starting with 4, and doubling at each new fragment.
-    /// let mut vec = SplitVec::with_doubling_growth();
-    /// assert_eq!(4, vec.capacity());
-    ///
-    /// for i in 0..4 {
-    ///     vec.push(i);
-    /// }
-    /// assert_eq!(4, vec.capacity());
-    ///
-    /// vec.push(4);
-    /// assert_eq!(4 + 8, vec.capacity());
-    ///
-    /// ```
     fn capacity(&self) -> usize {
-        self.fragments.iter().map(|f| f.capacity()).sum()
+        SplitVec::capacity(self)
     }
 
     fn capacity_state(&self) -> CapacityState {
@@ -163,117 +142,23 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         }
     }
 
-    /// Clears the vector, removing all values.
-    ///
-    /// This method:
-    /// * drops all fragments except for the first one, and
-    /// * clears the first fragment.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(5);
-    /// for _ in 0..10 {
-    ///     vec.push(4.2);
-    /// }
-    ///
-    /// vec.clear();
-    ///
-    /// assert!(vec.is_empty());
-    /// ```
     fn clear(&mut self) {
-        if !self.fragments.is_empty() {
-            self.fragments.truncate(1);
-            self.fragments[0].clear();
-        }
-        self.len = 0;
+        SplitVec::clear(self)
     }
 
-    /// Clones and appends all elements in a slice to the vec.
-    ///
-    /// Iterates over the slice `other`, clones each element, and then appends
-    /// it to this vector. The `other` slice is traversed in-order.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(4);
-    /// vec.push(1);
-    /// vec.push(2);
-    /// vec.push(3);
-    /// assert_eq!(vec, [1, 2, 3]);
-    ///
-    /// vec.extend_from_slice(&[4, 5, 6, 7]);
-    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
-    /// ```
     fn extend_from_slice(&mut self, other: &[T])
     where
         T: Clone,
     {
-        self.len += other.len();
-        let mut slice = other;
-        while !slice.is_empty() {
-            if !self.has_capacity_for_one() {
-                self.add_fragment();
-            }
-            let f = self.fragments.len() - 1;
-
-            let last = &mut self.fragments[f];
-            let available = last.room();
-
-            if available < slice.len() {
-                last.extend_from_slice(&slice[0..available]);
-                slice = &slice[available..];
-                self.add_fragment();
-            } else {
-                last.extend_from_slice(slice);
-                break;
-            }
-        }
+        SplitVec::extend_from_slice(self, other)
     }
 
-    /// Returns a reference to the element with the given `index`;
-    /// None if index is out of bounds.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(5);
-    /// vec.extend_from_slice(&[10, 40, 30]);
-    /// assert_eq!(Some(&40), vec.get(1));
-    /// assert_eq!(None, vec.get(3));
-    /// ```
     fn get(&self, index: usize) -> Option<&T> {
-        self.get_fragment_and_inner_indices(index)
-            .map(|(f, i)| unsafe { self.fragments.get_unchecked(f).get_unchecked(i) })
+        SplitVec::get(self, index)
     }
 
-    /// Returns a mutable reference to the element with the given `index`;
-    /// None if index is out of bounds.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(5);
-    /// vec.extend_from_slice(&[0, 1, 2]);
-    ///
-    /// if let Some(elem) = vec.get_mut(1) {
-    ///     *elem = 42;
-    /// }
-    ///
-    /// assert_eq!(vec, &[0, 42, 2]);
-    /// ```
     fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        self.get_fragment_and_inner_indices(index)
-            .map(|(f, i)| unsafe { self.fragments.get_unchecked_mut(f).get_unchecked_mut(i) })
+        SplitVec::get_mut(self, index)
     }
 
     /// Returns a reference to an element or sub-slice, without doing bounds checking.
@@ -300,52 +185,14 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         self.get_mut(index).expect("out-of-bounds")
     }
 
-    /// Returns a reference to the first element of the vector; returns None if the vector is empty.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::new();
-    /// assert!(vec.first().is_none());
-    ///
-    /// vec.push(42);
-    /// assert_eq!(Some(&42), vec.first());
-    ///
-    /// vec.push(864121);
-    /// assert_eq!(Some(&42), vec.first());
-    ///
-    /// vec.insert(0, 7);
-    /// assert_eq!(Some(&7), vec.first());
-    /// ```
     #[inline(always)]
     fn first(&self) -> Option<&T> {
-        self.fragments.first().and_then(|x| x.first())
+        SplitVec::first(self)
     }
 
-    /// Returns a reference to the last element of the vector; returns None if the vector is empty.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::new();
-    /// assert!(vec.last().is_none());
-    ///
-    /// vec.push(42);
-    /// assert_eq!(Some(&42), vec.last());
-    ///
-    /// vec.push(7);
-    /// assert_eq!(Some(&7), vec.last());
-    ///
-    /// vec.insert(0, 684321);
-    /// assert_eq!(Some(&7), vec.last());
-    /// ```
     #[inline(always)]
     fn last(&self) -> Option<&T> {
-        self.fragments.last().and_then(|x| x.last())
+        SplitVec::last(self)
     }
 
     #[inline(always)]
@@ -360,181 +207,43 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     }
 
     fn insert(&mut self, index: usize, value: T) {
-        if index == self.len {
-            self.push(value);
-        } else {
-            // make room for one
-            if !self.has_capacity_for_one() {
-                self.add_fragment();
-            }
-
-            let (f, i) = self
-                .get_fragment_and_inner_indices(index)
-                .expect("out-of-bounds");
-
-            if self.fragments[f].has_capacity_for_one() {
-                self.fragments[f].insert(i, value);
-            } else {
-                let mut popped = self.fragments[f].pop().expect("no-way!");
-                self.fragments[f].insert(i, value);
-                let mut f = f;
-                loop {
-                    f += 1;
-
-                    if self.fragments[f].has_capacity_for_one() {
-                        self.fragments[f].insert(0, popped);
-                        break;
-                    } else {
-                        let new_popped = self.fragments[f].pop().expect("no-way");
-                        self.fragments[f].insert(0, popped);
-                        popped = new_popped;
-                    }
-                }
-            }
-            self.len += 1;
-        }
+        SplitVec::insert(self, index, value)
     }
 
-    /// Returns `true` if the vector contains no elements.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(2);
-    /// assert!(vec.is_empty());
-    /// vec.push(1);
-    /// assert!(!vec.is_empty());
-    /// ```
     fn is_empty(&self) -> bool {
-        self.len == 0
+        SplitVec::is_empty(self)
     }
 
-    /// Returns the number of elements in the vector, also referred to
-    /// as its 'length'.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec =  SplitVec::with_linear_growth(8);
-    /// assert_eq!(0, vec.len());
-    /// vec.push(1);
-    /// vec.push(2);
-    /// vec.push(3);
-    /// assert_eq!(3, vec.len());
-    /// ```
     fn len(&self) -> usize {
-        self.len
+        SplitVec::len(self)
     }
 
     fn pop(&mut self) -> Option<T> {
-        if self.fragments.is_empty() {
-            None
-        } else {
-            let f = self.fragments.len() - 1;
-            if self.fragments[f].is_empty() {
-                if f == 0 {
-                    None
-                } else {
-                    self.len -= 1;
-                    self.fragments.pop();
-                    self.fragments[f - 1].pop()
-                }
-            } else {
-                self.len -= 1;
-                let popped = self.fragments[f].pop();
-                if self.fragments[f].is_empty() {
-                    self.fragments.pop();
-                }
-                popped
-            }
-        }
+        SplitVec::pop(self)
     }
 
-    /// Appends an element to the back of a collection.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use orx_split_vec::*;
-    ///
-    /// let mut vec = SplitVec::with_linear_growth(16);
-    /// vec.push(1);
-    /// vec.push(2);
-    /// vec.push(3);
-    /// assert_eq!(vec, [1, 2, 3]);
-    /// ```
     fn push(&mut self, value: T) {
-        self.len += 1;
-        if self.has_capacity_for_one() {
-            let last_f = self.fragments.len() - 1;
-            self.fragments[last_f].push(value);
-            return;
-        }
-
-        self.add_fragment_with_first_value(value);
+        SplitVec::push(self, value)
     }
 
     fn remove(&mut self, index: usize) -> T {
-        self.drop_last_empty_fragment();
-
-        let (f, i) = self
-            .get_fragment_and_inner_indices(index)
-            .expect("out-of-bounds");
-
-        let value = self.fragments[f].remove(i);
-
-        for f2 in f + 1..self.fragments.len() {
-            let x = self.fragments[f2].remove(0);
-            self.fragments[f2 - 1].push(x);
-            if self.fragments[f2].is_empty() {
-                self.fragments.remove(f2);
-                break;
-            }
-        }
-
-        self.drop_last_empty_fragment();
-
-        self.len -= 1;
-        value
+        SplitVec::remove(self, index)
     }
 
     fn swap(&mut self, a: usize, b: usize) {
-        let (af, ai) = self
-            .get_fragment_and_inner_indices(a)
-            .expect("out-of-bounds");
-        let (bf, bi) = self
-            .get_fragment_and_inner_indices(b)
-            .expect("out-of-bounds");
-        if af == bf {
-            self.fragments[af].swap(ai, bi);
-        } else {
-            let ptr_a = unsafe { self.fragments[af].as_mut_ptr().add(ai) };
-            let ref_a = unsafe { &mut *ptr_a };
-            let ref_b = &mut self.fragments[bf][bi];
-            std::mem::swap(ref_a, ref_b);
-        }
+        SplitVec::swap(self, a, b)
     }
 
     fn truncate(&mut self, len: usize) {
-        if let Some((f, i)) = self.get_fragment_and_inner_indices(len) {
-            self.fragments.truncate(f + 1);
-            self.fragments[f].truncate(i);
-            self.len = len;
-
-            self.drop_last_empty_fragment();
-        }
+        SplitVec::truncate(self, len)
     }
 
     fn iter(&self) -> Self::Iter<'_> {
-        Self::Iter::new(&self.fragments)
+        SplitVec::iter(self)
     }
 
     fn iter_mut(&mut self) -> Self::IterMut<'_> {
-        Self::IterMut::new(&mut self.fragments)
+        SplitVec::iter_mut(self)
     }
 
     fn iter_rev(&self) -> Self::IterRev<'_> {
@@ -545,11 +254,13 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
         Self::IterMutRev::new(&mut self.fragments)
     }
 
-    /// Returns the view on the required `range` as a vector of slices:
+    /// Returns the view on the required `range` as an iterator of slices:
     ///
-    /// * returns an empty vector if the range is out of bounds;
-    /// * returns a vector with one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
-    /// * returns an ordered vector of slices when chained forms the required range.
+    /// * yields no slices if the range is out of bounds;
+    /// * yields one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
+    /// * yields the ordered slices which, when chained, form the required range.
+    ///
+    /// The iterator does not allocate; see also [`SplitVec::slices_iter`].
     ///
     /// # Examples
     ///
@@ -565,51 +276,30 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// assert_eq!(vec.fragments()[2], &[8, 9]);
     ///
     /// // single fragment
-    /// assert_eq!(vec![&[0, 1, 2, 3]], vec.slices(0..4));
-    /// assert_eq!(vec![&[5, 6]], vec.slices(5..7));
-    /// assert_eq!(vec![&[8, 9]], vec.slices(8..10));
+    /// assert_eq!(vec![&[0, 1, 2, 3]], vec.slices(0..4).collect::<Vec<_>>());
+    /// assert_eq!(vec![&[5, 6]], vec.slices(5..7).collect::<Vec<_>>());
+    /// assert_eq!(vec![&[8, 9]], vec.slices(8..10).collect::<Vec<_>>());
     ///
     /// // Fragmented
-    /// assert_eq!(vec![&vec![3], &vec![4, 5]], vec.slices(3..6));
-    /// assert_eq!(vec![&vec![3], &vec![4, 5, 6, 7], &vec![8]], vec.slices(3..9));
-    /// assert_eq!(vec![&vec![7], &vec![8]], vec.slices(7..9));
+    /// assert_eq!(vec![&[3][..], &[4, 5]], vec.slices(3..6).collect::<Vec<_>>());
+    /// assert_eq!(vec![&[3][..], &[4, 5, 6, 7], &[8]], vec.slices(3..9).collect::<Vec<_>>());
+    /// assert_eq!(vec![&[7], &[8]], vec.slices(7..9).collect::<Vec<_>>());
     ///
     /// // OutOfBounds
-    /// assert!(vec.slices(5..12).is_empty());
-    /// assert!(vec.slices(10..11).is_empty());
+    /// assert_eq!(vec.slices(5..12).len(), 0);
+    /// assert_eq!(vec.slices(10..11).len(), 0);
     /// ```
     fn slices<R: RangeBounds<usize>>(&self, range: R) -> Self::SliceIter<'_> {
-        let a = range_start(&range);
-        let b = range_end(&range, self.len());
-
-        match b.saturating_sub(a) {
-            0 => vec![],
-            _ => match self.get_fragment_and_inner_indices(a) {
-                None => vec![],
-                Some((sf, si)) => match self.get_fragment_and_inner_indices(b - 1) {
-                    None => vec![],
-                    Some((ef, ei)) => match sf.cmp(&ef) {
-                        Ordering::Equal => vec![&self.fragments[sf][si..=ei]],
-                        _ => {
-                            let mut vec = Vec::with_capacity(ef - sf + 1);
-                            vec.push(&self.fragments[sf][si..]);
-                            for f in sf + 1..ef {
-                                vec.push(&self.fragments[f]);
-                            }
-                            vec.push(&self.fragments[ef][..=ei]);
-                            vec
-                        }
-                    },
-                },
-            },
-        }
+        self.slices_iter(range)
     }
 
-    /// Returns a mutable view on the required `range` as a vector of slices:
+    /// Returns a mutable view on the required `range` as an iterator of slices:
     ///
-    /// * returns an empty vector if the range is out of bounds;
-    /// * returns a vector with one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
-    /// * returns an ordered vector of slices when chained forms the required range.
+    /// * yields no slices if the range is out of bounds;
+    /// * yields one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok),
+    /// * yields the ordered slices which, when chained, form the required range.
+    ///
+    /// The iterator does not allocate; see also [`SplitVec::slices_iter_mut`].
     ///
     /// # Examples
     ///
@@ -624,21 +314,21 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// assert_eq!(vec.fragments()[2], &[8, 9]);
     ///
     /// // single fragment
-    /// let mut slices = vec.slices_mut(0..4);
+    /// let mut slices: Vec<_> = vec.slices_mut(0..4).collect();
     /// assert_eq!(slices.len(), 1);
     /// assert_eq!(slices[0], &[0, 1, 2, 3]);
     /// slices[0][1] *= 10;
     /// assert_eq!(vec.fragments()[0], &[0, 10, 2, 3]);
     ///
     /// // single fragment - partially
-    /// let mut slices = vec.slices_mut(5..7);
+    /// let mut slices: Vec<_> = vec.slices_mut(5..7).collect();
     /// assert_eq!(slices.len(), 1);
     /// assert_eq!(slices[0], &[5, 6]);
     /// slices[0][1] *= 10;
     /// assert_eq!(vec.fragments()[1], &[4, 5, 60, 7]);
     ///
     /// // multiple fragments
-    /// let slices = vec.slices_mut(2..6);
+    /// let slices: Vec<_> = vec.slices_mut(2..6).collect();
     /// assert_eq!(slices.len(), 2);
     /// assert_eq!(slices[0], &[2, 3]);
     /// assert_eq!(slices[1], &[4, 5]);
@@ -653,41 +343,11 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     /// assert_eq!(vec.fragments()[2], &[8, 9]);
     ///
     /// // out of bounds
-    /// assert!(vec.slices_mut(5..12).is_empty());
-    /// assert!(vec.slices_mut(10..11).is_empty());
+    /// assert_eq!(vec.slices_mut(5..12).len(), 0);
+    /// assert_eq!(vec.slices_mut(10..11).len(), 0);
     /// ```
     fn slices_mut<R: RangeBounds<usize>>(&mut self, range: R) -> Self::SliceMutIter<'_> {
-        use std::slice::from_raw_parts_mut;
-
-        let a = range_start(&range);
-        let b = range_end(&range, self.len());
-
-        match b.saturating_sub(a) {
-            0 => vec![],
-            _ => match self.get_fragment_and_inner_indices(a) {
-                None => vec![],
-                Some((sf, si)) => match self.get_fragment_and_inner_indices(b - 1) {
-                    None => vec![],
-                    Some((ef, ei)) => match sf.cmp(&ef) {
-                        Ordering::Equal => vec![&mut self.fragments[sf][si..=ei]],
-                        _ => {
-                            let mut vec = Vec::with_capacity(ef - sf + 1);
-
-                            let ptr_s = unsafe { self.fragments[sf].as_mut_ptr().add(si) };
-                            let slice_len = self.fragments[sf].capacity() - si;
-                            vec.push(unsafe { from_raw_parts_mut(ptr_s, slice_len) });
-                            for f in sf + 1..ef {
-                                let ptr_s = self.fragments[f].as_mut_ptr();
-                                let slice_len = self.fragments[f].capacity();
-                                vec.push(unsafe { from_raw_parts_mut(ptr_s, slice_len) });
-                            }
-                            vec.push(&mut self.fragments[ef][..=ei]);
-                            vec
-                        }
-                    },
-                },
-            },
-        }
+        self.slices_iter_mut(range)
     }
 
     /// Returns a mutable reference to the `index`-th element of the vector.
@@ -711,13 +371,14 @@ impl<T, G: Growth> PinnedVec<T> for SplitVec<T, G> {
     unsafe fn set_len(&mut self, new_len: usize) {
         set_fragments_len(&mut self.fragments, new_len);
         self.len = new_len;
+        self.fragments_mutated_from(0);
     }
 
     fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
     where
         F: FnMut(&T) -> Ordering,
     {
-        algorithms::binary_search::binary_search_by(&self.fragments, f)
+        algorithms::binary_search::binary_search_by(&self.growth, self.len, &self.fragments, f)
     }
 }
 
@@ -1073,8 +734,8 @@ mod tests {
     fn slices() {
         fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
             for i in 0..184 {
-                assert!(vec.slices(i..i + 1).is_empty());
-                assert!(vec.slices(0..i + 1).is_empty());
+                assert_eq!(0, vec.slices(i..i + 1).len());
+                assert_eq!(0, vec.slices(0..i + 1).len());
                 vec.push(i);
             }
 
@@ -1107,8 +768,8 @@ mod tests {
     fn slices_mut() {
         fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
             for i in 0..184 {
-                assert!(vec.slices_mut(i..i + 1).is_empty());
-                assert!(vec.slices_mut(0..i + 1).is_empty());
+                assert_eq!(0, vec.slices_mut(i..i + 1).len());
+                assert_eq!(0, vec.slices_mut(0..i + 1).len());
                 vec.push(i);
             }
 
@@ -1140,7 +801,7 @@ mod tests {
                 vec.push(0);
             }
 
-            fn update(slice: Vec<&mut [usize]>, begin: usize) {
+            fn update<'a>(slice: impl Iterator<Item = &'a mut [usize]>, begin: usize) {
                 let mut val = begin;
                 for s in slice {
                     for x in s {
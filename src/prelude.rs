@@ -1,14 +1,22 @@
 pub use crate::common_traits::iterator::iter::Iter;
+pub use crate::common_traits::iterator::{slices_iter::SlicesIter, slices_iter_mut::SlicesIterMut};
+pub use crate::error::SplitVecError;
 pub use crate::fragment::fragment_struct::Fragment;
 pub use crate::fragment::into_fragments::IntoFragments;
 pub use crate::growth::{
-    doubling::Doubling,
+    byte_budget::ByteBudget,
+    capped_doubling::CappedDoubling,
+    doubling::{Doubling, DoublingFrom},
+    geometric::Geometric,
     growth_trait::{Growth, GrowthWithConstantTimeAccess},
-    linear::Linear,
+    linear::{ConstLinear, Linear, LinearN},
     recursive::Recursive,
 };
+pub use crate::new_split_vec::default::NewWithDefaultGrowth;
 pub use crate::slice::SplitVecSlice;
 pub use crate::split_vec::SplitVec;
+pub use crate::view::{split_vec_view::SplitVecView, split_vec_view_mut::SplitVecViewMut};
+pub use allocator_api2::alloc::{Allocator, Global};
 pub use orx_pinned_vec::{
     ConcurrentPinnedVec, IntoConcurrentPinnedVec, PinnedVec, PinnedVecGrowthError,
 };
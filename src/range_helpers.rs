@@ -1,25 +1,64 @@
-use std::ops::RangeBounds;
+use core::ops::RangeBounds;
 
 pub(crate) fn range_start<R: RangeBounds<usize>>(range: &R) -> usize {
     match range.start_bound() {
-        std::ops::Bound::Excluded(x) => x + 1,
-        std::ops::Bound::Included(x) => *x,
-        std::ops::Bound::Unbounded => 0,
+        core::ops::Bound::Excluded(x) => x + 1,
+        core::ops::Bound::Included(x) => *x,
+        core::ops::Bound::Unbounded => 0,
     }
 }
 pub(crate) fn range_end<R: RangeBounds<usize>>(range: &R, vec_len: usize) -> usize {
     match range.end_bound() {
-        std::ops::Bound::Excluded(x) => *x,
-        std::ops::Bound::Included(x) => x + 1,
-        std::ops::Bound::Unbounded => vec_len,
+        core::ops::Bound::Excluded(x) => *x,
+        core::ops::Bound::Included(x) => x + 1,
+        core::ops::Bound::Unbounded => vec_len,
     }
 }
 
+/// Returns the start and end of the `range` after validating them against the length `len`.
+///
+/// # Panics
+///
+/// Panics with the same messages as slices and `Vec` if the start of the range is greater than its end,
+/// or if the range is out of bounds.
+pub(crate) fn range_bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let a = match range.start_bound() {
+        core::ops::Bound::Excluded(x) => x
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        core::ops::Bound::Included(x) => *x,
+        core::ops::Bound::Unbounded => 0,
+    };
+    let b = match range.end_bound() {
+        core::ops::Bound::Excluded(x) => *x,
+        core::ops::Bound::Included(x) => x
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        core::ops::Bound::Unbounded => {
+            assert!(
+                a <= len,
+                "range start index {} out of range for slice of length {}",
+                a,
+                len
+            );
+            len
+        }
+    };
+
+    assert!(a <= b, "slice index starts at {} but ends at {}", a, b);
+    assert!(
+        b <= len,
+        "range end index {} out of range for slice of length {}",
+        b,
+        len
+    );
+    (a, b)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{test_all_growth_types, Growth, SplitVec};
-    use orx_pinned_vec::PinnedVec;
 
     #[test]
     fn range_start_end() {
@@ -39,4 +78,40 @@ mod tests {
 
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn range_bounds_within_len() {
+        assert_eq!(range_bounds(&(2..5), 10), (2, 5));
+        assert_eq!(range_bounds(&(2..=5), 10), (2, 6));
+        assert_eq!(range_bounds(&(..5), 10), (0, 5));
+        assert_eq!(range_bounds(&(..=9), 10), (0, 10));
+        assert_eq!(range_bounds(&(10..), 10), (10, 10));
+        assert_eq!(range_bounds(&(..), 10), (0, 10));
+        assert_eq!(range_bounds(&(3..3), 10), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 5 but ends at 3")]
+    fn range_bounds_decreasing() {
+        #[allow(clippy::reversed_empty_ranges)]
+        range_bounds(&(5..3), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 11 out of range for slice of length 10")]
+    fn range_bounds_end_out_of_range() {
+        range_bounds(&(5..11), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 11 out of range for slice of length 10")]
+    fn range_bounds_start_out_of_range() {
+        range_bounds(&(11..), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn range_bounds_inclusive_end_overflow() {
+        range_bounds(&(5..=usize::MAX), 10);
+    }
 }
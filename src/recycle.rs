@@ -0,0 +1,248 @@
+use crate::{Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator,
+{
+    /// Clears the vector, removing all values, while keeping the allocated fragments for reuse.
+    ///
+    /// This method:
+    /// * clears the first fragment and keeps it in the vector, as [`clear`] does, and
+    /// * clears all other fragments and keeps them as spare fragments rather than dropping them.
+    ///
+    /// As the vector grows again, the spare fragments are added back to the vector, in order, without any allocation.
+    /// Therefore, a vector which is repeatedly cleared and refilled to a similar length allocates its fragments only once.
+    ///
+    /// A spare fragment is reused only when its capacity is the one required by the growth strategy;
+    /// otherwise, the spare fragments are dropped and a new fragment is allocated.
+    /// Spare fragments can be released at any time by [`shrink_to_fit`] or [`shrink_to`].
+    ///
+    /// [`clear`]: orx_pinned_vec::PinnedVec::clear
+    /// [`shrink_to_fit`]: SplitVec::shrink_to_fit
+    /// [`shrink_to`]: SplitVec::shrink_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    /// assert_eq!(vec.fragments().len(), 3);
+    /// let ptr = vec.fragments()[2].as_ptr();
+    ///
+    /// vec.clear_keep_capacity();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.fragments().len(), 1);
+    ///
+    /// vec.extend(10..20);
+    /// assert_eq!(vec, &[10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+    /// assert_eq!(vec.fragments()[2].as_ptr(), ptr);
+    /// ```
+    pub fn clear_keep_capacity(&mut self) {
+        if self.fragments.is_empty() {
+            return;
+        }
+
+        for fragment in self.fragments.iter_mut() {
+            fragment.clear();
+        }
+
+        self.spare.reserve(self.fragments.len() - 1);
+        for fragment in self.fragments.drain(1..).rev() {
+            self.spare.push_front(fragment);
+        }
+
+        self.len = 0;
+        self.fragments_mutated_from(0);
+    }
+
+    /// Releases all spare fragments of the vector.
+    ///
+    /// Spare fragments are the fragments which are allocated in advance by [`try_reserve`],
+    /// or kept for reuse by [`clear_keep_capacity`].
+    /// Fragments holding the elements of the vector are not affected.
+    ///
+    /// [`try_reserve`]: SplitVec::try_reserve
+    /// [`clear_keep_capacity`]: SplitVec::clear_keep_capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.clear_keep_capacity();
+    /// vec.shrink_to_fit();
+    ///
+    /// vec.extend(0..3);
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// assert_eq!(vec.capacity(), 4);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.spare.clear();
+        self.spare.shrink_to_fit();
+    }
+
+    /// Releases the spare fragments of the vector which are not required to keep at least `min_capacity`
+    /// as the total capacity of the fragments of the vector and the retained spare fragments.
+    ///
+    /// Spare fragments are released from the back; i.e., the spare fragments which would be added to the vector
+    /// first as it grows are retained.
+    /// If the capacity of the vector is already greater than or equal to `min_capacity`, all spare fragments are released.
+    /// Fragments holding the elements of the vector are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..20);
+    /// assert_eq!(vec.fragments().len(), 5);
+    /// let ptr = vec.fragments()[1].as_ptr();
+    ///
+    /// vec.clear_keep_capacity();
+    /// vec.shrink_to(6);
+    ///
+    /// // only the second fragment is retained
+    /// vec.extend(0..20);
+    /// assert_eq!(vec.fragments()[1].as_ptr(), ptr);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let mut capacity: usize = self.fragments.iter().map(|f| f.capacity()).sum();
+        let mut num_retained = 0;
+        for fragment in &self.spare {
+            if capacity >= min_capacity {
+                break;
+            }
+            capacity = capacity.saturating_add(fragment.capacity());
+            num_retained += 1;
+        }
+
+        self.spare.truncate(num_retained);
+        self.spare.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    fn fragment_pointers<T, G: Growth>(vec: &SplitVec<T, G>) -> Vec<*const T> {
+        vec.fragments().iter().map(|f| f.as_ptr()).collect()
+    }
+
+    #[test]
+    fn clear_keep_capacity_reuses_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.clear();
+            vec.extend((0..100).map(|x| x.to_string()));
+            let pointers = fragment_pointers(&vec);
+
+            for len in [100, 37, 0, 100, 1, 64] {
+                vec.clear_keep_capacity();
+                assert!(vec.is_empty());
+                assert_eq!(vec.fragments().len(), 1);
+
+                vec.extend((0..len).map(|x| x.to_string()));
+                assert_eq!(vec, (0..len).map(|x| x.to_string()).collect::<Vec<_>>());
+
+                let num_fragments = vec.fragments().len();
+                assert_eq!(fragment_pointers(&vec), pointers[..num_fragments]);
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn clear_keep_capacity_drops_elements() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend((0..10).map(|_| counter.clone()));
+        assert_eq!(Rc::strong_count(&counter), 11);
+
+        vec.clear_keep_capacity();
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert_eq!(vec.spare.len(), 2);
+        assert!(vec.spare.iter().all(|f| f.is_empty()));
+    }
+
+    #[test]
+    fn clear_keep_capacity_after_try_reserve() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.clear();
+            vec.extend(0..10);
+            vec.try_reserve(50).expect("small allocation");
+            let num_fragments = vec.fragments().len();
+            let num_spare = vec.spare.len();
+
+            vec.clear_keep_capacity();
+            assert_eq!(vec.spare.len(), num_fragments - 1 + num_spare);
+
+            vec.extend(0..60);
+            assert_eq!(vec, (0..60).collect::<Vec<_>>());
+
+            let mut expected = SplitVec::with_growth(vec.growth().clone());
+            expected.extend(0..60);
+            let capacities = |v: &SplitVec<usize, G>| -> Vec<usize> {
+                v.fragments().iter().map(|f| f.capacity()).collect()
+            };
+            assert_eq!(capacities(&vec), capacities(&expected));
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn shrink_to_fit_releases_spare() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.clear();
+            vec.extend(0..100);
+            let num_fragments = vec.fragments().len();
+
+            vec.clear_keep_capacity();
+            assert_eq!(vec.spare.len(), num_fragments - 1);
+
+            vec.shrink_to_fit();
+            assert!(vec.spare.is_empty());
+
+            vec.extend(0..100);
+            assert_eq!(vec, (0..100).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn shrink_to_retains_required_spare() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.clear();
+            vec.extend(0..100);
+            let total_capacity = vec.capacity();
+
+            for min_capacity in [0, 1, 4, 5, 50, 100, total_capacity, 1000] {
+                vec.clear();
+                vec.extend(0..100);
+                vec.clear_keep_capacity();
+
+                vec.shrink_to(min_capacity);
+
+                let capacity = vec.capacity();
+                let spare: usize = vec.spare.iter().map(|f| f.capacity()).sum();
+                let last_spare = vec.spare.back().map(|f| f.capacity()).unwrap_or(0);
+                assert!(capacity + spare >= min_capacity.min(total_capacity));
+                assert!(spare == 0 || capacity + spare - last_spare < min_capacity);
+
+                vec.extend(0..100);
+                assert_eq!(vec, (0..100).collect::<Vec<_>>());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+}
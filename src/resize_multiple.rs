@@ -1,15 +1,21 @@
 use crate::{Growth, SplitVec};
-use orx_pinned_vec::PinnedVec;
+use allocator_api2::alloc::Allocator;
 
-impl<'a, T: Clone + 'a, G> Extend<&'a T> for SplitVec<T, G>
+impl<'a, T: Copy + 'a, G, A: Allocator + Clone> Extend<&'a T> for SplitVec<T, G, A>
 where
     G: Growth,
 {
-    /// Clones and appends all elements in the iterator to the vec.
+    /// Copies and appends all elements in the iterator to the vec.
     ///
-    /// Iterates over the `iter`, clones each element, and then appends
+    /// Iterates over the `iter`, copies each element, and then appends
     /// it to this vector.
     ///
+    /// Similar to `Vec`, this implementation requires `T: Copy`; elements which are only `Clone` can be
+    /// appended by `vec.extend(iter.cloned())` or by [`SplitVec::extend_from_slice`].
+    ///
+    /// The elements are copied directly into the spare capacity of the last fragment,
+    /// and the length of the fragment is updated once per fragment rather than once per element.
+    ///
     /// # Examples
     ///
     /// ```
@@ -29,21 +35,54 @@ where
     /// assert_eq!(sec_vec, [1, 2, 3, 4, 5, 6, 7]);
     /// ```
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        for x in iter {
-            self.push(x.clone());
+        let mut iter = iter.into_iter();
+        self.reserve_fragments_for(iter.size_hint().0);
+
+        loop {
+            if !self.has_capacity_for_one() {
+                match iter.next() {
+                    Some(first) => self.push(*first),
+                    None => break,
+                }
+                continue;
+            }
+
+            let f = self.fragments.len() - 1;
+            let last = &mut self.fragments[f].data;
+
+            let mut num_copied = 0;
+            for (slot, x) in last.spare_capacity_mut().iter_mut().zip(iter.by_ref()) {
+                slot.write(*x);
+                num_copied += 1;
+            }
+
+            // SAFETY: the first `num_copied` positions of the spare capacity are initialized above;
+            // since T is Copy, no element is leaked if the iterator panics before `set_len`.
+            unsafe { last.set_len(last.len() + num_copied) };
+            self.len += num_copied;
+
+            if num_copied == 0 {
+                break;
+            }
         }
     }
 }
 
-impl<T, G> Extend<T> for SplitVec<T, G>
+impl<T, G, A> Extend<T> for SplitVec<T, G, A>
 where
     G: Growth,
+    A: Allocator + Clone,
 {
     /// Extends a collection with the contents of an iterator.
     ///
     /// Iterates over the `iter`, moves and appends each element
     /// to this vector.
     ///
+    /// Elements are written directly into the spare capacity of the last fragment; a new fragment is added
+    /// only once the last fragment is full.
+    /// Further, the lower bound of the iterator's `size_hint` is used to reserve room
+    /// for the fragments that will be required in advance.
+    ///
     /// # Examples
     ///
     /// ```
@@ -59,8 +98,22 @@ where
     /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
     /// ```
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for x in iter {
-            self.push(x);
+        let mut iter = iter.into_iter();
+        self.reserve_fragments_for(iter.size_hint().0);
+
+        while let Some(first) = iter.next() {
+            if !self.has_capacity_for_one() {
+                self.add_fragment();
+            }
+
+            let f = self.fragments.len() - 1;
+            let last = &mut self.fragments[f];
+            last.push(first);
+            self.len += 1;
+
+            let available = last.room();
+            let len = &mut self.len;
+            last.extend(iter.by_ref().take(available).inspect(|_| *len += 1));
         }
     }
 }
@@ -84,4 +137,76 @@ mod tests {
         }
         test_all_growth_types!(test);
     }
+
+    #[test]
+    fn extend_by_copying_references() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let source: Vec<_> = (0..100).collect();
+
+            vec.extend(source.iter().take(3));
+            vec.extend(source[3..40].iter().filter(|_| true));
+            vec.extend(&source[40..40]);
+            vec.extend(&source[40..]);
+
+            assert_eq!(100, vec.len());
+            assert_eq!(100, vec.fragments().iter().map(|f| f.len()).sum::<usize>());
+            assert_eq!(vec, &source);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn extend_without_size_hint() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..100).filter(|x| x % 2 == 0).map(|x| x.to_string()));
+            vec.extend(Vec::<String>::new());
+            vec.extend([100.to_string(), 102.to_string()].iter().cloned());
+
+            assert_eq!(52, vec.len());
+            for (i, x) in vec.iter().enumerate() {
+                assert_eq!(x, &(2 * i).to_string());
+            }
+
+            vec.push(104.to_string());
+            assert_eq!(vec.get(52), Some(&104.to_string()));
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn extend_after_zero_capacity_fragment() {
+        let mut vec: SplitVec<usize, Recursive> = Vec::new().into();
+        vec.extend(0..5);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(Vec::<usize>::new());
+        vec.extend(0..5);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_keeps_len_when_iterator_panics() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..3).map(|x| x.to_string()));
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                vec.extend((3..100).map(|x| match x {
+                    #[allow(clippy::panic)]
+                    42 => panic!("failing iterator"),
+                    x => x.to_string(),
+                }))
+            }));
+            assert!(result.is_err());
+
+            assert_eq!(42, vec.len());
+            assert_eq!(42, vec.fragments().iter().map(|f| f.len()).sum::<usize>());
+            for (i, x) in vec.iter().enumerate() {
+                assert_eq!(x, &i.to_string());
+            }
+        }
+        test_all_growth_types!(test);
+    }
 }
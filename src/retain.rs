@@ -0,0 +1,212 @@
+use crate::algorithms::compaction::Compaction;
+use crate::{Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all elements `e` for which `f(&e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the original order,
+    /// and preserves the order of the retained elements.
+    ///
+    /// The elements are visited in a single pass with read and write cursors over the fragments;
+    /// trailing fragments which become empty are dropped.
+    ///
+    /// # Pinned elements
+    ///
+    /// Retained elements preceding the first removed element keep their memory locations.
+    /// However, retained elements following a removed element are moved towards the front to close the gap;
+    /// therefore, their memory locations are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// vec.retain(|x| x % 3 != 0);
+    /// assert_eq!(vec, &[1, 2, 4, 5, 7, 8]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// In other words, removes all elements `e` such that `f(&mut e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the original order,
+    /// and preserves the order of the retained elements.
+    ///
+    /// Please see [`SplitVec::retain`] for details and the remark on pinned elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// vec.retain_mut(|x| match *x % 2 == 0 {
+    ///     true => {
+    ///         *x *= 10;
+    ///         true
+    ///     }
+    ///     false => false,
+    /// });
+    /// assert_eq!(vec, &[20, 40, 60, 80]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut compaction = Compaction::new(self, 0);
+        while let Some(ptr) = compaction.read_ptr() {
+            // SAFETY: ptr points to the initialized element at the read cursor
+            match f(unsafe { &mut *ptr }) {
+                true => compaction.keep(),
+                false => {
+                    compaction.discard(1);
+                    // SAFETY: the discarded element will not be accessed again
+                    unsafe { core::ptr::drop_in_place(ptr) };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn retain() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let predicates: [fn(usize) -> bool; 5] = [
+                |_| true,
+                |_| false,
+                |x| x % 2 == 0,
+                |x| !(13..=66).contains(&x),
+                |x| (20..40).contains(&x),
+            ];
+
+            for predicate in predicates {
+                vec.clear();
+                vec.extend((0..100).map(|x| x.to_string()));
+                let mut expected: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+
+                let keep = |x: &String| predicate(x.parse().expect("is-number"));
+                vec.retain(keep);
+                expected.retain(keep);
+
+                assert_eq!(vec, expected.as_slice());
+                for (i, x) in expected.iter().enumerate() {
+                    assert_eq!(vec.get(i), Some(x));
+                }
+
+                vec.push("x".to_string());
+                assert_eq!(vec.get(expected.len()), Some(&"x".to_string()));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            vec.retain_mut(|x| {
+                *x += 1;
+                *x % 3 == 0
+            });
+
+            let expected: Vec<_> = (1..101).filter(|x| x % 3 == 0).collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_drops_trailing_empty_fragments() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(0..20);
+        assert_eq!(vec.fragments().len(), 5);
+
+        vec.retain(|x| x % 4 == 0);
+        assert_eq!(vec, &[0, 4, 8, 12, 16]);
+        assert_eq!(vec.fragments().len(), 2);
+
+        vec.retain(|_| false);
+        assert!(vec.is_empty());
+        assert_eq!(vec.fragments().len(), 1);
+
+        vec.extend(0..6);
+        assert_eq!(vec, &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn retain_keeps_prefix_pinned() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            let addresses: Vec<_> = vec.iter().map(|x| x as *const usize).collect();
+
+            vec.retain(|x| *x < 30 || *x > 60);
+            for (i, address) in addresses.iter().take(30).enumerate() {
+                assert_eq!(&vec[i] as *const usize, *address);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_panic_in_predicate() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..40).map(|x| x.to_string()));
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vec.retain(|x| match x.as_str() {
+                    #[allow(clippy::panic)]
+                    "25" => panic!("panic in predicate"),
+                    x => x.len() == 1,
+                })
+            }));
+            assert!(result.is_err());
+
+            let expected: Vec<_> = (0..10).chain(25..40).map(|x| x.to_string()).collect();
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn retain_drops_each_removed_element_once() {
+        struct Counted<'a>(usize, &'a Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+
+        let num_dropped = Cell::new(0);
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend((0..50).map(|x| Counted(x, &num_dropped)));
+
+        vec.retain(|x| x.0 % 5 == 0);
+        assert_eq!(num_dropped.get(), 40);
+        assert_eq!(vec.len(), 10);
+
+        drop(vec);
+        assert_eq!(num_dropped.get(), 50);
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     range_helpers::{range_end, range_start},
     Growth, SplitVec,
 };
-use orx_pinned_vec::PinnedVec;
-use std::{cmp::Ordering, ops::RangeBounds};
+use allocator_api2::alloc::Allocator;
+use core::{cmp::Ordering, ops::RangeBounds};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 /// Returns the result of trying to get a slice as a contagious memory from the split vector.
@@ -18,7 +18,7 @@ pub enum SplitVecSlice<'a, T> {
     OutOfBounds,
 }
 
-impl<T, G: Growth> SplitVec<T, G> {
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
     /// Returns the result of trying to return the required `range` as a contagious slice of data.
     /// It might return Ok of the slice if the range belongs to one fragment.
     ///
@@ -59,7 +59,7 @@ impl<T, G: Growth> SplitVec<T, G> {
     /// ```
     pub fn try_get_slice<R: RangeBounds<usize>>(&self, range: R) -> SplitVecSlice<T> {
         let a = range_start(&range);
-        let b = range_end(&range, self.len());
+        let b = range_end(&range, self.len);
 
         match b.saturating_sub(a) {
             0 => SplitVecSlice::Ok(&[]),
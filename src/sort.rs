@@ -0,0 +1,756 @@
+use crate::{Fragment, Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+use allocator_api2::vec::Vec;
+use core::cmp::Ordering;
+
+/// Ranges of at most this many elements are sorted by insertion sort.
+const INSERTION_SORT_LEN: usize = 16;
+
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Sorts the vector.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// The fragment layout of the vector is unchanged; i.e., the number, capacities and lengths of fragments remain the same.
+    /// The elements, on the other hand, are moved between the positions of the vector.
+    ///
+    /// # Current implementation
+    ///
+    /// The elements are sorted in their fragments by a merge sort over the positions of the vector.
+    /// Merging requires a scratch buffer which is allocated by the allocator of the vector;
+    /// its capacity is bounded by the length of the largest fragment and by half of the length of the vector.
+    /// Runs which do not fit in the scratch buffer are merged in place by rotations.
+    ///
+    /// If the comparison panics, the vector keeps all its elements in an unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[-5, 4, 1, -3, 2, 8, -1]);
+    ///
+    /// vec.sort();
+    /// assert_eq!(vec, &[-5, -3, -1, 1, 2, 4, 8]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+        A: Clone,
+    {
+        self.merge_sort_by(&mut T::cmp)
+    }
+
+    /// Sorts the vector with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// Please see [`SplitVec::sort`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[5, 4, 1, 3, 2]);
+    ///
+    /// vec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, &[5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        A: Clone,
+    {
+        self.merge_sort_by(&mut compare)
+    }
+
+    /// Sorts the vector with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*)) worst-case,
+    /// where the key function is *O*(*m*).
+    ///
+    /// Please see [`SplitVec::sort`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.append(vec![-5i32, 4, 1]);
+    /// vec.append(vec![-3, 2]);
+    ///
+    /// vec.sort_by_key(|k| k.abs());
+    /// assert_eq!(vec, &[1, 2, -3, 4, -5]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+        A: Clone,
+    {
+        self.merge_sort_by(&mut |a: &T, b: &T| f(a).cmp(&f(b)))
+    }
+
+    /// Sorts the vector in place, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place (i.e., does not allocate),
+    /// and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// The fragment layout of the vector is unchanged; i.e., the number, capacities and lengths of fragments remain the same.
+    ///
+    /// # Current implementation
+    ///
+    /// The vector is sorted by an introsort over the positions of the vector:
+    /// ranges are partitioned by quicksort across fragments, falling back to heapsort when the recursion gets too deep.
+    /// Once a range lies within a single fragment, it is sorted directly by the slice sort.
+    ///
+    /// If the comparison panics, the vector keeps all its elements in an unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[-5, 4, 1, -3, 2, 8, -1]);
+    ///
+    /// vec.sort_unstable();
+    /// assert_eq!(vec, &[-5, -3, -1, 1, 2, 4, 8]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.intro_sort_by(&mut T::cmp)
+    }
+
+    /// Sorts the vector in place with a comparator function, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place (i.e., does not allocate),
+    /// and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// Please see [`SplitVec::sort_unstable`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend_from_slice(&[5, 4, 1, 3, 2]);
+    ///
+    /// vec.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, &[5, 4, 3, 2, 1]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.intro_sort_by(&mut compare)
+    }
+
+    // helpers
+
+    fn positions(&mut self) -> Positions<'_, T, G, A> {
+        Positions {
+            growth: &self.growth,
+            fragments: &mut self.fragments,
+            len: self.len,
+        }
+    }
+
+    fn intro_sort_by<F>(&mut self, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len;
+        let depth_limit = 2 * (usize::BITS - len.leading_zeros());
+        self.positions().intro_sort(0, len, compare, depth_limit);
+    }
+
+    fn merge_sort_by<F>(&mut self, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        A: Clone,
+    {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+
+        let largest_fragment = self.fragments.iter().map(|x| x.len()).max().unwrap_or(0);
+        let scratch_len = largest_fragment.min(len / 2);
+        let mut buffer: Vec<T, A> = Vec::with_capacity_in(scratch_len, self.allocator.clone());
+
+        let mut positions = self.positions();
+        let scratch = Scratch {
+            ptr: buffer.as_mut_ptr(),
+            capacity: scratch_len,
+        };
+
+        for begin in (0..len).step_by(INSERTION_SORT_LEN) {
+            positions.insertion_sort(begin, len.min(begin + INSERTION_SORT_LEN), compare);
+        }
+
+        let mut width = INSERTION_SORT_LEN;
+        while width < len {
+            for begin in (0..len).step_by(2 * width) {
+                let mid = len.min(begin + width);
+                let end = len.min(mid + width);
+                positions.merge(begin, mid, end, &scratch, compare);
+            }
+            width = width.saturating_mul(2);
+        }
+    }
+}
+
+/// Elements of the split vector addressed by their positions in the vector, regardless of the fragments they belong to.
+struct Positions<'a, T, G: Growth, A: Allocator> {
+    growth: &'a G,
+    fragments: &'a mut [Fragment<T, A>],
+    len: usize,
+}
+
+impl<T, G: Growth, A: Allocator> Positions<'_, T, G, A> {
+    /// Returns the (fragment-index, index-within-fragment) of the element at the given `position`.
+    fn location(&self, position: usize) -> (usize, usize) {
+        self.growth
+            .get_fragment_and_inner_indices(self.len, self.fragments, position)
+            .expect("position is within the bounds of the vector")
+    }
+
+    fn ptr(&mut self, position: usize) -> *mut T {
+        let (f, i) = self.location(position);
+        // SAFETY: i is within the length of the f-th fragment
+        unsafe { self.fragments[f].as_mut_ptr().add(i) }
+    }
+
+    /// Returns whether the element at position `a` is less than the element at position `b`.
+    fn less<F>(&mut self, a: usize, b: usize, compare: &mut F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (a, b) = (self.ptr(a), self.ptr(b));
+        // SAFETY: both pointers point to initialized elements of the vector
+        unsafe { compare(&*a, &*b) == Ordering::Less }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.ptr(a), self.ptr(b));
+        // SAFETY: both pointers point to initialized elements of the vector; they are allowed to be equal
+        unsafe { core::ptr::swap(a, b) }
+    }
+
+    /// Returns the slice of the elements at positions `begin..end` if they all belong to the same fragment.
+    fn fragment_slice(&mut self, begin: usize, end: usize) -> Option<&mut [T]> {
+        let (f, i) = self.location(begin);
+        let (g, j) = self.location(end - 1);
+        match f == g {
+            true => Some(&mut self.fragments[f][i..=j]),
+            false => None,
+        }
+    }
+
+    // unstable
+
+    fn intro_sort<F>(
+        &mut self,
+        mut begin: usize,
+        mut end: usize,
+        compare: &mut F,
+        mut depth_limit: u32,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while end - begin > 1 {
+            if let Some(slice) = self.fragment_slice(begin, end) {
+                slice.sort_unstable_by(|a, b| compare(a, b));
+                return;
+            }
+
+            if end - begin <= INSERTION_SORT_LEN {
+                self.insertion_sort(begin, end, compare);
+                return;
+            }
+
+            if depth_limit == 0 {
+                self.heap_sort(begin, end, compare);
+                return;
+            }
+            depth_limit -= 1;
+
+            let pivot = self.partition(begin, end, compare);
+            match pivot - begin < end - pivot {
+                true => {
+                    self.intro_sort(begin, pivot, compare, depth_limit);
+                    begin = pivot + 1;
+                }
+                false => {
+                    self.intro_sort(pivot + 1, end, compare, depth_limit);
+                    end = pivot;
+                }
+            }
+        }
+    }
+
+    /// Partitions the elements at positions `begin..end`, where `end - begin > 2`, around the median of three elements;
+    /// returns the final position of the pivot.
+    fn partition<F>(&mut self, begin: usize, end: usize, compare: &mut F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (first, mid, last) = (begin, begin + (end - begin) / 2, end - 1);
+        if self.less(mid, first, compare) {
+            self.swap(mid, first);
+        }
+        if self.less(last, mid, compare) {
+            self.swap(last, mid);
+            if self.less(mid, first, compare) {
+                self.swap(mid, first);
+            }
+        }
+        self.swap(begin, mid);
+
+        let (mut i, mut j) = (begin + 1, end - 1);
+        loop {
+            while i <= j && self.less(i, begin, compare) {
+                i += 1;
+            }
+            while i <= j && self.less(begin, j, compare) {
+                j -= 1;
+            }
+            if i >= j {
+                break;
+            }
+            self.swap(i, j);
+            i += 1;
+            j -= 1;
+        }
+
+        self.swap(begin, j);
+        j
+    }
+
+    fn heap_sort<F>(&mut self, begin: usize, end: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = end - begin;
+        for node in (0..len / 2).rev() {
+            self.sift_down(begin, node, len, compare);
+        }
+        for heap_len in (1..len).rev() {
+            self.swap(begin, begin + heap_len);
+            self.sift_down(begin, 0, heap_len, compare);
+        }
+    }
+
+    fn sift_down<F>(&mut self, begin: usize, mut node: usize, heap_len: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        loop {
+            let mut child = 2 * node + 1;
+            if child >= heap_len {
+                return;
+            }
+            if child + 1 < heap_len && self.less(begin + child, begin + child + 1, compare) {
+                child += 1;
+            }
+            if !self.less(begin + node, begin + child, compare) {
+                return;
+            }
+            self.swap(begin + node, begin + child);
+            node = child;
+        }
+    }
+
+    // stable
+
+    fn insertion_sort<F>(&mut self, begin: usize, end: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for i in (begin + 1)..end {
+            let mut j = i;
+            while j > begin && self.less(j, j - 1, compare) {
+                self.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Stably merges the sorted runs at positions `begin..mid` and `mid..end`.
+    fn merge<F>(
+        &mut self,
+        begin: usize,
+        mid: usize,
+        end: usize,
+        scratch: &Scratch<T>,
+        compare: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if begin == mid || mid == end || !self.less(mid, mid - 1, compare) {
+            return;
+        }
+
+        let (len_left, len_right) = (mid - begin, end - mid);
+        if len_left <= scratch.capacity && len_left <= len_right {
+            self.merge_forward(begin, mid, end, scratch, compare);
+        } else if len_right <= scratch.capacity {
+            self.merge_backward(begin, mid, end, scratch, compare);
+        } else {
+            let (cut_left, cut_right) = match len_left > len_right {
+                true => {
+                    let cut_left = begin + len_left / 2;
+                    (
+                        cut_left,
+                        self.partition_point(mid, end, |x, p| x.less(p, cut_left, compare)),
+                    )
+                }
+                false => {
+                    let cut_right = mid + len_right / 2;
+                    (
+                        self.partition_point(begin, mid, |x, p| !x.less(cut_right, p, compare)),
+                        cut_right,
+                    )
+                }
+            };
+            self.rotate(cut_left, mid, cut_right);
+            let new_mid = cut_left + (cut_right - mid);
+            self.merge(begin, cut_left, new_mid, scratch, compare);
+            self.merge(new_mid, cut_right, end, scratch, compare);
+        }
+    }
+
+    /// Merges by moving the left run into the scratch buffer and filling the positions from the front.
+    fn merge_forward<F>(
+        &mut self,
+        begin: usize,
+        mid: usize,
+        end: usize,
+        scratch: &Scratch<T>,
+        compare: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for (k, position) in (begin..mid).enumerate() {
+            let src = self.ptr(position);
+            // SAFETY: scratch has capacity for the left run; the positions of the left run now form the hole
+            unsafe { core::ptr::copy_nonoverlapping(src, scratch.ptr.add(k), 1) };
+        }
+
+        let mut hole = Hole {
+            positions: self,
+            scratch: scratch.ptr,
+            begin: 0,
+            end: mid - begin,
+            dest: begin,
+        };
+        let mut right = mid;
+        while hole.begin < hole.end && right < end {
+            let r = hole.positions.ptr(right);
+            let d = hole.positions.ptr(hole.dest);
+            // SAFETY: r is an initialized element, l is an initialized element of the scratch buffer and d is in the hole;
+            // hole.dest + (hole.end - hole.begin) == right holds after each iteration
+            unsafe {
+                let l = hole.scratch.add(hole.begin);
+                match compare(&*r, &*l) == Ordering::Less {
+                    true => {
+                        core::ptr::copy_nonoverlapping(r, d, 1);
+                        right += 1;
+                    }
+                    false => {
+                        core::ptr::copy_nonoverlapping(l, d, 1);
+                        hole.begin += 1;
+                    }
+                }
+            }
+            hole.dest += 1;
+        }
+    }
+
+    /// Merges by moving the right run into the scratch buffer and filling the positions from the back.
+    fn merge_backward<F>(
+        &mut self,
+        begin: usize,
+        mid: usize,
+        end: usize,
+        scratch: &Scratch<T>,
+        compare: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for (k, position) in (mid..end).enumerate() {
+            let src = self.ptr(position);
+            // SAFETY: scratch has capacity for the right run; the positions of the right run now form the hole
+            unsafe { core::ptr::copy_nonoverlapping(src, scratch.ptr.add(k), 1) };
+        }
+
+        let mut hole = Hole {
+            positions: self,
+            scratch: scratch.ptr,
+            begin: 0,
+            end: end - mid,
+            dest: mid,
+        };
+        let mut out = end;
+        while hole.dest > begin && hole.end > 0 {
+            let l = hole.positions.ptr(hole.dest - 1);
+            let d = hole.positions.ptr(out - 1);
+            // SAFETY: l is an initialized element, r is an initialized element of the scratch buffer and d is in the hole;
+            // hole.dest + hole.end == out holds after each iteration
+            unsafe {
+                let r = hole.scratch.add(hole.end - 1);
+                match compare(&*r, &*l) == Ordering::Less {
+                    true => {
+                        core::ptr::copy_nonoverlapping(l, d, 1);
+                        hole.dest -= 1;
+                    }
+                    false => {
+                        core::ptr::copy_nonoverlapping(r, d, 1);
+                        hole.end -= 1;
+                    }
+                }
+            }
+            out -= 1;
+        }
+    }
+
+    /// Returns the first position within `begin..end` for which `pred` returns false,
+    /// provided that `pred` returns true for a prefix of the positions and false for the rest.
+    fn partition_point<P>(&mut self, mut begin: usize, mut end: usize, mut pred: P) -> usize
+    where
+        P: FnMut(&mut Self, usize) -> bool,
+    {
+        while begin < end {
+            let mid = begin + (end - begin) / 2;
+            match pred(self, mid) {
+                true => begin = mid + 1,
+                false => end = mid,
+            }
+        }
+        begin
+    }
+
+    /// Rotates the elements at positions `begin..end` such that the element at `mid` moves to `begin`.
+    fn rotate(&mut self, begin: usize, mid: usize, end: usize) {
+        self.reverse(begin, mid);
+        self.reverse(mid, end);
+        self.reverse(begin, end);
+    }
+
+    fn reverse(&mut self, mut begin: usize, mut end: usize) {
+        while begin + 1 < end {
+            end -= 1;
+            self.swap(begin, end);
+            begin += 1;
+        }
+    }
+}
+
+/// Uninitialized buffer which temporarily holds the elements of a run during a merge.
+struct Scratch<T> {
+    ptr: *mut T,
+    capacity: usize,
+}
+
+/// Elements `scratch[begin..end]` which are moved out of the positions `dest..dest + (end - begin)` of the vector;
+/// they are moved back when dropped, such that the vector keeps all its elements even if the comparison panics.
+struct Hole<'p, 'a, T, G: Growth, A: Allocator> {
+    positions: &'p mut Positions<'a, T, G, A>,
+    scratch: *mut T,
+    begin: usize,
+    end: usize,
+    dest: usize,
+}
+
+impl<T, G: Growth, A: Allocator> Drop for Hole<'_, '_, T, G, A> {
+    fn drop(&mut self) {
+        for k in self.begin..self.end {
+            let dst = self.positions.ptr(self.dest + k - self.begin);
+            // SAFETY: the k-th element of the scratch buffer is moved back to its place in the hole
+            unsafe { core::ptr::copy_nonoverlapping(self.scratch.add(k), dst, 1) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+    use rand::prelude::*;
+    use rand_chacha::ChaCha8Rng;
+
+    fn random_values(len: usize, max: usize) -> Vec<usize> {
+        let mut rng = ChaCha8Rng::seed_from_u64(7316);
+        (0..len).map(|_| rng.gen_range(0..max)).collect()
+    }
+
+    fn layout<T, G: Growth>(vec: &SplitVec<T, G>) -> Vec<(*const T, usize, usize)> {
+        vec.fragments()
+            .iter()
+            .map(|x| (x.as_ptr(), x.len(), x.capacity()))
+            .collect()
+    }
+
+    #[test]
+    fn sort() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for len in [0, 1, 4, 5, 33, 1000] {
+                let mut expected = random_values(len, 100);
+                vec.clear();
+                vec.extend(expected.iter().copied());
+                let layout_before = layout(&vec);
+
+                vec.sort();
+                expected.sort();
+
+                assert_eq!(vec, expected.as_slice());
+                assert_eq!(layout(&vec), layout_before);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn sort_unstable() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            let mut expected: Vec<_> = random_values(300, 1000)
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect();
+            vec.extend(expected.iter().cloned());
+
+            vec.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(vec, expected.as_slice());
+
+            vec.sort_unstable_by(|a, b| b.cmp(a));
+            expected.sort_unstable_by(|a, b| b.cmp(a));
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn sort_is_stable() {
+        #[allow(clippy::unnecessary_sort_by)]
+        fn test<G: Growth>(mut vec: SplitVec<(usize, usize), G>) {
+            let mut expected: Vec<_> = random_values(500, 10)
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| (x, i))
+                .collect();
+            vec.extend(expected.iter().copied());
+
+            vec.sort_by_key(|x| x.0);
+            expected.sort_by_key(|x| x.0);
+            assert_eq!(vec, expected.as_slice());
+
+            vec.sort_by(|a, b| b.0.cmp(&a.0));
+            expected.sort_by(|a, b| b.0.cmp(&a.0));
+            assert_eq!(vec, expected.as_slice());
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn sort_patterns() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let len = 777;
+            let patterns: [Vec<usize>; 4] = [
+                (0..len).collect(),
+                (0..len).rev().collect(),
+                (0..len).map(|x| x % 3).collect(),
+                (0..len)
+                    .map(|x| if x % 2 == 0 { x } else { len - x })
+                    .collect(),
+            ];
+            for pattern in patterns {
+                let mut expected = pattern.clone();
+                expected.sort();
+
+                vec.clear();
+                vec.extend(pattern.iter().copied());
+                vec.sort();
+                assert_eq!(vec, expected.as_slice());
+
+                vec.clear();
+                vec.extend(pattern.iter().copied());
+                vec.sort_unstable();
+                assert_eq!(vec, expected.as_slice());
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn heap_sort() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut expected = random_values(333, 50);
+            vec.extend(expected.iter().copied());
+            let layout_before = layout(&vec);
+
+            vec.positions().heap_sort(0, 333, &mut usize::cmp);
+            expected.sort();
+
+            assert_eq!(vec, expected.as_slice());
+            assert_eq!(layout(&vec), layout_before);
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn sort_with_appended_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![9, 3, 7]);
+        vec.append(Vec::<i32>::new());
+        vec.append(vec![1, 8]);
+        vec.append(vec![5, 2, 6, 4, 0]);
+        let layout_before = layout(&vec);
+
+        vec.sort();
+        assert_eq!(vec, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(layout(&vec), layout_before);
+    }
+
+    #[test]
+    fn sort_panic_in_compare() {
+        #[allow(clippy::panic)]
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..100).rev().map(|x| x.to_string()));
+            let layout_before = layout(&vec);
+
+            let mut num_comparisons = 0;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vec.sort_by(|a, b| {
+                    num_comparisons += 1;
+                    if num_comparisons == 50 {
+                        panic!("panic in compare");
+                    }
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err());
+
+            assert_eq!(layout(&vec), layout_before);
+            let mut values: Vec<usize> =
+                vec.iter().map(|x| x.parse().expect("is-number")).collect();
+            values.sort();
+            assert_eq!(values, (0..100).collect::<Vec<_>>());
+        }
+
+        test_all_growth_types!(test);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{Fragment, Growth, SplitVec};
+use allocator_api2::alloc::Allocator;
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated split vector containing the elements in the range `[at, len)`.
+    /// After the call, the original vector will be left containing the elements `[0, at)`.
+    ///
+    /// Elements of the original vector in the range `[0, at)` keep their memory locations.
+    ///
+    /// Whenever the growth strategy can adopt the tail fragments as they are (see [`Growth::can_adopt_fragments`]),
+    /// whole fragments are handed over to the new vector without copying their elements;
+    /// only the elements of the fragment containing the split position, if any, are moved to a new fragment.
+    /// This is always the case for the [`Recursive`] growth.
+    /// [`Linear`] growth adopts the tail when the split position is at the beginning of a fragment,
+    /// while [`Doubling`] growth adopts the tail when the split position is zero.
+    ///
+    /// Otherwise, the tail fragments are appended to the new vector as in [`SplitVec::append`];
+    /// i.e., fragments are adopted whenever possible and elements are moved with bulk memory copies otherwise.
+    ///
+    /// [`Recursive`]: crate::Recursive
+    /// [`Linear`]: crate::Linear
+    /// [`Doubling`]: crate::Doubling
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_recursive_growth();
+    /// vec.append(vec![0, 1, 2, 3]);
+    /// vec.append(vec![4, 5, 6]);
+    /// vec.append(vec![7, 8, 9]);
+    ///
+    /// let tail = vec.split_off(4);
+    /// assert_eq!(vec, &[0, 1, 2, 3]);
+    /// assert_eq!(tail, &[4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(tail.fragments().len(), 2);
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let tail = vec.split_off(3);
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// assert_eq!(tail, &[3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.len,
+            "`at` split index (is {}) should be <= len (is {})",
+            at,
+            self.len
+        );
+
+        let growth = self.growth.clone();
+        let (f, i) = match self.get_fragment_and_inner_indices(at) {
+            Some(indices) => indices,
+            None => return Self::with_growth_in(growth, self.allocator.clone()),
+        };
+
+        let tail_len = self.len - at;
+        let mut tail_fragments = self.fragments.split_off(f);
+        if i > 0 {
+            let source = &mut tail_fragments[0];
+            let mut head = Fragment::new_in(source.capacity(), self.allocator.clone());
+            head.extend(source.drain(i..));
+            let source = core::mem::replace(source, head);
+            self.fragments.push(source);
+        }
+
+        self.len = at;
+        if self.fragments.is_empty() {
+            self.add_fragment();
+        }
+        self.fragments_mutated_from(f);
+
+        match growth.can_adopt_fragments(&tail_fragments) {
+            true => {
+                let allocator = self.allocator.clone();
+                Self::from_raw_parts_in(tail_len, tail_fragments, growth, allocator)
+            }
+            false => {
+                let mut tail = Self::with_growth_in(growth, self.allocator.clone());
+                tail.append_fragments(tail_fragments);
+                tail
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    #[test]
+    fn split_off() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for at in [0, 1, 4, 5, 12, 13, 33, 99, 100] {
+                vec.clear();
+                vec.extend(0..100);
+
+                let mut tail = vec.split_off(at);
+
+                assert_eq!(vec.len(), at);
+                assert_eq!(tail.len(), 100 - at);
+                assert!(vec.iter().copied().eq(0..at));
+                assert!(tail.iter().copied().eq(at..100));
+                for i in 0..tail.len() {
+                    assert_eq!(tail.get(i), Some(&(at + i)));
+                }
+
+                vec.push(100);
+                tail.push(100);
+                assert_eq!(vec.get(at), Some(&100));
+                assert_eq!(tail.get(100 - at), Some(&100));
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_off_adopts_tail_fragments() {
+        let mut vec = SplitVec::with_linear_growth(2);
+        vec.extend(0..20);
+        let pointers: Vec<_> = vec.fragments().iter().map(|x| x.as_ptr()).collect();
+
+        let tail = vec.split_off(8);
+        assert_eq!(tail, (8..20).collect::<Vec<_>>());
+        let tail_pointers: Vec<_> = tail.fragments().iter().map(|x| x.as_ptr()).collect();
+        assert_eq!(tail_pointers, pointers[2..]);
+
+        let tail = vec.split_off(5);
+        assert_eq!(tail, &[5, 6, 7]);
+        assert_eq!(tail.fragments().len(), 1);
+    }
+
+    #[test]
+    fn split_off_copies_unadoptable_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<String, G>) {
+            vec.extend((0..100).map(|x| x.to_string()));
+
+            let tail = vec.split_off(13);
+            assert_eq!(tail, (13..100).map(|x| x.to_string()).collect::<Vec<_>>());
+            assert!(tail.fragments().iter().all(|x| !x.is_empty()));
+
+            let num_fragments = tail.fragments().len();
+            let room: Vec<_> = tail.fragments().iter().map(|x| x.room()).collect();
+            assert!(room[1..num_fragments - 1].iter().all(|x| *x == 0));
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_off_keeps_head_pinned() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..100);
+            let addresses: Vec<_> = vec.iter().map(|x| x as *const usize).collect();
+
+            let tail = vec.split_off(42);
+            assert_eq!(tail.len(), 58);
+            for (i, x) in vec.iter().enumerate() {
+                assert_eq!(x as *const usize, addresses[i]);
+            }
+        }
+
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_off_hands_over_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(vec![3, 4, 5, 6]);
+        vec.append(vec![7, 8]);
+        let split_fragment = vec.fragments()[1].as_ptr();
+        let last_fragment = vec.fragments()[2].as_ptr();
+
+        let tail = vec.split_off(5);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+        assert_eq!(tail, &[5, 6, 7, 8]);
+        assert_eq!(tail.fragments().len(), 2);
+        assert_eq!(vec.fragments()[1].as_ptr(), split_fragment);
+        assert_eq!(tail.fragments()[1].as_ptr(), last_fragment);
+
+        let mut vec = SplitVec::with_linear_growth(1);
+        vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+        let third_fragment = vec.fragments()[2].as_ptr();
+
+        let tail = vec.split_off(4);
+        assert_eq!(tail.fragments().len(), 2);
+        assert_eq!(tail.fragments()[0].as_ptr(), third_fragment);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        _ = vec.split_off(11);
+    }
+}
@@ -1,4 +1,13 @@
-use crate::{fragment::fragment_struct::Fragment, Doubling, Growth};
+use crate::{
+    algorithms,
+    fragment::fragment_struct::Fragment,
+    range_helpers::{range_end, range_start},
+    Doubling, Growth, SplitVecError,
+};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::ops::RangeBounds;
 
 /// A split vector; i.e., a vector of fragments, with the following features:
 ///
@@ -6,13 +15,18 @@ use crate::{fragment::fragment_struct::Fragment, Doubling, Growth};
 /// * Growth does not cause any memory copies.
 /// * Capacity of an already created fragment is never changed.
 /// * The above feature allows the data to stay pinned in place. Memory location of an item added to the split vector will never change unless it is removed from the vector or the vector is dropped.
-pub struct SplitVec<T, G = Doubling>
+/// * Memory of the fragments is allocated by the allocator `A`, which is the [`Global`] allocator by default.
+pub struct SplitVec<T, G = Doubling, A = Global>
 where
     G: Growth,
+    A: Allocator,
 {
     pub(crate) len: usize,
-    pub(crate) fragments: Vec<Fragment<T>>,
+    pub(crate) fragments: Vec<Fragment<T, A>>,
     pub(crate) growth: G,
+    /// Allocated fragments to be added to the vector, in order, as it grows.
+    pub(crate) spare: VecDeque<Fragment<T, A>>,
+    pub(crate) allocator: A,
 }
 
 impl<T, G> SplitVec<T, G>
@@ -20,14 +34,46 @@ where
     G: Growth,
 {
     pub(crate) fn from_raw_parts(len: usize, fragments: Vec<Fragment<T>>, growth: G) -> Self {
+        Self::from_raw_parts_in(len, fragments, growth, Global)
+    }
+}
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator,
+{
+    pub(crate) fn from_raw_parts_in(
+        len: usize,
+        fragments: Vec<Fragment<T, A>>,
+        mut growth: G,
+        allocator: A,
+    ) -> Self {
         debug_assert_eq!(len, fragments.iter().map(|x| x.len()).sum());
+        growth.fragments_mutated_from(&fragments, 0);
         Self {
             len,
             fragments,
             growth,
+            spare: VecDeque::new(),
+            allocator,
         }
     }
 
+    /// Returns a reference to the allocator which allocates the fragments of the split vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<char> = SplitVec::new_in(Global);
+    /// let _allocator: &Global = vec.allocator();
+    /// ```
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
     // get
     /// Growth strategy of the split vector.
     ///
@@ -42,7 +88,7 @@ where
     ///
     /// * `Linear` (`SplitVec::with_linear_growth`) -> O(1)
     /// * `Doubling` (`SplitVec::with_doubling_growth`) -> O(1)
-    /// * `Recursive` (`SplitVec::with_recursive_growth`) -> O(f) where f is the number of fragments; and O(1) append time complexity
+    /// * `Recursive` (`SplitVec::with_recursive_growth`) -> O(log(f)) where f is the number of fragments; and O(1) append time complexity
     pub fn growth(&self) -> &G {
         &self.growth
     }
@@ -59,9 +105,17 @@ where
     ///     * none of the fragments with indices `0..F-2` has capacity; i.e., len==capacity,
     ///     * the last fragment at position `F-1` might or might not have capacity.
     ///
+    /// Further, growth strategies might maintain a state derived from the fragments,
+    /// such as the cumulative lengths index of the [`Recursive`] growth.
+    /// Whenever the lengths or the number of fragments change through the returned reference,
+    /// the caller must call [`SplitVec::fragments_mutated_from`] with the index of the first mutated fragment
+    /// before any other method of the vector is called, in order to keep this state in sync with the fragments.
+    ///
     /// Breaking this structure invalidates the `SplitVec` struct,
     /// and its methods lead to UB.
-    pub unsafe fn fragments_mut(&mut self) -> &mut Vec<Fragment<T>> {
+    ///
+    /// [`Recursive`]: crate::Recursive
+    pub unsafe fn fragments_mut(&mut self) -> &mut Vec<Fragment<T, A>> {
         &mut self.fragments
     }
 
@@ -91,7 +145,7 @@ where
     /// assert_eq!(&[4, 5], vec.fragments()[1].as_slice());
     ///
     /// ```
-    pub fn fragments(&self) -> &[Fragment<T>] {
+    pub fn fragments(&self) -> &[Fragment<T, A>] {
         &self.fragments
     }
 
@@ -105,30 +159,20 @@ where
 
     /// Makes sure that the split vector can safely reach the given `maximum_capacity` in a concurrent program.
     /// * returns Ok of the new maximum capacity if the vector succeeds to reserve.
-    /// * returns the corresponding error message otherwise.
+    /// * returns [`SplitVecError::CapacityOverflow`] if the growth strategy cannot reach the `maximum_capacity`,
+    /// * returns [`SplitVecError::AllocationFailed`] if the fragments collection cannot be allocated.
     ///
     /// Note that this method does not allocate the `maximum_capacity`, it only ensures that the concurrent growth to this capacity is safe.
     /// In order to achieve this, it might need to extend allocation of the fragments collection.
     /// However, note that by definition number of fragments is insignificant in a split vector.
-    pub fn concurrent_reserve(&mut self, maximum_capacity: usize) -> Result<usize, String> {
+    pub fn concurrent_reserve(&mut self, maximum_capacity: usize) -> Result<usize, SplitVecError> {
         let required_num_fragments = self
             .growth
             .required_fragments_len(&self.fragments, maximum_capacity)?;
 
-        let additional_fragments = match required_num_fragments > self.fragments.capacity() {
-            true => required_num_fragments - self.fragments.capacity(),
-            false => 0,
-        };
-
-        if additional_fragments > 0 {
-            let prior_fragments_capacity = self.fragments.capacity();
-            let num_fragments = self.fragments.len();
-
-            unsafe { self.fragments.set_len(prior_fragments_capacity) };
-
-            self.fragments.reserve(additional_fragments);
-
-            unsafe { self.fragments.set_len(num_fragments) };
+        if required_num_fragments > self.fragments.capacity() {
+            self.fragments
+                .try_reserve(required_num_fragments - self.fragments.len())?;
         }
 
         Ok(self.maximum_concurrent_capacity())
@@ -170,8 +214,182 @@ where
             .get_fragment_and_inner_indices(self.len, &self.fragments, index)
     }
 
+    /// Returns the index of the partition point according to the given predicate (the index of the first element of the second partition).
+    ///
+    /// The vector is assumed to be partitioned according to the given predicate.
+    /// This means that all elements for which the predicate returns true are at the start of the vector
+    /// and all elements for which the predicate returns false are at the end.
+    ///
+    /// Similar to `binary_search_by`, positions of the vector are binary searched, where each probed element is located
+    /// through the growth strategy; once the remaining positions belong to a single fragment, the partition point is searched
+    /// only within this fragment.
+    /// The search requires O(log(n)) predicate calls; each probe takes constant time with growth strategies allowing
+    /// constant time access, O(log(f)) time with [`Recursive`](crate::Recursive) and O(f) time otherwise,
+    /// where f is the number of fragments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[1, 2, 3, 3, 5, 6, 7]);
+    ///
+    /// let i = vec.partition_point(|&x| x < 5);
+    /// assert_eq!(i, 4);
+    /// assert!(vec.iter().take(i).all(|&x| x < 5));
+    /// assert!(vec.iter().skip(i).all(|&x| !(x < 5)));
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        algorithms::binary_search::partition_point(&self.growth, self.len, &self.fragments, pred)
+    }
+
+    /// Returns an iterator over the elements of the vector starting from the element at the given `index`.
+    ///
+    /// The iterator yields the same elements as `vec.iter().skip(index)`.
+    /// When the growth strategy allows for constant time access, such as [`Doubling`] or [`Linear`](crate::Linear),
+    /// the iterator is created in constant time by directly jumping to the `index`-th element.
+    /// Otherwise, it skips whole fragments until the one containing the `index`-th element.
+    ///
+    /// Note that the same jump is also performed by the `nth` method of the iterators;
+    /// and hence, by the `skip` and `step_by` adapters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_doubling_growth();
+    /// vec.extend(0..1000);
+    ///
+    /// let mut iter = vec.iter_from(700);
+    /// assert_eq!(iter.len(), 300);
+    /// assert_eq!(iter.next(), Some(&700));
+    ///
+    /// assert_eq!(vec.iter_from(1000).next(), None);
+    /// ```
+    pub fn iter_from(&self, index: usize) -> crate::Iter<'_, T, A, G> {
+        assert!(index <= self.len, "index out of bounds");
+
+        let mut iter = crate::Iter::with_growth(&self.fragments, &self.growth, self.len);
+        if let Some(n) = index.checked_sub(1) {
+            _ = iter.nth(n);
+        }
+        iter
+    }
+
+    /// Returns an iterator over the slices which, when chained, form the elements of the vector in the given `range`.
+    ///
+    /// * yields no slices if the range is empty or out of bounds;
+    /// * yields one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok);
+    /// * yields the ordered slices of the fragments overlapping with the range otherwise.
+    ///
+    /// The iterator does not allocate; it is double ended and exact sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(vec.fragments()[0], &[0, 1, 2, 3]);
+    /// assert_eq!(vec.fragments()[1], &[4, 5, 6, 7]);
+    /// assert_eq!(vec.fragments()[2], &[8, 9]);
+    ///
+    /// // single fragment
+    /// let mut slices = vec.slices_iter(5..7);
+    /// assert_eq!(slices.len(), 1);
+    /// assert_eq!(slices.next(), Some(&[5, 6][..]));
+    /// assert_eq!(slices.next(), None);
+    ///
+    /// // fragmented
+    /// let mut slices = vec.slices_iter(3..9);
+    /// assert_eq!(slices.len(), 3);
+    /// assert_eq!(slices.next_back(), Some(&[8][..]));
+    /// assert_eq!(slices.next(), Some(&[3][..]));
+    /// assert_eq!(slices.next(), Some(&[4, 5, 6, 7][..]));
+    /// assert_eq!(slices.next(), None);
+    ///
+    /// // out of bounds
+    /// assert_eq!(vec.slices_iter(5..12).len(), 0);
+    /// assert_eq!(vec.slices_iter(10..11).len(), 0);
+    /// ```
+    pub fn slices_iter<R: RangeBounds<usize>>(&self, range: R) -> crate::SlicesIter<'_, T, A> {
+        match self.slices_locations(range) {
+            Some((first, last)) => crate::SlicesIter::new(&self.fragments, first, last),
+            None => Default::default(),
+        }
+    }
+
+    /// Returns a mutable iterator over the slices which, when chained, form the elements of the vector in the given `range`.
+    ///
+    /// * yields no slices if the range is empty or out of bounds;
+    /// * yields one slice if the range completely belongs to one fragment (in this case `try_get_slice` would return Ok);
+    /// * yields the ordered slices of the fragments overlapping with the range otherwise.
+    ///
+    /// The iterator does not allocate; it is double ended and exact sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// let slices = vec.slices_iter_mut(2..9);
+    /// assert_eq!(slices.len(), 3);
+    /// for (s, slice) in slices.rev().enumerate() {
+    ///     for x in slice {
+    ///         *x += 10 * (s + 1);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vec.fragments()[0], &[0, 1, 32, 33]);
+    /// assert_eq!(vec.fragments()[1], &[24, 25, 26, 27]);
+    /// assert_eq!(vec.fragments()[2], &[18, 9]);
+    ///
+    /// assert_eq!(vec.slices_iter_mut(7..7).len(), 0);
+    /// ```
+    pub fn slices_iter_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> crate::SlicesIterMut<'_, T, A> {
+        match self.slices_locations(range) {
+            Some((first, last)) => crate::SlicesIterMut::new(&mut self.fragments, first, last),
+            None => Default::default(),
+        }
+    }
+
     // helpers
 
+    /// Returns the fragment and inner indices of the first and last elements of the `range`;
+    /// None if the range is empty or out of bounds.
+    fn slices_locations<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        let a = range_start(&range);
+        let b = range_end(&range, self.len);
+        match a < b && b <= self.len {
+            true => {
+                let first = self.get_fragment_and_inner_indices(a)?;
+                let last = self.get_fragment_and_inner_indices(b - 1)?;
+                Some((first, last))
+            }
+            false => None,
+        }
+    }
+
     pub(crate) fn has_capacity_for_one(&self) -> bool {
         self.fragments
             .last()
@@ -179,40 +397,51 @@ where
             .unwrap_or(false)
     }
 
-    /// Adds a new fragment to fragments of the split vector; returns the capacity of the new fragment.
-    #[inline(always)]
-    pub(crate) fn add_fragment(&mut self) -> usize {
-        self.add_fragment_get_fragment_capacity(false)
-    }
-
-    /// Adds a new fragment and return the capacity of the added (now last) fragment.
-    fn add_fragment_get_fragment_capacity(&mut self, zeroed: bool) -> usize {
-        let new_fragment_capacity = self.growth.new_fragment_capacity(&self.fragments);
-
-        let mut new_fragment = Fragment::new(new_fragment_capacity);
-        if zeroed {
-            // SAFETY: new_fragment empty with len=0, zeroed elements will not be read with safe api
-            unsafe { new_fragment.zero() };
+    /// Reserves room in the fragments collection for the fragments required to push `additional` elements.
+    ///
+    /// Note that the fragments are not allocated; only the fragments collection is.
+    /// Reservation stops early if the growth strategy computes a zero fragment capacity,
+    /// in which case the fragments are added one by one as the vector grows.
+    pub(crate) fn reserve_fragments_for(&mut self, additional: usize) {
+        let mut available = self.fragments.last().map(|f| f.room()).unwrap_or(0);
+        if available >= additional {
+            return;
         }
 
-        self.fragments.push(new_fragment);
-
-        new_fragment_capacity
-    }
+        let mut capacities: Vec<_> = self.fragments.iter().map(|f| f.capacity()).collect();
+        while available < additional {
+            let capacity = self
+                .growth
+                .new_fragment_capacity_from(capacities.iter().copied());
+            if capacity == 0 {
+                break;
+            }
+            available = available.saturating_add(capacity);
+            capacities.push(capacity);
+        }
 
-    pub(crate) fn add_fragment_with_first_value(&mut self, first_value: T) {
-        let capacity = self.growth.new_fragment_capacity(&self.fragments);
-        let new_fragment = Fragment::new_with_first_value(capacity, first_value);
-        self.fragments.push(new_fragment);
+        self.fragments
+            .reserve(capacities.len() - self.fragments.len());
     }
 
     pub(crate) fn drop_last_empty_fragment(&mut self) {
         let drop_empty_last_fragment = self.fragments.last().map(|f| f.is_empty()).unwrap_or(false);
         if drop_empty_last_fragment {
             _ = self.fragments.pop();
+            self.fragments_mutated_from(self.fragments.len());
         }
     }
 
+    /// Informs the growth strategy that the fragments starting from the `begin_fragment`-th fragment have been mutated;
+    /// see [`Growth::fragments_mutated_from`].
+    ///
+    /// This method must be called after the lengths or the number of fragments are changed through [`SplitVec::fragments_mut`].
+    #[inline(always)]
+    pub fn fragments_mutated_from(&mut self, begin_fragment: usize) {
+        self.growth
+            .fragments_mutated_from(&self.fragments, begin_fragment);
+    }
+
     #[inline(always)]
     pub(crate) unsafe fn growth_get_ptr_mut(&mut self, index: usize) -> Option<*mut T> {
         self.growth.get_ptr_mut(&mut self.fragments, index)
@@ -225,17 +454,101 @@ where
     /// Note that this method does not allocate the `maximum_capacity`, it only ensures that the concurrent growth to this capacity is safe.
     /// In order to achieve this, it might need to extend allocation of the fragments collection.
     /// However, note that by definition number of fragments is insignificant in a split vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the maximum capacity cannot be reserved; see [`SplitVec::try_reserve_maximum_concurrent_capacity`] for the fallible version.
     pub fn reserve_maximum_concurrent_capacity(&mut self, new_maximum_capacity: usize) -> usize {
+        self.try_reserve_maximum_concurrent_capacity(new_maximum_capacity)
+            .expect("Failed to reserve maximum capacity")
+    }
+
+    /// Makes sure that the split vector can safely reach the given `maximum_capacity` in a concurrent program.
+    ///
+    /// Returns the new maximum capacity; or the error if the maximum capacity cannot be reserved, see [`SplitVec::concurrent_reserve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<char, Doubling> = SplitVec::with_doubling_growth();
+    /// assert!(vec.try_reserve_maximum_concurrent_capacity(1000).expect("reachable") >= 1000);
+    ///
+    /// let max = vec.try_reserve_maximum_concurrent_capacity(usize::MAX);
+    /// assert!(matches!(max, Err(SplitVecError::CapacityOverflow { .. })));
+    /// ```
+    pub fn try_reserve_maximum_concurrent_capacity(
+        &mut self,
+        new_maximum_capacity: usize,
+    ) -> Result<usize, SplitVecError> {
         let current_max = self.maximum_concurrent_capacity();
         match current_max < new_maximum_capacity {
-            true => {
-                self.concurrent_reserve(new_maximum_capacity)
-                    .expect("Failed to reserve maximum capacity");
-                self.maximum_concurrent_capacity()
+            true => self.concurrent_reserve(new_maximum_capacity),
+            false => Ok(current_max),
+        }
+    }
+}
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Adds a new fragment to fragments of the split vector; returns the capacity of the new fragment.
+    #[inline(always)]
+    pub(crate) fn add_fragment(&mut self) -> usize {
+        self.add_fragment_get_fragment_capacity(false)
+    }
+
+    /// Adds a new fragment and return the capacity of the added (now last) fragment.
+    fn add_fragment_get_fragment_capacity(&mut self, zeroed: bool) -> usize {
+        let new_fragment_capacity = self.growth.new_fragment_capacity(&self.fragments);
+
+        let mut new_fragment = self.take_spare_or_allocate(new_fragment_capacity);
+        if zeroed {
+            // SAFETY: new_fragment empty with len=0, zeroed elements will not be read with safe api
+            unsafe { new_fragment.zero() };
+        }
+
+        self.fragments.push(new_fragment);
+        self.fragments_mutated_from(self.fragments.len() - 1);
+
+        new_fragment_capacity
+    }
+
+    pub(crate) fn add_fragment_with_first_value(&mut self, first_value: T) {
+        let capacity = self.growth.new_fragment_capacity(&self.fragments);
+        let new_fragment = match self.take_spare(capacity) {
+            Some(mut fragment) => {
+                fragment.push(first_value);
+                fragment
             }
-            false => self.maximum_concurrent_capacity(),
+            None => {
+                self.spare.clear();
+                Fragment::new_with_first_value_in(capacity, first_value, self.allocator.clone())
+            }
+        };
+        self.fragments.push(new_fragment);
+        self.fragments_mutated_from(self.fragments.len() - 1);
+    }
+
+    /// Pops and returns the next spare fragment if it has the required `capacity`; None otherwise.
+    fn take_spare(&mut self, capacity: usize) -> Option<Fragment<T, A>> {
+        match self.spare.front().map(|x| x.capacity()) == Some(capacity) {
+            true => self.spare.pop_front(),
+            false => None,
         }
     }
+
+    /// Returns the next spare fragment if it has the required `capacity`;
+    /// otherwise, drops the spare fragments which no longer fit the growth and allocates a new fragment.
+    fn take_spare_or_allocate(&mut self, capacity: usize) -> Fragment<T, A> {
+        self.take_spare(capacity).unwrap_or_else(|| {
+            self.spare.clear();
+            Fragment::new_in(capacity, self.allocator.clone())
+        })
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,12 @@ macro_rules! test_all_growth_types {
         $fun::<$crate::Linear>(SplitVec::with_linear_growth(2));
         $fun::<$crate::Doubling>(SplitVec::with_doubling_growth());
         $fun::<$crate::Recursive>(SplitVec::with_recursive_growth());
+        $fun::<$crate::DoublingFrom<3>>(SplitVec::with_doubling_growth_from());
+        $fun::<$crate::Geometric>(SplitVec::with_geometric_growth(4, 1.5));
+        $fun::<$crate::CappedDoubling>(SplitVec::with_capped_doubling_growth(4));
+        $fun::<$crate::ByteBudget<_>>(SplitVec::with_byte_budget_growth(64));
+        $fun::<$crate::ConstLinear<3>>(SplitVec::with_const_linear_growth());
+        $fun::<$crate::LinearN>(SplitVec::with_linear_n_growth(3));
     };
 }
 
@@ -0,0 +1,317 @@
+use crate::{Fragment, Growth, SplitVec, SplitVecError};
+use alloc::vec::Vec;
+use allocator_api2::alloc::Allocator;
+
+impl<T, G, A> SplitVec<T, G, A>
+where
+    G: Growth,
+    A: Allocator + Clone,
+{
+    /// Tries to reserve capacity for at least `additional` more elements to be pushed to the vector.
+    ///
+    /// The fragments required to hold the `additional` elements are allocated in advance;
+    /// they are kept as spare fragments and are added to the vector, in order, as it grows.
+    /// Therefore, the capacity reported by `capacity` increases only when the vector grows into the reserved fragments.
+    /// Pushing up to `additional` elements afterwards does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SplitVecError::CapacityOverflow`] if the required capacity exceeds `isize::MAX` bytes
+    /// or cannot be reached since the growth strategy computes a zero fragment capacity,
+    /// or [`SplitVecError::AllocationFailed`] if the allocator reports a failure.
+    /// In either case, the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2]);
+    ///
+    /// vec.try_reserve(10).expect("the allocation is small enough");
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// assert_eq!(vec.fragments().len(), 1);
+    ///
+    /// vec.extend_from_slice(&[3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    /// assert_eq!(vec.fragments().len(), 4);
+    ///
+    /// let result = vec.try_reserve(usize::MAX);
+    /// assert!(matches!(result, Err(SplitVecError::CapacityOverflow { .. })));
+    /// assert_eq!(vec.len(), 13);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), SplitVecError> {
+        let mut available = self.fragments.last().map(|f| f.room()).unwrap_or(0);
+        if available >= additional {
+            return Ok(());
+        }
+
+        let max_len = isize::MAX as usize / core::mem::size_of::<T>().max(1);
+        match self.len.checked_add(additional) {
+            Some(len) if len <= max_len => {}
+            _ => return Err(SplitVecError::CapacityOverflow { max: max_len }),
+        }
+
+        let mut capacities = Vec::new();
+        capacities.try_reserve(self.fragments.len() + self.spare.len() + 1)?;
+        capacities.extend(self.fragments.iter().map(|f| f.capacity()));
+
+        let mut num_spare = 0;
+        for fragment in &self.spare {
+            let capacity = self
+                .growth
+                .new_fragment_capacity_from(capacities.iter().copied());
+            if available >= additional || fragment.capacity() != capacity {
+                break;
+            }
+            capacities.push(capacity);
+            available = available.saturating_add(capacity);
+            num_spare += 1;
+        }
+
+        let mut new_fragments = Vec::new();
+        while available < additional {
+            let capacity = self
+                .growth
+                .new_fragment_capacity_from(capacities.iter().copied());
+            if capacity == 0 {
+                let max = capacities.iter().sum();
+                return Err(SplitVecError::CapacityOverflow { max });
+            }
+            capacities.try_reserve(1)?;
+            capacities.push(capacity);
+            new_fragments.try_reserve(1)?;
+            new_fragments.push(Fragment::try_new_in(capacity, self.allocator.clone())?);
+            available = available.saturating_add(capacity);
+        }
+
+        self.fragments
+            .try_reserve(capacities.len() - self.fragments.len())?;
+
+        if !new_fragments.is_empty() {
+            self.spare.try_reserve(new_fragments.len())?;
+            self.spare.truncate(num_spare);
+            self.spare.extend(new_fragments);
+        }
+
+        Ok(())
+    }
+
+    /// Tries to append an element to the back of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error if a new fragment is required and its allocation fails.
+    /// In this case, the vector is left unchanged and the `value` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// for i in 0..10 {
+    ///     vec.try_push(i).expect("the allocation is small enough");
+    /// }
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), SplitVecError> {
+        self.try_reserve(1)?;
+        self.push(value);
+        Ok(())
+    }
+
+    /// Tries to clone and append all elements in the `other` slice to the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error if the allocation of the required fragments fails.
+    /// In this case, none of the elements are appended and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.try_extend_from_slice(&[0, 1, 2, 3, 4, 5]).expect("the allocation is small enough");
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), SplitVecError>
+    where
+        T: Clone,
+    {
+        self.try_reserve(other.len())?;
+        self.extend_from_slice(other);
+        Ok(())
+    }
+
+    /// Tries to insert an element at position `index` within the vector, shifting all elements after it to the right.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error if a new fragment is required and its allocation fails.
+    /// In this case, the vector is left unchanged and the `value` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// vec.try_insert(1, 42).expect("the allocation is small enough");
+    /// assert_eq!(vec, &[0, 42, 1, 2, 3]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), SplitVecError> {
+        self.try_reserve(1)?;
+        self.insert(index, value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    fn fragment_capacities<T, G: Growth>(vec: &SplitVec<T, G>) -> Vec<usize> {
+        vec.fragments().iter().map(|f| f.capacity()).collect()
+    }
+
+    #[test]
+    fn try_reserve_allocates_in_advance() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for additional in [0, 1, 5, 33, 100] {
+                vec.clear();
+                vec.extend(0..7);
+
+                vec.try_reserve(additional).expect("small allocation");
+                assert_eq!(vec.len(), 7);
+
+                let spare_room: usize = vec.spare.iter().map(|f| f.capacity()).sum();
+                let last_room = vec.fragments().last().map(|f| f.room()).unwrap_or(0);
+                assert!(last_room + spare_room >= additional);
+
+                let mut expected = SplitVec::with_growth(vec.growth().clone());
+                expected.extend(0..7);
+                for i in 7..(7 + additional) {
+                    vec.push(i);
+                    expected.push(i);
+                }
+
+                assert_eq!(vec, expected);
+                assert_eq!(fragment_capacities(&vec), fragment_capacities(&expected));
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn try_reserve_reuses_spare_fragments() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.try_reserve(40).expect("small allocation");
+            let num_spare = vec.spare.len();
+
+            vec.try_reserve(20).expect("small allocation");
+            assert_eq!(vec.spare.len(), num_spare);
+
+            vec.try_reserve(80).expect("small allocation");
+            assert!(vec.spare.len() >= num_spare);
+
+            vec.extend(0..80);
+            assert_eq!(vec, (0..80).collect::<Vec<_>>());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn try_reserve_stale_spare_fragments() {
+        let mut vec = SplitVec::with_doubling_growth();
+        vec.extend(0..100);
+        vec.try_reserve(100).expect("small allocation");
+        assert!(!vec.spare.is_empty());
+
+        vec.clear();
+        vec.extend(0..300);
+
+        assert_eq!(vec, (0..300).collect::<Vec<_>>());
+        let mut expected = SplitVec::with_doubling_growth();
+        expected.extend(0..300);
+        assert_eq!(fragment_capacities(&vec), fragment_capacities(&expected));
+    }
+
+    #[test]
+    fn try_methods_on_failure() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            vec.extend(0..33);
+            let capacities = fragment_capacities(&vec);
+
+            let max = isize::MAX as usize / 8;
+            assert_eq!(
+                vec.try_reserve(usize::MAX),
+                Err(SplitVecError::CapacityOverflow { max })
+            );
+            assert_eq!(
+                vec.try_reserve(max),
+                Err(SplitVecError::CapacityOverflow { max })
+            );
+            assert!(vec.try_extend_from_slice(&vec![0; 1000]).is_ok());
+            vec.truncate(33);
+            assert!(vec.try_reserve(usize::MAX - 33).is_err());
+            assert_eq!(vec, (0..33).collect::<Vec<_>>());
+            assert!(fragment_capacities(&vec).starts_with(&capacities));
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn try_push_insert_extend() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let mut expected = vec![];
+            for i in 0..100 {
+                vec.try_push(i).expect("small allocation");
+                expected.push(i);
+            }
+            for i in 0..50 {
+                let index = (i * 7) % (vec.len() + 1);
+                vec.try_insert(index, 1000 + i).expect("small allocation");
+                expected.insert(index, 1000 + i);
+            }
+            vec.try_extend_from_slice(&expected.clone())
+                .expect("small allocation");
+            expected.extend_from_slice(&expected.clone());
+
+            assert_eq!(vec, expected);
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn try_methods_on_zero_capacity_growth() {
+        let mut vec: SplitVec<usize, Recursive> = Vec::new().into();
+
+        assert_eq!(
+            vec.try_reserve(5),
+            Err(SplitVecError::CapacityOverflow { max: 0 })
+        );
+        assert!(vec.try_push(0).is_err());
+        assert!(vec.try_insert(0, 0).is_err());
+        assert!(vec.try_extend_from_slice(&[0, 1, 2]).is_err());
+        assert!(vec.is_empty());
+        assert!(vec.spare.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_insert_out_of_bounds() {
+        let mut vec: SplitVec<_> = SplitVec::new();
+        vec.extend_from_slice(&[0, 1, 2]);
+        _ = vec.try_insert(4, 3);
+    }
+}
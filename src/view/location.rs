@@ -0,0 +1,36 @@
+use crate::Fragment;
+use allocator_api2::alloc::Allocator;
+
+/// Location of an element of a view with respect to its front slice, fragments and back slice.
+pub(crate) enum Location {
+    /// Index within the front slice.
+    Front(usize),
+    /// Index of the fragment and index within the fragment.
+    Fragment(usize, usize),
+    /// Index within the back slice, which might be out of bounds.
+    Back(usize),
+}
+
+/// Returns the location of the `index`-th element of the view consisting of a front slice
+/// with `front_len` elements, followed by the `fragments` and the back slice.
+///
+/// The fragments are scanned; hence, the location is found in O(f) time where f is the number of `fragments`.
+pub(crate) fn locate<T, A: Allocator>(
+    front_len: usize,
+    fragments: &[Fragment<T, A>],
+    index: usize,
+) -> Location {
+    if index < front_len {
+        return Location::Front(index);
+    }
+
+    let mut index = index - front_len;
+    for (f, fragment) in fragments.iter().enumerate() {
+        if index < fragment.len() {
+            return Location::Fragment(f, index);
+        }
+        index -= fragment.len();
+    }
+
+    Location::Back(index)
+}
@@ -0,0 +1,4 @@
+mod location;
+mod new_view;
+pub(crate) mod split_vec_view;
+pub(crate) mod split_vec_view_mut;
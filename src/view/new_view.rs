@@ -0,0 +1,100 @@
+use crate::{range_helpers::range_bounds, Growth, SplitVec, SplitVecView, SplitVecViewMut};
+use allocator_api2::alloc::Allocator;
+use core::ops::RangeBounds;
+
+impl<T, G: Growth, A: Allocator> SplitVec<T, G, A> {
+    /// Returns a view of the elements in the given `range` of the vector.
+    ///
+    /// Unlike [`SplitVec::try_get_slice`], a view can be created for any range, including the ranges spanning multiple fragments.
+    /// Creating the view does not allocate; see [`SplitVecView`] for details.
+    ///
+    /// Indexing by a range, such as `&vec[3..9]`, returns a slice only when the range lies within a single fragment;
+    /// a view, on the other hand, can be created for any range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than the range end, or if the range end is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// assert_eq!(vec.try_get_slice(3..9), SplitVecSlice::Fragmented(0, 2));
+    ///
+    /// let view = vec.view(3..9);
+    /// assert_eq!(view, &[3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(view.iter().sum::<i32>(), 33);
+    /// ```
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> SplitVecView<'_, T, A> {
+        let (a, b) = range_bounds(&range, self.len);
+        match self.view_locations(a, b) {
+            None => SplitVecView::new(&[], &[], &[]),
+            Some(((sf, si), (ef, ei))) if sf == ef => {
+                SplitVecView::new(&self.fragments[sf][si..ei], &[], &[])
+            }
+            Some(((sf, si), (ef, ei))) => SplitVecView::new(
+                &self.fragments[sf][si..],
+                &self.fragments[(sf + 1)..ef],
+                &self.fragments[ef][..ei],
+            ),
+        }
+    }
+
+    /// Returns a mutable view of the elements in the given `range` of the vector.
+    ///
+    /// Creating the view does not allocate; see [`SplitVecViewMut`] for details.
+    ///
+    /// Indexing by a range, such as `&mut vec[3..9]`, returns a mutable slice only when the range lies within a single fragment;
+    /// a mutable view, on the other hand, can be created for any range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than the range end, or if the range end is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// let mut view = vec.view_mut(3..9);
+    /// view.iter_mut().for_each(|x| *x = 0);
+    ///
+    /// assert_eq!(vec, &[0, 1, 2, 0, 0, 0, 0, 0, 0, 9]);
+    /// ```
+    pub fn view_mut<R: RangeBounds<usize>>(&mut self, range: R) -> SplitVecViewMut<'_, T, A> {
+        let (a, b) = range_bounds(&range, self.len);
+        match self.view_locations(a, b) {
+            None => SplitVecViewMut::new(&mut [], &mut [], &mut []),
+            Some(((sf, si), (ef, ei))) if sf == ef => {
+                SplitVecViewMut::new(&mut self.fragments[sf][si..ei], &mut [], &mut [])
+            }
+            Some(((sf, si), (ef, ei))) => {
+                let (first, rest) = self.fragments[sf..=ef].split_at_mut(1);
+                let (middle, last) = rest.split_at_mut(ef - sf - 1);
+                SplitVecViewMut::new(&mut first[0][si..], middle, &mut last[0][..ei])
+            }
+        }
+    }
+
+    // helpers
+
+    /// Returns the location of the first element and the exclusive end location within the fragment of the last element
+    /// of the non-empty range `[a, b)`; returns None if the range is empty.
+    fn view_locations(&self, a: usize, b: usize) -> Option<((usize, usize), (usize, usize))> {
+        match a < b {
+            true => {
+                let begin = self.get_fragment_and_inner_indices(a);
+                let last = self.get_fragment_and_inner_indices(b - 1);
+                begin.zip(last.map(|(f, i)| (f, i + 1)))
+            }
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,495 @@
+use super::location::{locate, Location};
+use crate::{range_helpers::range_bounds, Fragment, Iter};
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::{fmt::Debug, ops::Index, ops::RangeBounds};
+
+/// A borrowed view of a range of elements of a [`SplitVec`](crate::SplitVec).
+///
+/// A view is to a split vector what a slice is to a standard vector.
+/// Unlike `SplitVec::try_get_slice`, a view can be created for any range, including the ranges spanning multiple fragments.
+/// It is created without any allocation and can be passed around cheaply since it is `Copy`.
+///
+/// Internally, the view is composed of a front slice, followed by a sequence of complete fragments and a back slice;
+/// therefore, splitting a view or creating a nested view never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_linear_growth(2);
+/// vec.extend(0..10);
+///
+/// let view = vec.view(3..9); // spans three fragments
+/// assert_eq!(view.len(), 6);
+/// assert_eq!(view, &[3, 4, 5, 6, 7, 8]);
+/// assert_eq!(view[2], 5);
+/// assert_eq!(view.first(), Some(&3));
+///
+/// let (left, right) = view.split_at(2);
+/// assert_eq!(left, &[3, 4]);
+/// assert_eq!(right, &[5, 6, 7, 8]);
+///
+/// let nested = right.view(1..3);
+/// assert_eq!(nested.to_vec(), vec![6, 7]);
+/// ```
+pub struct SplitVecView<'a, T, A: Allocator = Global> {
+    front: &'a [T],
+    fragments: &'a [Fragment<T, A>],
+    back: &'a [T],
+    len: usize,
+}
+
+impl<'a, T, A: Allocator> Clone for SplitVecView<'a, T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, A: Allocator> Copy for SplitVecView<'a, T, A> {}
+
+impl<'a, T, A: Allocator> SplitVecView<'a, T, A> {
+    /// Creates the view of elements of the `front` slice, followed by elements of the `fragments` and elements of the `back` slice.
+    pub(crate) fn new(front: &'a [T], fragments: &'a [Fragment<T, A>], back: &'a [T]) -> Self {
+        let fragments_len: usize = fragments.iter().map(|x| x.len()).sum();
+        Self {
+            front,
+            fragments,
+            back,
+            len: front.len() + fragments_len + back.len(),
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// assert_eq!(vec.view(10..20).len(), 10);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has a length of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// assert!(vec.view(10..10).is_empty());
+    /// assert!(!vec.view(10..11).is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the `index`-th element of the view; None if the index is out of bounds.
+    ///
+    /// The element is located by scanning the fragments spanned by the view; hence, random access on a view takes O(f) time,
+    /// where f is the number of these fragments, even when the growth strategy of the vector allows for constant time access.
+    /// When random access is frequent, the element can be accessed on the vector by offsetting the index by the start of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view(10..20);
+    ///
+    /// assert_eq!(view.get(0), Some(&10));
+    /// assert_eq!(view.get(9), Some(&19));
+    /// assert_eq!(view.get(10), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let (front, fragments, back) = self.parts();
+        match locate(front.len(), fragments, index) {
+            Location::Front(i) => front.get(i),
+            Location::Fragment(f, i) => fragments[f].get(i),
+            Location::Back(i) => back.get(i),
+        }
+    }
+
+    /// Returns a reference to the first element of the view; None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    ///
+    /// assert_eq!(vec.view(10..20).first(), Some(&10));
+    /// assert_eq!(vec.view(10..10).first(), None);
+    /// ```
+    pub fn first(&self) -> Option<&'a T> {
+        self.slices().find_map(|x| x.first())
+    }
+
+    /// Returns a reference to the last element of the view; None if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    ///
+    /// assert_eq!(vec.view(10..20).last(), Some(&19));
+    /// assert_eq!(vec.view(10..10).last(), None);
+    /// ```
+    pub fn last(&self) -> Option<&'a T> {
+        self.slices().rev().find_map(|x| x.last())
+    }
+
+    /// Returns an iterator over the elements of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view(10..20);
+    ///
+    /// assert_eq!(view.iter().sum::<usize>(), (10..20).sum());
+    /// assert_eq!(view.iter().rev().next(), Some(&19));
+    /// ```
+    pub fn iter(&self) -> Iter<'a, T, A> {
+        Iter::from_parts(self.front, self.fragments, self.back)
+    }
+
+    /// Divides the view into two views at the index `mid`.
+    ///
+    /// The first view contains elements at positions `[0, mid)` and the second contains those at `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view(10..20);
+    ///
+    /// let (left, right) = view.split_at(3);
+    /// assert_eq!(left, &[10, 11, 12]);
+    /// assert_eq!(right, &[13, 14, 15, 16, 17, 18, 19]);
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        assert!(
+            mid <= self.len,
+            "mid (is {}) should be <= len (is {})",
+            mid,
+            self.len
+        );
+
+        let (front, fragments, back) = self.parts();
+        match locate(front.len(), fragments, mid) {
+            Location::Front(i) => {
+                let (left, right) = front.split_at(i);
+                (Self::new(left, &[], &[]), Self::new(right, fragments, back))
+            }
+            Location::Fragment(f, i) => {
+                let (left, right) = fragments[f].split_at(i);
+                (
+                    Self::new(front, &fragments[..f], left),
+                    Self::new(right, &fragments[(f + 1)..], back),
+                )
+            }
+            Location::Back(i) => {
+                let (left, right) = back.split_at(i);
+                (
+                    Self::new(front, fragments, left),
+                    Self::new(&[], &[], right),
+                )
+            }
+        }
+    }
+
+    /// Returns the view of the given `range` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than the range end, or if the range end is greater than the length of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view(10..20);
+    ///
+    /// assert_eq!(view.view(2..5), &[12, 13, 14]);
+    /// assert_eq!(view.view(8..), &[18, 19]);
+    /// ```
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let (a, b) = range_bounds(&range, self.len);
+        let (_, right) = self.split_at(a);
+        let (view, _) = right.split_at(b - a);
+        view
+    }
+
+    /// Copies the elements of the view into a new `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).map(|x| x.to_string()).collect();
+    /// let view = vec.view(10..13);
+    ///
+    /// assert_eq!(view.to_vec(), vec!["10", "11", "12"]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        for slice in self.slices() {
+            vec.extend_from_slice(slice);
+        }
+        vec
+    }
+
+    /// Copies all elements of the view into `dst` with bulk memory copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` is not equal to the length of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view(10..13);
+    ///
+    /// let mut dst = [0; 3];
+    /// view.copy_to_slice(&mut dst);
+    /// assert_eq!(dst, [10, 11, 12]);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            dst.len(),
+            self.len,
+            "destination slice length (is {}) should be equal to the view length (is {})",
+            dst.len(),
+            self.len
+        );
+
+        let mut begin = 0;
+        for slice in self.slices() {
+            let end = begin + slice.len();
+            dst[begin..end].copy_from_slice(slice);
+            begin = end;
+        }
+    }
+
+    // helpers
+
+    /// Returns the front slice, complete fragments and the back slice of the view.
+    #[inline(always)]
+    pub(crate) fn parts(&self) -> (&'a [T], &'a [Fragment<T, A>], &'a [T]) {
+        (self.front, self.fragments, self.back)
+    }
+
+    /// Returns the contiguous slices of the view in order, some of which might be empty.
+    pub(crate) fn slices(&self) -> impl DoubleEndedIterator<Item = &'a [T]> {
+        let (front, fragments, back) = self.parts();
+        core::iter::once(front)
+            .chain(fragments.iter().map(|x| &x[..]))
+            .chain(core::iter::once(back))
+    }
+}
+
+impl<'a, T, A: Allocator> Index<usize> for SplitVecView<'a, T, A> {
+    type Output = T;
+
+    /// Returns a reference to the `index`-th element of the view.
+    ///
+    /// Locating the element takes O(f) time, where f is the number of fragments spanned by the view; see [`SplitVecView::get`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index is out of bounds")
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for SplitVecView<'a, T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: Debug, A: Allocator> Debug for SplitVecView<'a, T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, U, A: Allocator> PartialEq<U> for SplitVecView<'a, T, A>
+where
+    U: AsRef<[T]>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &U) -> bool {
+        let other = other.as_ref();
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b, T: PartialEq, A: Allocator> PartialEq<SplitVecView<'b, T, A>>
+    for SplitVecView<'a, T, A>
+{
+    fn eq(&self, other: &SplitVecView<'b, T, A>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    fn ranges(len: usize) -> Vec<(usize, usize)> {
+        let mut ranges = vec![(0, 0), (0, len), (len, len)];
+        for a in [0, 1, 3, 4, 7, 12, 33] {
+            for b in [a, a + 1, a + 5, a + 17, len] {
+                if a <= b && b <= len {
+                    ranges.push((a, b));
+                }
+            }
+        }
+        ranges
+    }
+
+    #[test]
+    fn view() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            for len in [0, 1, 4, 5, 33, 100] {
+                vec.clear();
+                vec.extend(0..len);
+                let expected: Vec<_> = (0..len).collect();
+
+                for (a, b) in ranges(len) {
+                    let view = vec.view(a..b);
+                    let slice = &expected[a..b];
+
+                    assert_eq!(view.len(), b - a);
+                    assert_eq!(view.is_empty(), a == b);
+                    assert_eq!(view, slice);
+                    assert_eq!(view.first(), slice.first());
+                    assert_eq!(view.last(), slice.last());
+                    assert_eq!(view.to_vec(), slice.to_vec());
+                    assert!(view.iter().rev().eq(slice.iter().rev()));
+                    assert_eq!(view.iter().len(), slice.len());
+
+                    for i in 0..(b - a + 2) {
+                        assert_eq!(view.get(i), slice.get(i));
+                    }
+                    for i in 0..(b - a) {
+                        assert_eq!(view[i], slice[i]);
+                    }
+
+                    let mut dst = vec![0; b - a];
+                    view.copy_to_slice(&mut dst);
+                    assert_eq!(dst, slice);
+                }
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_at_and_nested_views() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let len = 100;
+            vec.extend(0..len);
+            let expected: Vec<_> = (0..len).collect();
+
+            for (a, b) in ranges(len) {
+                let view = vec.view(a..b);
+                let slice = &expected[a..b];
+
+                for mid in 0..=(b - a) {
+                    let (left, right) = view.split_at(mid);
+                    let (expected_left, expected_right) = slice.split_at(mid);
+                    assert_eq!(left, expected_left);
+                    assert_eq!(right, expected_right);
+                }
+
+                for (c, d) in ranges(b - a) {
+                    assert_eq!(view.view(c..d), &slice[c..d]);
+                    assert_eq!(view.view(c..d), vec.view((a + c)..(a + d)));
+                }
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn view_with_empty_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(vec![3]);
+        vec.append(vec![4, 5, 6, 7]);
+        for f in [1, 3] {
+            unsafe { vec.fragments_mut().insert(f, Fragment::new(0)) };
+        }
+        vec.fragments_mutated_from(0);
+
+        let view = vec.view(2..7);
+        assert_eq!(view, &[2, 3, 4, 5, 6]);
+        assert_eq!(view.split_at(1).1, &[3, 4, 5, 6]);
+        assert_eq!(view.split_at(2).1, &[4, 5, 6]);
+        assert_eq!(view.view(1..2).first(), Some(&3));
+        assert_eq!(view.view(1..2).last(), Some(&3));
+        assert_eq!(format!("{:?}", view), "[2, 3, 4, 5, 6]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_out_of_bounds() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let _ = vec.view(5..11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let _ = vec.view(5..8).split_at(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_to_slice_length_mismatch() {
+        let vec: SplitVec<_> = (0..10).collect();
+        let mut dst = [0; 4];
+        vec.view(5..8).copy_to_slice(&mut dst);
+    }
+}
@@ -0,0 +1,516 @@
+use super::location::{locate, Location};
+use crate::{range_helpers::range_bounds, Fragment, Iter, IterMut, SplitVecView};
+use alloc::vec::Vec;
+use allocator_api2::alloc::{Allocator, Global};
+use core::{
+    fmt::Debug,
+    ops::{Index, IndexMut, RangeBounds},
+};
+
+/// A mutable borrowed view of a range of elements of a [`SplitVec`](crate::SplitVec).
+///
+/// A mutable view is to a split vector what a mutable slice is to a standard vector.
+/// It can be created for any range, including the ranges spanning multiple fragments, without any allocation.
+///
+/// Internally, the view is composed of a front slice, followed by a sequence of complete fragments and a back slice;
+/// therefore, splitting a view or creating a nested view never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use orx_split_vec::*;
+///
+/// let mut vec = SplitVec::with_linear_growth(2);
+/// vec.extend(0..10);
+///
+/// let mut view = vec.view_mut(3..9); // spans three fragments
+/// view[0] = 42;
+/// for x in view.iter_mut().skip(1) {
+///     *x *= 10;
+/// }
+///
+/// let (mut left, mut right) = view.split_at_mut(3);
+/// left.copy_from_slice(&[1, 2, 3]);
+/// *right.last_mut().unwrap() = 7;
+///
+/// assert_eq!(vec, &[0, 1, 2, 1, 2, 3, 60, 70, 7, 9]);
+/// ```
+pub struct SplitVecViewMut<'a, T, A: Allocator = Global> {
+    front: &'a mut [T],
+    fragments: &'a mut [Fragment<T, A>],
+    back: &'a mut [T],
+    len: usize,
+}
+
+impl<'a, T, A: Allocator> SplitVecViewMut<'a, T, A> {
+    /// Creates the view of elements of the `front` slice, followed by elements of the `fragments` and elements of the `back` slice.
+    pub(crate) fn new(
+        front: &'a mut [T],
+        fragments: &'a mut [Fragment<T, A>],
+        back: &'a mut [T],
+    ) -> Self {
+        let fragments_len: usize = fragments.iter().map(|x| x.len()).sum();
+        let len = front.len() + fragments_len + back.len();
+        Self {
+            front,
+            fragments,
+            back,
+            len,
+        }
+    }
+
+    /// Returns a read-only view of the elements of this view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_> = (0..42).collect();
+    /// let view = vec.view_mut(10..20);
+    ///
+    /// assert_eq!(view.as_view(), &[10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+    /// ```
+    pub fn as_view(&self) -> SplitVecView<'_, T, A> {
+        SplitVecView::new(self.front, self.fragments, self.back)
+    }
+
+    /// Returns the number of elements in the view.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has a length of 0.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the `index`-th element of the view; None if the index is out of bounds.
+    ///
+    /// The element is located by scanning the fragments spanned by the view; hence, random access on a view takes O(f) time,
+    /// where f is the number of these fragments, even when the growth strategy of the vector allows for constant time access.
+    /// When random access is frequent, the element can be accessed on the vector by offsetting the index by the start of the view.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match locate(self.front.len(), self.fragments, index) {
+            Location::Front(i) => self.front.get(i),
+            Location::Fragment(f, i) => self.fragments[f].get(i),
+            Location::Back(i) => self.back.get(i),
+        }
+    }
+
+    /// Returns a mutable reference to the `index`-th element of the view; None if the index is out of bounds.
+    ///
+    /// Similar to [`SplitVecViewMut::get`], locating the element takes O(f) time, where f is the number of fragments spanned by the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_> = (0..42).collect();
+    /// let mut view = vec.view_mut(10..20);
+    ///
+    /// *view.get_mut(2).unwrap() = 42;
+    /// assert_eq!(view.get_mut(10), None);
+    ///
+    /// assert_eq!(vec[12], 42);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match locate(self.front.len(), self.fragments, index) {
+            Location::Front(i) => self.front.get_mut(i),
+            Location::Fragment(f, i) => self.fragments[f].get_mut(i),
+            Location::Back(i) => self.back.get_mut(i),
+        }
+    }
+
+    /// Returns a reference to the first element of the view; None if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.as_view().first()
+    }
+
+    /// Returns a reference to the last element of the view; None if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.as_view().last()
+    }
+
+    /// Returns a mutable reference to the first element of the view; None if it is empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.slices_mut().find_map(|x| x.first_mut())
+    }
+
+    /// Returns a mutable reference to the last element of the view; None if it is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.slices_mut().rev().find_map(|x| x.last_mut())
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter::from_parts(self.front, self.fragments, self.back)
+    }
+
+    /// Returns a mutable iterator over the elements of the view.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+        self.reborrow().into_iter()
+    }
+
+    /// Divides the view into two read-only views at the index `mid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (SplitVecView<'_, T, A>, SplitVecView<'_, T, A>) {
+        self.as_view().split_at(mid)
+    }
+
+    /// Divides the view into two mutable views at the index `mid`.
+    ///
+    /// The first view contains elements at positions `[0, mid)` and the second contains those at `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_> = (0..42).collect();
+    /// let mut view = vec.view_mut(10..20);
+    ///
+    /// let (mut left, mut right) = view.split_at_mut(3);
+    /// left[0] = 0;
+    /// right[0] = 0;
+    ///
+    /// assert_eq!(vec[10], 0);
+    /// assert_eq!(vec[13], 0);
+    /// ```
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (SplitVecViewMut<'_, T, A>, SplitVecViewMut<'_, T, A>) {
+        self.reborrow().split_into(mid)
+    }
+
+    /// Returns the read-only view of the given `range` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than the range end, or if the range end is greater than the length of the view.
+    pub fn view<R: RangeBounds<usize>>(&self, range: R) -> SplitVecView<'_, T, A> {
+        self.as_view().view(range)
+    }
+
+    /// Returns the mutable view of the given `range` of this view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range start is greater than the range end, or if the range end is greater than the length of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec: SplitVec<_> = (0..42).collect();
+    /// let mut view = vec.view_mut(10..20);
+    ///
+    /// view.view_mut(2..4).iter_mut().for_each(|x| *x = 0);
+    /// assert_eq!(vec.view(10..15), &[10, 11, 0, 0, 14]);
+    /// ```
+    pub fn view_mut<R: RangeBounds<usize>>(&mut self, range: R) -> SplitVecViewMut<'_, T, A> {
+        let (a, b) = range_bounds(&range, self.len);
+        let (_, right) = self.reborrow().split_into(a);
+        let (view, _) = right.split_into(b - a);
+        view
+    }
+
+    /// Copies the elements of the view into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_view().to_vec()
+    }
+
+    /// Copies all elements of the view into `dst` with bulk memory copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` is not equal to the length of the view.
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        self.as_view().copy_to_slice(dst)
+    }
+
+    /// Copies all elements of `src` into the view with bulk memory copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `src` is not equal to the length of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_split_vec::*;
+    ///
+    /// let mut vec = SplitVec::with_linear_growth(2);
+    /// vec.extend(0..10);
+    ///
+    /// vec.view_mut(2..7).copy_from_slice(&[0, 0, 0, 0, 0]);
+    /// assert_eq!(vec, &[0, 1, 0, 0, 0, 0, 0, 7, 8, 9]);
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            src.len(),
+            self.len,
+            "source slice length (is {}) should be equal to the view length (is {})",
+            src.len(),
+            self.len
+        );
+
+        let mut begin = 0;
+        for slice in self.slices_mut() {
+            let end = begin + slice.len();
+            slice.copy_from_slice(&src[begin..end]);
+            begin = end;
+        }
+    }
+
+    // helpers
+
+    /// Returns a mutable view of the same elements with a shorter lifetime.
+    fn reborrow(&mut self) -> SplitVecViewMut<'_, T, A> {
+        SplitVecViewMut {
+            front: &mut *self.front,
+            fragments: &mut *self.fragments,
+            back: &mut *self.back,
+            len: self.len,
+        }
+    }
+
+    /// Consumes the view and divides it into two mutable views at the index `mid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub(crate) fn split_into(self, mid: usize) -> (Self, Self) {
+        assert!(
+            mid <= self.len,
+            "mid (is {}) should be <= len (is {})",
+            mid,
+            self.len
+        );
+
+        let Self {
+            front,
+            fragments,
+            back,
+            len: _,
+        } = self;
+
+        match locate(front.len(), fragments, mid) {
+            Location::Front(i) => {
+                let (left, right) = front.split_at_mut(i);
+                (
+                    Self::new(left, &mut [], &mut []),
+                    Self::new(right, fragments, back),
+                )
+            }
+            Location::Fragment(f, i) => {
+                let (left_fragments, right_fragments) = fragments.split_at_mut(f);
+                let (fragment, right_fragments) = right_fragments.split_at_mut(1);
+                let (left, right) = fragment[0].split_at_mut(i);
+                (
+                    Self::new(front, left_fragments, left),
+                    Self::new(right, right_fragments, back),
+                )
+            }
+            Location::Back(i) => {
+                let (left, right) = back.split_at_mut(i);
+                (
+                    Self::new(front, fragments, left),
+                    Self::new(&mut [], &mut [], right),
+                )
+            }
+        }
+    }
+
+    /// Returns the contiguous mutable slices of the view in order, some of which might be empty.
+    fn slices_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> {
+        core::iter::once(&mut *self.front)
+            .chain(self.fragments.iter_mut().map(|x| &mut x[..]))
+            .chain(core::iter::once(&mut *self.back))
+    }
+}
+
+impl<'a, T, A: Allocator> Index<usize> for SplitVecViewMut<'a, T, A> {
+    type Output = T;
+
+    /// Returns a reference to the `index`-th element of the view.
+    ///
+    /// Locating the element takes O(f) time, where f is the number of fragments spanned by the view; see [`SplitVecViewMut::get`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index is out of bounds")
+    }
+}
+
+impl<'a, T, A: Allocator> IndexMut<usize> for SplitVecViewMut<'a, T, A> {
+    /// Returns a mutable reference to the `index`-th element of the view.
+    ///
+    /// Locating the element takes O(f) time, where f is the number of fragments spanned by the view; see [`SplitVecViewMut::get`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index is out of bounds")
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for SplitVecViewMut<'a, T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::from_parts(self.front, self.fragments, self.back)
+    }
+}
+
+impl<'a, T: Debug, A: Allocator> Debug for SplitVecViewMut<'a, T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, U, A: Allocator> PartialEq<U> for SplitVecViewMut<'a, T, A>
+where
+    U: AsRef<[T]>,
+    T: PartialEq,
+{
+    fn eq(&self, other: &U) -> bool {
+        self.as_view().eq(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_all_growth_types;
+    use crate::*;
+
+    #[test]
+    fn view_mut() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let len = 100;
+            for (a, b) in [(0, 0), (0, 100), (3, 4), (3, 40), (17, 18), (50, 100)] {
+                vec.clear();
+                vec.extend(0..len);
+                let mut expected: Vec<_> = (0..len).collect();
+
+                let mut view = vec.view_mut(a..b);
+                let slice = &mut expected[a..b];
+                assert_eq!(view.len(), slice.len());
+                assert_eq!(view, &*slice);
+                assert_eq!(view.first_mut(), slice.first_mut());
+                assert_eq!(view.last_mut(), slice.last_mut());
+
+                for (i, x) in view.iter_mut().enumerate() {
+                    *x += i * 1000;
+                }
+                for (i, x) in slice.iter_mut().enumerate() {
+                    *x += i * 1000;
+                }
+                assert_eq!(view, &*slice);
+
+                for i in 0..(b - a + 2) {
+                    assert_eq!(view.get_mut(i), slice.get_mut(i));
+                }
+                if let Some(x) = view.get_mut(1) {
+                    *x = 7;
+                    slice[1] = 7;
+                }
+                assert!(view.iter().rev().eq(slice.iter().rev()));
+
+                assert_eq!(vec, expected.as_slice());
+            }
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn split_at_mut_and_nested_views() {
+        fn test<G: Growth>(mut vec: SplitVec<usize, G>) {
+            let len = 100;
+            vec.extend(0..len);
+            let mut expected: Vec<_> = (0..len).collect();
+
+            let mut view = vec.view_mut(5..90);
+            let slice = &mut expected[5..90];
+            for mid in [0, 1, 3, 4, 10, 33, 84, 85] {
+                let (mut left, mut right) = view.split_at_mut(mid);
+                let (expected_left, expected_right) = slice.split_at_mut(mid);
+                assert_eq!(left, &*expected_left);
+                assert_eq!(right, &*expected_right);
+
+                if let (Some(x), Some(y)) = (left.last_mut(), right.first_mut()) {
+                    core::mem::swap(x, y);
+                }
+                if let (Some(x), Some(y)) = (expected_left.last_mut(), expected_right.first_mut()) {
+                    core::mem::swap(x, y);
+                }
+            }
+
+            let mut nested = view.view_mut(7..60);
+            let mut nested = nested.view_mut(20..);
+            nested.copy_from_slice(&[42; 33]);
+            slice[27..60].copy_from_slice(&[42; 33]);
+
+            assert_eq!(view, &*slice);
+            assert_eq!(view.view(20..30), &slice[20..30]);
+            assert_eq!(view.to_vec(), slice.to_vec());
+            assert_eq!(vec, expected.as_slice());
+        }
+        test_all_growth_types!(test);
+    }
+
+    #[test]
+    fn view_mut_with_empty_fragments() {
+        let mut vec = SplitVec::with_recursive_growth();
+        vec.append(vec![0, 1, 2]);
+        vec.append(vec![3]);
+        vec.append(vec![4, 5, 6, 7]);
+        for f in [1, 3] {
+            unsafe { vec.fragments_mut().insert(f, Fragment::new(0)) };
+        }
+        vec.fragments_mutated_from(0);
+
+        let mut view = vec.view_mut(2..7);
+        view[1] = 30;
+        *view.first_mut().expect("is-some") = 20;
+        *view.last_mut().expect("is-some") = 60;
+        assert_eq!(format!("{:?}", view), "[20, 30, 4, 5, 60]");
+
+        let (_, mut right) = view.split_at_mut(2);
+        right.copy_from_slice(&[40, 50, 60]);
+
+        assert_eq!(vec, &[0, 1, 20, 30, 40, 50, 60, 7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_length_mismatch() {
+        let mut vec: SplitVec<_> = (0..10).collect();
+        vec.view_mut(5..8).copy_from_slice(&[0; 4]);
+    }
+}
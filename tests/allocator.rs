@@ -0,0 +1,183 @@
+use allocator_api2::alloc::AllocError;
+use core::alloc::Layout;
+use core::ptr::NonNull;
+use orx_split_vec::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[derive(Clone, Default)]
+struct CountingAllocator {
+    num_allocations: Arc<AtomicUsize>,
+    num_live: Arc<AtomicUsize>,
+}
+
+impl CountingAllocator {
+    fn num_allocations(&self) -> usize {
+        self.num_allocations.load(Ordering::SeqCst)
+    }
+
+    fn num_live(&self) -> usize {
+        self.num_live.load(Ordering::SeqCst)
+    }
+}
+
+unsafe impl Allocator for CountingAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.num_allocations.fetch_add(1, Ordering::SeqCst);
+        self.num_live.fetch_add(1, Ordering::SeqCst);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.num_live.fetch_sub(1, Ordering::SeqCst);
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn fragments_are_allocated_by_allocator() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec: SplitVec<String, Doubling, CountingAllocator> = SplitVec::new_in(alloc.clone());
+    assert_eq!(alloc.num_live(), 1);
+
+    for i in 0..100 {
+        vec.push(i.to_string());
+    }
+    assert_eq!(vec.len(), 100);
+    assert_eq!(alloc.num_live(), vec.fragments().len());
+    assert_eq!(alloc.num_allocations(), vec.fragments().len());
+
+    let clone = vec.clone();
+    assert_eq!(clone, vec);
+    assert_eq!(alloc.num_live(), 2 * vec.fragments().len());
+
+    drop(clone);
+    drop(vec);
+    assert_eq!(alloc.num_live(), 0);
+}
+
+#[test]
+fn operations_with_allocator() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec = SplitVec::with_growth_in(Linear::pseudo_default(), alloc.clone());
+    vec.extend(0..42);
+    vec.insert(3, 100);
+    vec.retain(|x| x % 2 == 0);
+    vec.truncate(10);
+    assert_eq!(vec, &[0, 2, 100, 4, 6, 8, 10, 12, 14, 16]);
+
+    let tail = vec.split_off(4);
+    assert_eq!(vec, &[0, 2, 100, 4]);
+    assert_eq!(tail, &[6, 8, 10, 12, 14, 16]);
+    assert_eq!(tail.allocator().num_allocations(), alloc.num_allocations());
+
+    vec.append(tail);
+    assert_eq!(vec, &[0, 2, 100, 4, 6, 8, 10, 12, 14, 16]);
+
+    let values: Vec<_> = vec.into_iter().collect();
+    assert_eq!(values, &[0, 2, 100, 4, 6, 8, 10, 12, 14, 16]);
+    assert_eq!(alloc.num_live(), 0);
+}
+
+#[test]
+fn into_concurrent_with_allocator() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec: SplitVec<String, Doubling, _> = SplitVec::new_in(alloc.clone());
+    vec.push(0.to_string());
+    vec.reserve_maximum_concurrent_capacity(20);
+
+    let con_vec = vec.into_concurrent();
+    let capacity = con_vec.grow_to(20).expect("must grow");
+    assert!(capacity >= 20);
+    let num_allocations = alloc.num_allocations();
+    assert!(num_allocations > 1);
+
+    for i in 1..20 {
+        unsafe { con_vec.get_ptr_mut(i).write(i.to_string()) };
+    }
+
+    let vec = unsafe { con_vec.into_inner(20) };
+    assert_eq!(alloc.num_allocations(), num_allocations);
+    assert_eq!(vec.len(), 20);
+    for (i, x) in vec.iter().enumerate() {
+        assert_eq!(x, &i.to_string());
+    }
+
+    drop(vec);
+    assert_eq!(alloc.num_live(), 0);
+}
+
+#[test]
+fn sort_with_allocator() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec = SplitVec::with_growth_in(Doubling, alloc.clone());
+    vec.extend((0..100).rev());
+    let num_allocations = alloc.num_allocations();
+
+    vec.sort_unstable();
+    assert_eq!(vec, (0..100).collect::<Vec<_>>());
+    assert_eq!(alloc.num_allocations(), num_allocations);
+
+    vec.sort_by(|a, b| b.cmp(a));
+    assert_eq!(vec, (0..100).rev().collect::<Vec<_>>());
+    assert_eq!(alloc.num_allocations(), num_allocations + 1);
+    assert_eq!(alloc.num_live(), vec.fragments().len());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_with_allocator() {
+    use rayon::prelude::*;
+
+    let alloc = CountingAllocator::default();
+
+    let mut vec = SplitVec::with_growth_in(Recursive::pseudo_default(), alloc.clone());
+    vec.par_extend((0..10_000).into_par_iter().map(|x| x.to_string()));
+    assert_eq!(vec.len(), 10_000);
+    assert_eq!(alloc.num_live(), vec.fragments().len());
+
+    let collected: SplitVec<String, Recursive, CountingAllocator> =
+        (0..100).into_par_iter().map(|x| x.to_string()).collect();
+    assert_eq!(collected.len(), 100);
+
+    let sum: usize = vec
+        .into_par_iter()
+        .take(5_000)
+        .map(|x| x.parse::<usize>().expect("is a number"))
+        .sum();
+    assert_eq!(sum, (0..5_000).sum());
+    assert_eq!(alloc.num_live(), 0);
+}
+
+#[test]
+fn borrowed_allocator() {
+    let alloc = CountingAllocator::default();
+
+    let mut vec: SplitVec<String, Linear, &CountingAllocator> =
+        SplitVec::with_growth_in(Linear::pseudo_default(), &alloc);
+    for i in 0..10 {
+        vec.push(i.to_string());
+    }
+    vec.insert(0, 100.to_string());
+    assert_eq!(vec.pop(), Some(9.to_string()));
+    assert_eq!(vec.remove(1), 0.to_string());
+    vec.swap(0, 1);
+    vec.extend_from_slice(&[200.to_string()]);
+    vec.truncate(5);
+    assert_eq!(vec.len(), 5);
+    assert_eq!(vec.first(), Some(&1.to_string()));
+    assert_eq!(vec.get(1), Some(&100.to_string()));
+    assert!(vec.iter().eq(["1", "100", "2", "3", "4"]));
+    assert_eq!(alloc.num_live(), vec.fragments().len());
+
+    vec.clear();
+    assert!(vec.is_empty());
+    assert_eq!(alloc.num_live(), 1);
+
+    drop(vec);
+    assert_eq!(alloc.num_live(), 0);
+}
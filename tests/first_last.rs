@@ -1,4 +1,3 @@
-use orx_pinned_vec::PinnedVec;
 use orx_split_vec::SplitVec;
 
 #[test]